@@ -37,6 +37,13 @@ fn bench_order_statistic(c: &mut Criterion) {
             BatchSize::SmallInput,
         )
     });
+    group.bench_function("quantile_custom: QuantileDefinition::R7", |b| {
+        b.iter_batched(
+            || to_random_owned(&data),
+            |mut data| data.quantile_custom(tau, QuantileDefinition::R7),
+            BatchSize::SmallInput,
+        )
+    });
     group.bench_function("percentile", |b| {
         b.iter_batched(
             || to_random_owned(&data),
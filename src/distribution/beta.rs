@@ -131,6 +131,35 @@ impl ContinuousCDF<f64, f64> for Beta {
             beta::beta_reg(self.shape_a, self.shape_b, x)
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the beta
+    /// distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I^-1_x(α, β)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I^-1_x` is the inverse of
+    /// the regularized lower incomplete beta function
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&x) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
+            Ok(0.5)
+        } else if self.shape_a.is_infinite() {
+            Ok(1.0)
+        } else if self.shape_b.is_infinite() {
+            Ok(0.0)
+        } else {
+            Ok(beta::inv_beta_reg(self.shape_a, self.shape_b, x))
+        }
+    }
 }
 
 impl Min<f64> for Beta {
@@ -307,7 +336,7 @@ impl Continuous<f64, f64> for Beta {
     ///
     /// where `α` is shapeA, `β` is shapeB, and `Γ` is the gamma function
     fn pdf(&self, x: f64) -> f64 {
-        if x < 0.0 || x > 1.0 {
+        if !(0.0..=1.0).contains(&x) {
             0.0
         } else if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
             if ulps_eq!(x, 0.5) {
@@ -351,7 +380,7 @@ impl Continuous<f64, f64> for Beta {
     ///
     /// where `α` is shapeA, `β` is shapeB, and `Γ` is the gamma function
     fn ln_pdf(&self, x: f64) -> f64 {
-        if x < 0.0 || x > 1.0 {
+        if !(0.0..=1.0).contains(&x) {
             f64::NEG_INFINITY
         } else if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
             if ulps_eq!(x, 0.5) {
@@ -655,6 +684,29 @@ mod tests {
         test_case(f64::INFINITY, f64::INFINITY, 1.0, cdf(1.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Beta| x.inverse_cdf(arg);
+        test_case(1.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 0.5, 1e-15, inverse_cdf(0.5));
+        test_case(1.0, 1.0, 1.0, inverse_cdf(1.0));
+        test_almost(9.0, 1.0, 0.5, 1e-15, inverse_cdf(0.001953125));
+        test_almost(7.5, 13.5, 0.1722762136319127, 1e-14, inverse_cdf(0.025));
+        test_almost(7.5, 13.5, 0.567766093841495, 1e-14, inverse_cdf(0.975));
+        test_case(1.0, f64::INFINITY, 0.0, inverse_cdf(0.5));
+        test_case(f64::INFINITY, 1.0, 1.0, inverse_cdf(0.5));
+        test_case(f64::INFINITY, f64::INFINITY, 0.5, inverse_cdf(0.5));
+        let n = try_create(7.5, 13.5);
+        assert_eq!(n.checked_inverse_cdf(0.025).unwrap(), n.inverse_cdf(0.025));
+        assert!(n.checked_inverse_cdf(-0.5).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_input_gt_1() {
+        get_value(1.0, 1.0, |x: Beta| x.inverse_cdf(1.5));
+    }
+
     #[test]
     fn test_cdf_input_lt_0() {
         let cdf = |arg: f64| move |x: Beta| x.cdf(arg);
@@ -410,6 +410,13 @@ mod tests {
         test_case(f64::INFINITY, 1.0, 0.0, cdf(5.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Cauchy| x.inverse_cdf(arg);
+        test_almost(1.0, 2.0, -5.1553670743505068051, 1e-14, inverse_cdf(0.1));
+        test_almost(1.0, 2.0, 1.0, 1e-15, inverse_cdf(0.5));
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(-1.2, 3.4), -1500.0, 1500.0);
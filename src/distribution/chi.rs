@@ -99,6 +99,21 @@ impl ContinuousCDF<f64, f64> for Chi {
             gamma::gamma_lr(self.freedom / 2.0, x * x / 2.0)
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
 }
 
 impl Min<f64> for Chi {
@@ -145,7 +160,7 @@ impl Distribution<f64> for Chi {
     /// where `k` is degrees of freedom and `Γ` is the gamma function
     fn mean(&self) -> Option<f64> {
         if self.freedom.is_infinite() {
-            return None;
+            None
         } else if self.freedom > 300.0 {
             // Large n approximation based on the Stirling series approximation to the Gamma function
             // This avoids call the Gamma function with large arguments and returning NaN
@@ -560,6 +575,13 @@ mod tests {
         test_case(f64::INFINITY, 1.0, cdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Chi| x.inverse_cdf(arg);
+        test_almost(3.0, 0.76444383322464133549, 1e-15, inverse_cdf(0.1));
+        test_almost(3.0, 2.5002777108094059071, 1e-14, inverse_cdf(0.9));
+    }
+
     #[test]
     fn test_neg_cdf() {
         let cdf = |arg: f64| move |x: Chi| x.cdf(arg);
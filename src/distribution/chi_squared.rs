@@ -117,6 +117,21 @@ impl ContinuousCDF<f64, f64> for ChiSquared {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        self.g.checked_inverse_cdf(x)
+    }
 }
 
 impl Min<f64> for ChiSquared {
@@ -268,7 +283,7 @@ impl Continuous<f64, f64> for ChiSquared {
 #[cfg(test)]
 mod tests {
     use crate::statistics::Median;
-    use crate::distribution::ChiSquared;
+    use crate::distribution::{ChiSquared, ContinuousCDF};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -304,6 +319,12 @@ mod tests {
         test_case(3.0, 3.0 - 2.0 / 3.0, median);
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(5.0, 1.1454762260617692499, 1e-14, |x| x.inverse_cdf(0.05));
+        test_almost(5.0, 11.070497693516354178, 1e-13, |x| x.inverse_cdf(0.95));
+    }
+
     #[test]
     fn test_continuous() {
         // TODO: figure out why this test fails:
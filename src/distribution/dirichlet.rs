@@ -201,7 +201,7 @@ impl VarianceN<DMatrix<f64>> for Dirichlet {
     }
 }
 
-impl<'a> Continuous<&'a DVector<f64>, f64> for Dirichlet {
+impl Continuous<&DVector<f64>, f64> for Dirichlet {
     /// Calculates the probabiliy density function for the dirichlet
     /// distribution
     /// with given `x`'s corresponding to the concentration parameters for this
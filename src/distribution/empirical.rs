@@ -13,13 +13,13 @@ impl<T: PartialEq> Eq for NonNAN<T> {}
 
 impl<T: PartialOrd> PartialOrd for NonNAN<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.0.partial_cmp(&other.0)
+        Some(self.cmp(other))
     }
 }
 
 impl<T: PartialOrd> Ord for NonNAN<T> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        self.0.partial_cmp(&other.0).unwrap()
     }
 }
 
@@ -165,7 +165,7 @@ impl Max<f64> for Empirical {
 /// Panics if number of samples is zero
 impl Min<f64> for Empirical {
     fn min(&self) -> f64 {
-        self.data.iter().map(|(key, _)| key.0).next().unwrap()
+        self.data.keys().map(|key| key.0).next().unwrap()
     }
 }
 
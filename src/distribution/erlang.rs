@@ -100,6 +100,21 @@ impl ContinuousCDF<f64, f64> for Erlang {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// erlang distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        self.g.checked_inverse_cdf(x)
+    }
 }
 
 impl Min<f64> for Erlang {
@@ -253,7 +268,7 @@ impl Continuous<f64, f64> for Erlang {
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use crate::distribution::Erlang;
+    use crate::distribution::{ContinuousCDF, Erlang};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -297,4 +312,11 @@ mod tests {
         tests::check_continuous_distribution(&try_create(2, 1.5), 0.0, 20.0);
         tests::check_continuous_distribution(&try_create(3, 0.5), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3, 0.5);
+        assert_almost_eq!(n.inverse_cdf(0.2), 3.0700884052892868698, 1e-14);
+        assert_almost_eq!(n.inverse_cdf(0.8), 8.5580597202506671198, 1e-14);
+    }
 }
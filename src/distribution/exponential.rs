@@ -439,6 +439,13 @@ mod tests {
         test_case(f64::INFINITY, 1.0, cdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Exp| x.inverse_cdf(arg);
+        test_almost(2.0, 0.17833747196936618946, 1e-15, inverse_cdf(0.3));
+        test_case(2.0, 0.0, inverse_cdf(0.0));
+    }
+
     #[test]
     fn test_neg_cdf() {
         let cdf = |arg: f64| move |x: Exp| x.cdf(arg);
@@ -123,6 +123,21 @@ impl ContinuousCDF<f64, f64> for FisherSnedecor {
             )
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
 }
 
 impl Min<f64> for FisherSnedecor {
@@ -530,6 +545,13 @@ mod tests {
         test_almost(10.0, 1.0, 0.340893132302059872675, 1e-15, cdf(1.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: FisherSnedecor| x.inverse_cdf(arg);
+        test_almost(3.0, 10.0, 0.19118955437204960998, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 3.7082648190468444854, 1e-12, inverse_cdf(0.95));
+    }
+
     #[test]
     fn test_cdf_lower_bound() {
         let cdf = |arg: f64| move |x: FisherSnedecor| x.cdf(arg);
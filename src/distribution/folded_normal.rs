@@ -0,0 +1,521 @@
+use crate::distribution::{internal, normal, Continuous, ContinuousCDF};
+use crate::function::erf;
+use crate::statistics::*;
+use crate::{consts, quadrature, solve, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Folded normal](https://en.wikipedia.org/wiki/Folded_normal_distribution)
+/// distribution, the distribution of `|X|` for a normally distributed `X`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{FoldedNormal, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = FoldedNormal::new(1.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.1666309411753726, 1e-10));
+/// assert!(prec::almost_eq(n.pdf(0.5), 0.4815829224301912, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FoldedNormal {
+    location: f64,
+    scale: f64,
+}
+
+impl FoldedNormal {
+    /// Constructs a new folded normal distribution with a location (μ) of
+    /// `location` and a scale (σ) of `scale`, the mean and standard
+    /// deviation of the underlying normal distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `scale` are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FoldedNormal;
+    ///
+    /// let mut result = FoldedNormal::new(1.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = FoldedNormal::new(1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<FoldedNormal> {
+        if location.is_finite() && scale.is_finite() && scale > 0.0 {
+            Ok(FoldedNormal { location, scale })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the location of the folded normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FoldedNormal;
+    ///
+    /// let n = FoldedNormal::new(-2.0, 1.5).unwrap();
+    /// assert_eq!(n.location(), -2.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the folded normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::FoldedNormal;
+    ///
+    /// let n = FoldedNormal::new(-2.0, 1.5).unwrap();
+    /// assert_eq!(n.scale(), 1.5);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the standardized location `r = |μ| / σ` together with
+    /// `d = 2φ(r) - r * erfc(r / sqrt(2))`, in terms of which the mean of the
+    /// standardized distribution is `r + d`
+    fn standardized(&self) -> (f64, f64) {
+        let r = self.location.abs() / self.scale;
+        let phi = (-0.5 * r * r - consts::LN_SQRT_2PI).exp();
+        (r, 2.0 * phi - r * erf::erfc(r / f64::consts::SQRT_2))
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for FoldedNormal {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        normal::sample_unchecked(r, self.location, self.scale).abs()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for FoldedNormal {
+    /// Calculates the cumulative distribution function for the folded
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * (erf((x + μ) / (σ * sqrt(2))) + erf((x - μ) / (σ * sqrt(2))))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `erf` is the error
+    /// function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let m = self.location.abs();
+        let denom = self.scale * f64::consts::SQRT_2;
+        // written in terms of erfc so that neither tail cancels
+        if x < m {
+            0.5 * (erf::erfc((m - x) / denom) - erf::erfc((m + x) / denom))
+        } else {
+            1.0 - 0.5 * (erf::erfc((x - m) / denom) + erf::erfc((x + m) / denom))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// folded normal distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        internal::inverse_cdf_newton(self, p)
+    }
+}
+
+impl Min<f64> for FoldedNormal {
+    /// Returns the minimum value in the domain of the folded normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for FoldedNormal {
+    /// Returns the maximum value in the domain of the folded normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for FoldedNormal {
+    /// Returns the mean of the folded normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2 / π) * exp(-μ^2 / (2σ^2)) + μ * erf(μ / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `erf` is the error
+    /// function
+    fn mean(&self) -> Option<f64> {
+        let (r, d) = self.standardized();
+        Some(self.scale * (r + d))
+    }
+    /// Returns the variance of the folded normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ^2 + σ^2 - m^2
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `m` is the mean
+    fn variance(&self) -> Option<f64> {
+        let (r, d) = self.standardized();
+        Some(self.scale * self.scale * (1.0 - d * (2.0 * r + d)))
+    }
+    /// Returns the entropy of the folded normal distribution
+    ///
+    /// # None
+    ///
+    /// If the numerical integration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The entropy has no closed form and is computed as `E[-ln f(X)]` by
+    /// numerical integration
+    fn entropy(&self) -> Option<f64> {
+        quadrature::expectation(self, |x| -self.ln_pdf(x)).ok()
+    }
+    /// Returns the skewness of the folded normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (e_3 + 3d * (r^2 - 1) + 6r * d^2 + 2d^3) / (1 - d * (2r + d))^(3 / 2)
+    /// ```
+    ///
+    /// where `r = |μ| / σ`, `d = 2φ(r) - r * erfc(r / sqrt(2))`,
+    /// `e_3 = 2(r^2 + 2) * φ(r) - (r^3 + 3r) * erfc(r / sqrt(2))`, `μ` is the
+    /// location, `σ` is the scale, `φ` is the standard normal pdf and `erfc`
+    /// is the complementary error function
+    fn skewness(&self) -> Option<f64> {
+        let (r, d) = self.standardized();
+        let phi = (-0.5 * r * r - consts::LN_SQRT_2PI).exp();
+        let e3 =
+            2.0 * (r * r + 2.0) * phi - (r * r * r + 3.0 * r) * erf::erfc(r / f64::consts::SQRT_2);
+        let third_cumulant = e3 + 3.0 * d * (r * r - 1.0) + 6.0 * r * d * d + 2.0 * d * d * d;
+        let variance = 1.0 - d * (2.0 * r + d);
+        Some(third_cumulant / variance.powf(1.5))
+    }
+}
+
+impl Median<f64> for FoldedNormal {
+    /// Returns the median of the folded normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 / 2)
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for FoldedNormal {
+    /// Returns the mode of the folded normal distribution
+    ///
+    /// # None
+    ///
+    /// If the root finding fails to converge
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if |μ| <= σ
+    ///     0
+    /// else
+    ///     σt
+    /// ```
+    ///
+    /// where `t` is the positive root of `t = r * tanh(rt)`, `r = |μ| / σ`,
+    /// `μ` is the location and `σ` is the scale
+    fn mode(&self) -> Option<f64> {
+        let r = self.location.abs() / self.scale;
+        if r <= 1.0 {
+            return Some(0.0);
+        }
+        // tanh(rt) / t - 1 / r, continuously extended to r - 1 / r at zero,
+        // which drops the trivial root of t = r * tanh(rt)
+        let f = |t: f64| {
+            if t == 0.0 {
+                r - 1.0 / r
+            } else {
+                (r * t).tanh() / t - 1.0 / r
+            }
+        };
+        solve::brent(f, 0.0, r, 0.0, solve::DEFAULT_MAX_ITERATIONS)
+            .ok()
+            .map(|t| self.scale * t)
+    }
+}
+
+impl Continuous<f64, f64> for FoldedNormal {
+    /// Calculates the probability density function for the folded normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / sqrt(2πσ^2)) * (e^(-(x - μ)^2 / (2σ^2)) + e^(-(x + μ)^2 / (2σ^2)))
+    /// ```
+    ///
+    /// for `x >= 0`, where `μ` is the location and `σ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the folded
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(x - |μ|)^2 / (2σ^2) - ln(sqrt(2π) * σ) + ln(1 + e^(-2|μ|x / σ^2))
+    /// ```
+    ///
+    /// for `x >= 0`, where `μ` is the location and `σ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let m = self.location.abs();
+        normal::ln_pdf_unchecked(x, m, self.scale)
+            + (-2.0 * m * x / (self.scale * self.scale)).exp().ln_1p()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, FoldedNormal, HalfNormal};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> FoldedNormal {
+        let n = FoldedNormal::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = FoldedNormal::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(FoldedNormal) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(FoldedNormal) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1.0, 1.0);
+        create_case(-2.0, 1.5);
+        create_case(0.0, 3.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: FoldedNormal| x.mean().unwrap();
+        test_almost(1.0, 1.0, 1.1666309411753725968, 1e-10, mean);
+        test_almost(-2.0, 1.5, 2.12718534512450938, 1e-10, mean);
+        test_almost(3.0, 0.5, 3.0000000001563569796, 1e-10, mean);
+        test_almost(0.0, 2.5, HalfNormal::new(2.5).unwrap().mean().unwrap(), 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: FoldedNormal| x.variance().unwrap();
+        test_almost(1.0, 1.0, 0.63897224709226432379, 1e-10, variance);
+        test_almost(-2.0, 1.5, 1.725082507487521918, 1e-10, variance);
+        test_almost(3.0, 0.5, 0.24999999906185812239, 1e-10, variance);
+        test_almost(0.0, 2.5, HalfNormal::new(2.5).unwrap().variance().unwrap(), 1e-14, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: FoldedNormal| x.entropy().unwrap();
+        test_almost(1.0, 1.0, 1.0626221729915590444, 1e-9, entropy);
+        test_almost(-2.0, 1.5, 1.6037425681701629573, 1e-9, entropy);
+        test_almost(3.0, 0.5, 0.72579134963895680607, 1e-9, entropy);
+        test_almost(0.0, 2.5, HalfNormal::new(2.5).unwrap().entropy().unwrap(), 1e-9, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: FoldedNormal| x.skewness().unwrap();
+        test_almost(1.0, 1.0, 0.70174993826067410087, 1e-9, skewness);
+        test_almost(-2.0, 1.5, 0.49701788930380094607, 1e-9, skewness);
+        test_almost(3.0, 0.5, 3.2879044612908954589e-8, 1e-9, skewness);
+        test_almost(0.0, 2.5, HalfNormal::new(2.5).unwrap().skewness().unwrap(), 1e-9, skewness);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: FoldedNormal| x.median();
+        test_almost(1.0, 1.0, 1.0505442928961916245, 1e-10, median);
+        test_almost(-2.0, 1.5, 2.0140070421067534812, 1e-10, median);
+        test_almost(3.0, 0.5, 3.0, 1e-10, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: FoldedNormal| x.mode().unwrap();
+        test_case(1.0, 1.0, 0.0, mode);
+        test_case(0.5, 1.0, 0.0, mode);
+        test_almost(-2.0, 1.5, 1.8581870326896869635, 1e-14, mode);
+        test_almost(3.0, 0.5, 3.0, 1e-14, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: FoldedNormal| x.min();
+        let max = |x: FoldedNormal| x.max();
+        test_case(1.0, 1.0, 0.0, min);
+        test_case(1.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: FoldedNormal| x.pdf(arg);
+        test_almost(1.0, 1.0, 0.4839414490382866996, 1e-15, pdf(0.0));
+        test_almost(1.0, 1.0, 0.48158292243019120539, 1e-15, pdf(0.5));
+        test_almost(1.0, 1.0, 0.24640257293108135697, 1e-15, pdf(2.0));
+        test_almost(-2.0, 1.5, 0.21868009956799149305, 1e-15, pdf(0.0));
+        test_almost(-2.0, 1.5, 0.27355884428348674524, 1e-15, pdf(2.0));
+        test_almost(3.0, 0.5, 2.4303531399293141948e-8, 1e-21, pdf(0.0));
+        test_case(1.0, 1.0, 0.0, pdf(-0.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: FoldedNormal| x.ln_pdf(arg);
+        test_almost(1.0, 1.0, -41.418938531143519121, 1e-13, ln_pdf(10.0));
+        test_almost(-2.0, 1.5, -1.4800253893923651585, 1e-14, ln_pdf(0.5));
+        test_almost(-2.0, 1.5, -15.546625844515116548, 1e-13, ln_pdf(10.0));
+        test_almost(3.0, 0.5, -17.532644172084782123, 1e-13, ln_pdf(0.0));
+        test_almost(3.0, 0.5, -12.7257852084512497, 1e-13, ln_pdf(0.5));
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(-0.5));
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: FoldedNormal| x.cdf(arg);
+        test_case(1.0, 1.0, 0.0, cdf(0.0));
+        test_almost(1.0, 1.0, 0.24173033745712883036, 1e-10, cdf(0.5));
+        test_almost(1.0, 1.0, 0.83999484803691285406, 1e-10, cdf(2.0));
+        test_almost(-2.0, 1.5, 0.11086490165864234356, 1e-10, cdf(0.5));
+        test_almost(-2.0, 1.5, 0.49616961943241026443, 1e-10, cdf(2.0));
+        test_almost(3.0, 0.5, 2.8665029206665002584e-7, 1e-16, cdf(0.5));
+        test_almost(3.0, 0.5, 0.0227501319481792072, 1e-10, cdf(2.0));
+        test_case(1.0, 1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: FoldedNormal| x.inverse_cdf(arg);
+        test_almost(1.0, 1.0, 0.020663656833396515429, 1e-10, inverse_cdf(0.01));
+        test_almost(1.0, 1.0, 3.3266320100034399169, 1e-9, inverse_cdf(0.99));
+        test_almost(-2.0, 1.5, 0.045723392350339122378, 1e-10, inverse_cdf(0.01));
+        test_almost(-2.0, 1.5, 5.4895385380706724583, 1e-9, inverse_cdf(0.99));
+        test_almost(3.0, 0.5, 1.8368260629795794496, 1e-9, inverse_cdf(0.01));
+        test_almost(3.0, 0.5, 4.1631739370204205504, 1e-9, inverse_cdf(0.99));
+        test_case(1.0, 1.0, 0.0, inverse_cdf(0.0));
+        assert!(try_create(1.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_half_normal() {
+        let folded = try_create(0.0, 2.0);
+        let half = HalfNormal::new(2.0).unwrap();
+        for &x in &[0.0, 0.5, 2.0, 10.0] {
+            assert_almost_eq!(folded.ln_pdf(x), half.ln_pdf(x), 1e-14);
+            assert_almost_eq!(folded.cdf(x), half.cdf(x), 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 1.0), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 1.5), 0.0, 15.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(-2.0, 1.5);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert!((mean - n.mean().unwrap()).abs() < 0.02);
+    }
+}
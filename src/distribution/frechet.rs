@@ -0,0 +1,544 @@
+use crate::distribution::generalized_extreme_value::{
+    ln_gamma_one_minus, skewness_unchecked, variance_factor,
+};
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Fréchet](https://en.wikipedia.org/wiki/Fr%C3%A9chet_distribution)
+/// distribution, the limiting distribution of block maxima of heavy tailed
+/// variables
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Frechet, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Frechet::new(1.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.5);
+/// assert_eq!(n.pdf(1.0), 0.36787944117144233);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frechet {
+    shape: f64,
+    scale: f64,
+}
+
+impl Frechet {
+    /// Constructs a new Fréchet distribution with a shape (α) of `shape`
+    /// and a scale (s) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `scale` are `NaN` or infinite.
+    /// Returns an error if `shape <= 0.0` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let mut result = Frechet::new(3.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Frechet::new(0.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(shape: f64, scale: f64) -> Result<Frechet> {
+        if shape.is_finite() && shape > 0.0 && scale.is_finite() && scale > 0.0 {
+            Ok(Frechet { shape, scale })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the shape of the Fréchet distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let n = Frechet::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the scale of the Fréchet distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let n = Frechet::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the return level for a return period of `period` blocks,
+    /// the level exceeded by the block maximum once every `period` blocks
+    /// on average
+    ///
+    /// # Errors
+    ///
+    /// If `period <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s * (-ln(1 - 1 / T))^(-1 / α)
+    /// ```
+    ///
+    /// where `α` is the shape, `s` is the scale and `T` is the period
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Frechet};
+    /// use statrs::prec;
+    ///
+    /// let n = Frechet::new(3.0, 2.0).unwrap();
+    /// let level = n.return_level(100.0).unwrap();
+    /// assert!(prec::almost_eq(n.cdf(level), 0.99, 1e-15));
+    /// ```
+    pub fn return_level(&self, period: f64) -> Result<f64> {
+        if period > 1.0 {
+            Ok(self.quantile_from_ln_cdf(-(-1.0 / period).ln_1p()))
+        } else {
+            Err(StatsError::ArgGt("period", 1.0))
+        }
+    }
+
+    /// Returns the value at which `-ln(cdf)` equals `y`
+    fn quantile_from_ln_cdf(&self, y: f64) -> f64 {
+        self.scale * y.powf(-1.0 / self.shape)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Frechet {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.quantile_from_ln_cdf(-u.ln())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Frechet {
+    /// Calculates the cumulative distribution function for the Fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-(x / s)^-α)
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            (-(x / self.scale).powf(-self.shape)).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// Fréchet distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s * (-ln(p))^(-1 / α)
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if (0.0..=1.0).contains(&p) {
+            Ok(self.quantile_from_ln_cdf(-p.ln()))
+        } else {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        }
+    }
+}
+
+impl Min<f64> for Frechet {
+    /// Returns the minimum value in the domain of the Fréchet distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Frechet {
+    /// Returns the maximum value in the domain of the Fréchet distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Frechet {
+    /// Returns the mean of the Fréchet distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s * Γ(1 - 1 / α)
+    /// ```
+    ///
+    /// where `α` is the shape, `s` is the scale and `Γ` is the gamma
+    /// function
+    fn mean(&self) -> Option<f64> {
+        if self.shape <= 1.0 {
+            None
+        } else {
+            Some(self.scale * ln_gamma_one_minus(1.0 / self.shape).exp())
+        }
+    }
+    /// Returns the variance of the Fréchet distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s^2 * (Γ(1 - 2 / α) - Γ(1 - 1 / α)^2)
+    /// ```
+    ///
+    /// where `α` is the shape, `s` is the scale and `Γ` is the gamma
+    /// function
+    fn variance(&self) -> Option<f64> {
+        if self.shape <= 2.0 {
+            None
+        } else {
+            let xi = 1.0 / self.shape;
+            Some(self.scale * self.scale * xi * xi * variance_factor(xi))
+        }
+    }
+    /// Returns the entropy of the Fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 + γ / α + γ + ln(s / α)
+    /// ```
+    ///
+    /// where `α` is the shape, `s` is the scale and `γ` is the
+    /// Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        let gamma = consts::EULER_MASCHERONI;
+        Some(1.0 + gamma / self.shape + gamma + (self.scale / self.shape).ln())
+    }
+    /// Returns the skewness of the Fréchet distribution
+    ///
+    /// # None
+    ///
+    /// If `α <= 3.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (g_3 - 3g_1 * g_2 + 2g_1^3) / (g_2 - g_1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `g_k = Γ(1 - k / α)`, `α` is the shape and `Γ` is the gamma
+    /// function
+    fn skewness(&self) -> Option<f64> {
+        if self.shape <= 3.0 {
+            None
+        } else {
+            Some(skewness_unchecked(1.0 / self.shape))
+        }
+    }
+}
+
+impl Median<f64> for Frechet {
+    /// Returns the median of the Fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s * ln(2)^(-1 / α)
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn median(&self) -> f64 {
+        self.quantile_from_ln_cdf(f64::consts::LN_2)
+    }
+}
+
+impl Mode<Option<f64>> for Frechet {
+    /// Returns the mode of the Fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s * (α / (1 + α))^(1 / α)
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn mode(&self) -> Option<f64> {
+        Some(self.quantile_from_ln_cdf(1.0 + 1.0 / self.shape))
+    }
+}
+
+impl Continuous<f64, f64> for Frechet {
+    /// Calculates the probability density function for the Fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α / s) * (x / s)^(-1 - α) * e^(-(x / s)^-α)
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the Fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((α / s) * (x / s)^(-1 - α) * e^(-(x / s)^-α))
+    /// ```
+    ///
+    /// where `α` is the shape and `s` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x.is_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            let ln_z = (x / self.scale).ln();
+            (self.shape / self.scale).ln() - (1.0 + self.shape) * ln_z - (-self.shape * ln_z).exp()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Frechet};
+    use crate::distribution::internal::*;
+
+    fn try_create(shape: f64, scale: f64) -> Frechet {
+        let n = Frechet::new(shape, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(shape: f64, scale: f64) {
+        let n = try_create(shape, scale);
+        assert_eq!(shape, n.shape());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(shape: f64, scale: f64) {
+        let n = Frechet::new(shape, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(shape: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Frechet) -> f64
+    {
+        let n = try_create(shape, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(shape: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Frechet) -> f64
+    {
+        let n = try_create(shape, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1.0, 1.0);
+        create_case(3.0, 2.0);
+        create_case(5.0, 0.5);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Frechet| x.mean().unwrap();
+        test_almost(3.0, 2.0, 2.7082358788528008339, 1e-14, mean);
+        test_almost(5.0, 0.5, 0.58211485686265168682, 1e-15, mean);
+        assert!(try_create(1.0, 1.0).mean().is_none());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Frechet| x.variance().unwrap();
+        test_almost(3.0, 2.0, 3.3812125633253880196, 1e-13, variance);
+        test_almost(5.0, 0.5, 0.033440355622978813954, 1e-15, variance);
+        assert!(try_create(2.0, 1.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Frechet| x.entropy().unwrap();
+        test_almost(1.0, 1.0, 2.1544313298030657212, 1e-15, entropy);
+        test_almost(3.0, 2.0, 1.3641557784272127655, 1e-15, entropy);
+        test_almost(5.0, 0.5, -0.60992629511220625129, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Frechet| x.skewness().unwrap();
+        test_almost(5.0, 0.5, 3.5350716046213945905, 1e-12, skewness);
+        assert!(try_create(3.0, 2.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Frechet| x.median();
+        test_almost(1.0, 1.0, 1.4426950408889634074, 1e-15, median);
+        test_almost(3.0, 2.0, 2.2598945526747801588, 1e-15, median);
+        test_almost(5.0, 0.5, 0.538028042569502561, 1e-15, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Frechet| x.mode().unwrap();
+        test_almost(1.0, 1.0, 0.5, 1e-16, mode);
+        test_almost(3.0, 2.0, 1.8171205928321396589, 1e-15, mode);
+        test_almost(5.0, 0.5, 0.48209625200131360046, 1e-16, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Frechet| x.min();
+        let max = |x: Frechet| x.max();
+        test_case(3.0, 2.0, 0.0, min);
+        test_case(3.0, 2.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Frechet| x.pdf(arg);
+        test_almost(1.0, 1.0, 0.54134113294645076758, 1e-15, pdf(0.5));
+        test_almost(1.0, 1.0, 0.3678794411714423216, 1e-16, pdf(1.0));
+        test_almost(1.0, 1.0, 0.0090483741803595957316, 1e-17, pdf(10.0));
+        test_almost(3.0, 2.0, 6.1586338197067693554e-26, 1e-38, pdf(0.5));
+        test_almost(3.0, 2.0, 0.22031617161656483418, 1e-16, pdf(3.0));
+        test_almost(5.0, 0.5, 3.678794411714423216, 1e-15, pdf(0.5));
+        test_almost(5.0, 0.5, 1.5624995117188262939e-7, 1e-21, pdf(10.0));
+        test_case(3.0, 2.0, 0.0, pdf(0.0));
+        test_case(3.0, 2.0, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Frechet| x.ln_pdf(arg);
+        test_almost(1.0, 1.0, -0.61370563888010938117, 1e-15, ln_pdf(0.5));
+        test_almost(3.0, 2.0, -58.049357447412273143, 1e-13, ln_pdf(0.5));
+        test_almost(3.0, 2.0, -6.0402865416282371164, 1e-15, ln_pdf(10.0));
+        test_almost(5.0, 0.5, -15.671808860829900277, 1e-14, ln_pdf(10.0));
+        test_case(3.0, 2.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(3.0, 2.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Frechet| x.cdf(arg);
+        test_almost(1.0, 1.0, 0.13533528323661269189, 1e-16, cdf(0.5));
+        test_almost(1.0, 1.0, 0.90483741803595957316, 1e-15, cdf(10.0));
+        test_almost(3.0, 2.0, 1.603810890548637853e-28, 1e-40, cdf(0.5));
+        test_almost(3.0, 2.0, 0.74356707920590631536, 1e-15, cdf(3.0));
+        test_almost(5.0, 0.5, 0.99987140744568611745, 1e-15, cdf(3.0));
+        test_case(3.0, 2.0, 0.0, cdf(0.0));
+        test_case(3.0, 2.0, 0.0, cdf(-1.0));
+        test_case(3.0, 2.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Frechet| x.inverse_cdf(arg);
+        test_almost(1.0, 1.0, 0.21714724095162591383, 1e-16, inverse_cdf(0.01));
+        test_almost(1.0, 1.0, 99.499162473422172731, 1e-12, inverse_cdf(0.99));
+        test_almost(3.0, 2.0, 1.202120770018240801, 1e-15, inverse_cdf(0.01));
+        test_almost(3.0, 2.0, 9.267653842790656673, 1e-14, inverse_cdf(0.99));
+        test_almost(5.0, 0.5, 0.36840105689670902248, 1e-16, inverse_cdf(0.01));
+        test_case(3.0, 2.0, 0.0, inverse_cdf(0.0));
+        test_case(3.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(3.0, 2.0).checked_inverse_cdf(1.1).is_err());
+    }
+
+    #[test]
+    fn test_return_level() {
+        let return_level = |arg: f64| move |x: Frechet| x.return_level(arg).unwrap();
+        test_almost(1.0, 1.0, 99.499162473422172731, 1e-12, return_level(100.0));
+        test_almost(3.0, 2.0, 9.267653842790656673, 1e-14, return_level(100.0));
+        test_almost(5.0, 0.5, 1.2546826408585783445, 1e-15, return_level(100.0));
+        assert!(try_create(3.0, 2.0).return_level(0.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(3.0, 2.0), 0.5, 100.0);
+        tests::check_continuous_distribution(&try_create(5.0, 0.5), 0.2, 10.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(5.0, 0.5);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!((mean - n.mean().unwrap()).abs() < 0.01);
+        assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.1);
+    }
+}
@@ -115,6 +115,21 @@ impl ContinuousCDF<f64, f64> for Gamma {
             gamma::gamma_lr(self.shape, x * self.rate)
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gamma distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
 }
 
 impl Min<f64> for Gamma {
@@ -310,10 +325,10 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, shape: f64, rate: f64) ->
             };
         }
 
-        v *= v * v;
+        v = v * v * v;
         x *= x;
         let u: f64 = rng.gen();
-        if u < 1.0 - 0.0331 * x * x || u.ln() < 0.5 * x + d * (1.0 - v - v.ln()) {
+        if u < 1.0 - 0.0331 * x * x || u.ln() < 0.5 * x + d * (1.0 - v + v.ln()) {
             return afix * d * v / rate;
         }
     }
@@ -515,6 +530,19 @@ mod tests {
         test_case(10.0, f64::INFINITY, 1.0, |x| x.cdf(10.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(3.0, 2.0, 0.0952666887842015953, 1e-15, |x| x.inverse_cdf(0.001));
+        test_almost(3.0, 2.0, 1.3370301568617801590, 1e-14, |x| x.inverse_cdf(0.5));
+        test_almost(3.0, 2.0, 5.6144361212063313152, 1e-13, |x| x.inverse_cdf(0.999));
+        test_almost(0.1, 1.0, 6.0730483627431726808e-11, 1e-23, |x| x.inverse_cdf(0.1));
+        test_almost(0.1, 1.0, 0.26615455373883775121, 1e-13, |x| x.inverse_cdf(0.9));
+        test_case(3.0, 2.0, 0.0, |x| x.inverse_cdf(0.0));
+        test_case(3.0, 2.0, f64::INFINITY, |x| x.inverse_cdf(1.0));
+        assert!(try_create(3.0, 2.0).checked_inverse_cdf(-0.1).is_err());
+        assert!(try_create(3.0, 2.0).checked_inverse_cdf(1.1).is_err());
+    }
+
     #[test]
     fn test_cdf_at_zero() {
         test_case(1.0, 0.1, 0.0, |x| x.cdf(0.0));
@@ -525,4 +553,21 @@ mod tests {
         tests::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 20.0);
         tests::check_continuous_distribution(&try_create(9.0, 2.0), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        for &(shape, rate) in &[(0.5, 1.0), (2.0, 0.5), (3.5, 2.0)] {
+            let n = try_create(shape, rate);
+            let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+            let mean = samples.iter().sum::<f64>() / 100_000.0;
+            let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+            assert!((mean / n.mean().unwrap() - 1.0).abs() < 0.02);
+            assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.05);
+        }
+    }
 }
@@ -0,0 +1,760 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Generalized extreme
+/// value](https://en.wikipedia.org/wiki/Generalized_extreme_value_distribution)
+/// distribution, the limiting distribution of block maxima. A shape of zero
+/// gives the [Gumbel](./struct.Gumbel.html) distribution, a positive shape a
+/// [Fréchet](./struct.Frechet.html) distribution and a negative shape a
+/// reversed [Weibull](./struct.Weibull.html) distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedExtremeValue, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = GeneralizedExtremeValue::new(0.0, 1.0, 0.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.36787944117144233);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedExtremeValue {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedExtremeValue {
+    /// Constructs a new generalized extreme value distribution with a
+    /// location (μ) of `location`, a scale (σ) of `scale` and a shape (ξ)
+    /// of `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the parameters are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let mut result = GeneralizedExtremeValue::new(0.0, 1.0, 0.1);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedExtremeValue::new(0.0, 0.0, 0.1);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedExtremeValue> {
+        if location.is_finite() && scale.is_finite() && scale > 0.0 && shape.is_finite() {
+            Ok(GeneralizedExtremeValue {
+                location,
+                scale,
+                shape,
+            })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the location of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.shape(), 0.1);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the return level for a return period of `period` blocks,
+    /// the level exceeded by the block maximum once every `period` blocks
+    /// on average
+    ///
+    /// # Errors
+    ///
+    /// If `period <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * ((-ln(1 - 1 / T))^-ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `ξ` is the shape and `T`
+    /// is the period
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, GeneralizedExtremeValue};
+    /// use statrs::prec;
+    ///
+    /// let n = GeneralizedExtremeValue::new(10.0, 2.0, 0.1).unwrap();
+    /// let level = n.return_level(100.0).unwrap();
+    /// assert!(prec::almost_eq(n.cdf(level), 0.99, 1e-15));
+    /// ```
+    pub fn return_level(&self, period: f64) -> Result<f64> {
+        if period > 1.0 {
+            Ok(self.quantile_from_ln_cdf(-(-1.0 / period).ln_1p()))
+        } else {
+            Err(StatsError::ArgGt("period", 1.0))
+        }
+    }
+
+    /// Returns the value at which `-ln(cdf)` equals `y`
+    fn quantile_from_ln_cdf(&self, y: f64) -> f64 {
+        self.location + self.scale * box_cox(-y.ln(), self.shape)
+    }
+
+    /// Returns `-ln(-ln(cdf(x)))`, or `None` outside the support
+    fn reduced_variate(&self, x: f64) -> Option<f64> {
+        let z = (x - self.location) / self.scale;
+        if self.shape == 0.0 {
+            Some(z)
+        } else if self.shape * z > -1.0 {
+            Some((self.shape * z).ln_1p() / self.shape)
+        } else {
+            None
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedExtremeValue {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.quantile_from_ln_cdf(-u.ln())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedExtremeValue {
+    /// Calculates the cumulative distribution function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-(1 + ξz)^(-1 / ξ))
+    /// ```
+    ///
+    /// where `z = (x - μ) / σ`, `μ` is the location, `σ` is the scale and
+    /// `ξ` is the shape, and `(1 + ξz)^(-1 / ξ)` is taken as `e^-z` for
+    /// `ξ = 0`
+    fn cdf(&self, x: f64) -> f64 {
+        match self.reduced_variate(x) {
+            Some(y) => (-(-y).exp()).exp(),
+            None if self.shape > 0.0 => 0.0,
+            None => 1.0,
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized extreme value distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * ((-ln(p))^-ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `((-ln(p))^-ξ - 1) / ξ` is taken as `-ln(-ln(p))` for `ξ = 0`
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if (0.0..=1.0).contains(&p) {
+            Ok(self.quantile_from_ln_cdf(-p.ln()))
+        } else {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedExtremeValue {
+    /// Returns the minimum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ > 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     NEG_INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn min(&self) -> f64 {
+        if self.shape > 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for GeneralizedExtremeValue {
+    /// Returns the maximum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedExtremeValue {
+    /// Returns the mean of the generalized extreme value distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (Γ(1 - ξ) - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `ξ` is the shape and `Γ`
+    /// is the gamma function, and `(Γ(1 - ξ) - 1) / ξ` is taken as `γ`, the
+    /// Euler-Mascheroni constant, for `ξ = 0`
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else if self.shape == 0.0 {
+            Some(self.location + self.scale * consts::EULER_MASCHERONI)
+        } else {
+            let g1 = ln_gamma_one_minus(self.shape).exp_m1();
+            Some(self.location + self.scale * g1 / self.shape)
+        }
+    }
+    /// Returns the variance of the generalized extreme value distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 0.5`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * (Γ(1 - 2ξ) - Γ(1 - ξ)^2) / ξ^2
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `Γ` is the gamma
+    /// function, and `(Γ(1 - 2ξ) - Γ(1 - ξ)^2) / ξ^2` is taken as `π^2 / 6`
+    /// for `ξ = 0`
+    fn variance(&self) -> Option<f64> {
+        if self.shape >= 0.5 {
+            None
+        } else {
+            Some(self.scale * self.scale * variance_factor(self.shape))
+        }
+    }
+    /// Returns the entropy of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + γξ + γ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `γ` is the
+    /// Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + consts::EULER_MASCHERONI * (self.shape + 1.0) + 1.0)
+    }
+    /// Returns the skewness of the generalized extreme value distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 1 / 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sgn(ξ) * (g_3 - 3g_1 * g_2 + 2g_1^3) / (g_2 - g_1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `g_k = Γ(1 - kξ)`, `ξ` is the shape and `Γ` is the gamma
+    /// function, which tends to `12 * sqrt(6) * ζ(3) / π^3` for `ξ = 0`
+    fn skewness(&self) -> Option<f64> {
+        if self.shape >= 1.0 / 3.0 {
+            None
+        } else {
+            Some(skewness_unchecked(self.shape))
+        }
+    }
+}
+
+impl Median<f64> for GeneralizedExtremeValue {
+    /// Returns the median of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (ln(2)^-ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `(ln(2)^-ξ - 1) / ξ` is taken as `-ln(ln(2))` for `ξ = 0`
+    fn median(&self) -> f64 {
+        self.quantile_from_ln_cdf(f64::consts::LN_2)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedExtremeValue {
+    /// Returns the mode of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ > -1 {
+    ///     μ + σ * ((1 + ξ)^-ξ - 1) / ξ
+    /// } else {
+    ///     μ - σ / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `((1 + ξ)^-ξ - 1) / ξ` is taken as `0` for `ξ = 0`
+    fn mode(&self) -> Option<f64> {
+        if self.shape > -1.0 {
+            let z = box_cox(-self.shape.ln_1p(), self.shape);
+            Some(self.location + self.scale * z)
+        } else {
+            Some(self.max())
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedExtremeValue {
+    /// Calculates the probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) * t^(ξ + 1) * e^-t
+    /// ```
+    ///
+    /// where `t = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, `μ` is the location, `σ` is
+    /// the scale and `ξ` is the shape, and `t` is taken as `e^(-(x - μ) /
+    /// σ)` for `ξ = 0`
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / σ) * t^(ξ + 1) * e^-t)
+    /// ```
+    ///
+    /// where `t = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, `μ` is the location, `σ` is
+    /// the scale and `ξ` is the shape, and `t` is taken as `e^(-(x - μ) /
+    /// σ)` for `ξ = 0`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        match self.reduced_variate(x) {
+            Some(y) => -self.scale.ln() - (self.shape + 1.0) * y - (-y).exp(),
+            None => f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// Returns `(e^(ξx) - 1) / ξ`, which tends to `x` as `ξ` tends to zero
+pub fn box_cox(x: f64, xi: f64) -> f64 {
+    if xi == 0.0 {
+        x
+    } else {
+        (xi * x).exp_m1() / xi
+    }
+}
+
+/// The values of the Riemann zeta function `ζ(k)` for `k = 2..=16`
+const ZETA: [f64; 15] = [
+    1.644_934_066_848_226_436_47,
+    1.202_056_903_159_594_285_4,
+    1.082_323_233_711_138_191_52,
+    1.036_927_755_143_369_926_33,
+    1.017_343_061_984_449_139_71,
+    1.008_349_277_381_922_826_84,
+    1.004_077_356_197_944_339_38,
+    1.002_008_392_826_082_214_42,
+    1.000_994_575_127_818_085_34,
+    1.000_494_188_604_119_464_56,
+    1.000_246_086_553_308_048_3,
+    1.000_122_713_347_578_489_15,
+    1.000_061_248_135_058_704_83,
+    1.000_030_588_236_307_020_49,
+    1.000_015_282_259_408_651_87,
+];
+
+/// The shapes below which the gamma function terms of the moments are
+/// summed from the series of `ln(Γ(1 - ξ))`, avoiding cancellation
+const SERIES_SHAPE: f64 = 0.1;
+
+/// Returns `Σ ζ(k) * w(k) * ξ^(k - m) / k` over `k >= max(2, m)`, the tail
+/// of the series `ln(Γ(1 - ξ)) = γξ + Σ ζ(k) ξ^k / k` weighted by `w` and
+/// divided by `ξ^m`
+fn zeta_series<W: Fn(i32) -> f64>(xi: f64, m: i32, w: W) -> f64 {
+    let mut sum = 0.0;
+    for k in (2.max(m)..=40).rev() {
+        let zeta = ZETA
+            .get(k as usize - 2)
+            .copied()
+            .unwrap_or_else(|| 1.0 + 2f64.powi(-k) + 3f64.powi(-k));
+        sum = sum * xi + zeta * w(k) / f64::from(k);
+    }
+    sum * xi.powi(2.max(m) - m)
+}
+
+/// Returns `ln(Γ(1 - ξ))` for `ξ < 1`
+pub fn ln_gamma_one_minus(xi: f64) -> f64 {
+    if xi.abs() < SERIES_SHAPE {
+        consts::EULER_MASCHERONI * xi + zeta_series(xi, 0, |_| 1.0)
+    } else {
+        gamma::ln_gamma(1.0 - xi)
+    }
+}
+
+/// Returns `(Γ(1 - 2ξ) - Γ(1 - ξ)^2) / ξ^2`, the variance of the standard
+/// generalized extreme value distribution, for `ξ < 1 / 2`
+pub fn variance_factor(xi: f64) -> f64 {
+    // a = ln(Γ(1 - 2ξ)) - 2 * ln(Γ(1 - ξ))
+    let a_over = if xi.abs() < SERIES_SHAPE {
+        zeta_series(xi, 2, |k| 2f64.powi(k) - 2.0)
+    } else {
+        (gamma::ln_gamma(1.0 - 2.0 * xi) - 2.0 * gamma::ln_gamma(1.0 - xi)) / (xi * xi)
+    };
+    let a = a_over * xi * xi;
+    let g1 = ln_gamma_one_minus(xi).exp();
+    g1 * g1 * a_over * exprel(a)
+}
+
+/// Returns the skewness of the generalized extreme value distribution with
+/// shape `ξ < 1 / 3`
+pub fn skewness_unchecked(xi: f64) -> f64 {
+    // with a = ln(g_2 / g_1^2) and b = ln(g_3 / g_1^3), the skewness is
+    // (e^b - 1 - 3(e^a - 1)) / (e^a - 1)^(3 / 2), whose numerator and
+    // denominator are of order ξ^3
+    let (a_over, numerator) = if xi.abs() < SERIES_SHAPE {
+        let a_over = zeta_series(xi, 2, |k| 2f64.powi(k) - 2.0);
+        let b_over = zeta_series(xi, 2, |k| 3f64.powi(k) - 3.0);
+        let mut numerator = zeta_series(xi, 3, |k| 3f64.powi(k) - 3.0 * 2f64.powi(k) + 3.0);
+        let mut factorial = 1.0;
+        for n in 2..10 {
+            factorial *= f64::from(n);
+            numerator += xi.powi(2 * n - 3) * (b_over.powi(n) - 3.0 * a_over.powi(n)) / factorial;
+        }
+        (a_over, numerator)
+    } else {
+        let l1 = gamma::ln_gamma(1.0 - xi);
+        let a = gamma::ln_gamma(1.0 - 2.0 * xi) - 2.0 * l1;
+        let b = gamma::ln_gamma(1.0 - 3.0 * xi) - 3.0 * l1;
+        (
+            a / (xi * xi),
+            (b.exp_m1() - 3.0 * a.exp_m1()) / (xi * xi * xi),
+        )
+    };
+    numerator / (a_over * exprel(a_over * xi * xi)).powf(1.5)
+}
+
+/// Returns `(e^x - 1) / x`, which tends to `1` as `x` tends to zero
+fn exprel(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        x.exp_m1() / x
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedExtremeValue};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedExtremeValue {
+        let n = GeneralizedExtremeValue::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64) {
+        let n = try_create(location, scale, shape);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64) {
+        let n = GeneralizedExtremeValue::new(location, scale, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(GeneralizedExtremeValue) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedExtremeValue) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0, 0.0);
+        create_case(1.0, 2.0, 0.2);
+        create_case(-1.0, 0.5, -0.3);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 0.0);
+        bad_create_case(0.0, f64::NAN, 0.0);
+        bad_create_case(0.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 0.0, 0.0);
+        bad_create_case(0.0, -1.0, 0.0);
+        bad_create_case(0.0, f64::INFINITY, 0.0);
+        bad_create_case(0.0, 1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: GeneralizedExtremeValue| x.mean().unwrap();
+        test_almost(0.0, 1.0, 0.0, 0.57721566490153286061, 1e-16, mean);
+        test_almost(1.0, 2.0, 0.2, 2.6422971372530337699, 1e-14, mean);
+        test_almost(-1.0, 0.5, -0.3, -0.82911782717712864398, 1e-15, mean);
+        test_almost(0.0, 1.0, 1e-9, 0.57721566589058885684, 1e-16, mean);
+        test_almost(0.0, 1.0, 0.05, 0.62906634258064392633, 1e-15, mean);
+        test_almost(0.0, 1.0, -0.05, 0.52991468874448713344, 1e-15, mean);
+        assert!(try_create(0.0, 1.0, 1.0).mean().is_none());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: GeneralizedExtremeValue| x.variance().unwrap();
+        test_almost(0.0, 1.0, 0.0, 1.6449340668482264365, 1e-15, variance);
+        test_almost(1.0, 2.0, 0.2, 13.376142249191526286, 1e-13, variance);
+        test_almost(-1.0, 0.5, -0.3, 0.24461582933115623134, 1e-14, variance);
+        test_almost(0.0, 1.0, 1e-9, 1.6449340711513036767, 1e-15, variance);
+        test_almost(0.0, 1.0, 0.05, 1.8931026811341969801, 1e-14, variance);
+        test_almost(0.0, 1.0, -0.05, 1.4560859191815921993, 1e-14, variance);
+        assert!(try_create(0.0, 1.0, 0.5).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: GeneralizedExtremeValue| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 0.0, 1.5772156649015328606, 1e-15, entropy);
+        test_almost(1.0, 2.0, 0.2, 2.3858059784417847486, 1e-15, entropy);
+        test_almost(-1.0, 0.5, -0.3, 0.71090378487112769942, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: GeneralizedExtremeValue| x.skewness().unwrap();
+        test_almost(0.0, 1.0, 0.0, 1.1395470994046486575, 1e-15, skewness);
+        test_almost(1.0, 2.0, 0.2, 3.5350716046213948879, 1e-12, skewness);
+        test_almost(-1.0, 0.5, -0.3, -0.068742099420967060803, 1e-12, skewness);
+        test_almost(0.0, 1.0, 1e-9, 1.1395471053712610824, 1e-15, skewness);
+        test_almost(0.0, 1.0, 0.05, 1.4738841312984271208, 1e-13, skewness);
+        test_almost(0.0, 1.0, -0.05, 0.86796509517451088306, 1e-13, skewness);
+        test_almost(0.0, 1.0, -1.0, -2.0, 1e-12, skewness);
+        assert!(try_create(0.0, 1.0, 0.4).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedExtremeValue| x.median();
+        test_almost(0.0, 1.0, 0.0, 0.36651292058166432701, 1e-16, median);
+        test_almost(1.0, 2.0, 0.2, 1.7605608513900512215, 1e-15, median);
+        test_almost(-1.0, 0.5, -0.3, -0.82645909257796486733, 1e-15, median);
+        test_almost(0.0, 1.0, 1e-9, 0.3665129206488301875, 1e-16, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: GeneralizedExtremeValue| x.mode().unwrap();
+        test_case(0.0, 1.0, 0.0, 0.0, mode);
+        test_almost(1.0, 2.0, 0.2, 0.64192504002627199166, 1e-15, mode);
+        test_almost(-1.0, 0.5, -0.3, -0.83087240298439960943, 1e-15, mode);
+        test_almost(0.0, 1.0, 1e-9, -9.9999999950000006211e-10, 1e-20, mode);
+        test_case(0.0, 1.0, -2.0, 0.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: GeneralizedExtremeValue| x.min();
+        let max = |x: GeneralizedExtremeValue| x.max();
+        test_case(0.0, 1.0, 0.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, 0.0, f64::INFINITY, max);
+        test_case(1.0, 2.0, 0.2, -9.0, min);
+        test_case(1.0, 2.0, 0.2, f64::INFINITY, max);
+        test_case(-1.0, 0.5, -0.25, f64::NEG_INFINITY, min);
+        test_case(-1.0, 0.5, -0.25, 1.0, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedExtremeValue| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.0, 0.0045662814201279156438, 1e-17, pdf(-2.0));
+        test_almost(0.0, 1.0, 0.0, 0.3678794411714423216, 1e-16, pdf(0.0));
+        test_almost(0.0, 1.0, 0.0, 0.000045397868655649819771, 1e-19, pdf(10.0));
+        test_almost(1.0, 2.0, 0.2, 0.011075726759846097968, 1e-16, pdf(-2.0));
+        test_almost(1.0, 2.0, 0.2, 0.11203386432543155235, 1e-16, pdf(3.0));
+        test_almost(1.0, 2.0, 0.2, 0.010207254306753940089, 1e-16, pdf(10.0));
+        test_almost(-1.0, 0.5, -0.3, 0.049742859823105002246, 1e-16, pdf(-2.0));
+        test_almost(-1.0, 0.5, -0.3, 0.64169290689971433519, 1e-15, pdf(-0.5));
+        test_case(-1.0, 0.5, -0.3, 0.0, pdf(1.0));
+        test_case(1.0, 2.0, 0.2, 0.0, pdf(-10.0));
+        test_almost(0.0, 1.0, 1e-9, 0.25464637986941972193, 1e-16, pdf(1.0));
+        test_almost(0.0, 1.0, 0.05, 0.049981563636585988467, 1e-16, pdf(3.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: GeneralizedExtremeValue| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, 0.0, -5.3890560989306502272, 1e-14, ln_pdf(-2.0));
+        test_almost(1.0, 2.0, 0.2, -2.1889540933401339787, 1e-15, ln_pdf(3.0));
+        test_almost(-1.0, 0.5, -0.3, -1.4920201303290134448, 1e-14, ln_pdf(0.0));
+        test_case(-1.0, 0.5, -0.3, f64::NEG_INFINITY, ln_pdf(1.0));
+        test_case(0.0, 1.0, 0.0, f64::NEG_INFINITY, ln_pdf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, -0.05, -13.16979738431327757, 1e-13, ln_pdf(10.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedExtremeValue| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.0, 0.00061797898933109349862, 1e-18, cdf(-2.0));
+        test_almost(0.0, 1.0, 0.0, 0.69220062755534635387, 1e-16, cdf(1.0));
+        test_almost(1.0, 2.0, 0.2, 0.0026060963551382670457, 1e-17, cdf(-2.0));
+        test_almost(1.0, 2.0, 0.2, 0.96041854290456670381, 1e-15, cdf(10.0));
+        test_almost(-1.0, 0.5, -0.3, 0.73745436356275463916, 1e-15, cdf(-0.5));
+        test_case(-1.0, 0.5, -0.3, 1.0, cdf(1.0));
+        test_case(1.0, 2.0, 0.2, 0.0, cdf(-10.0));
+        test_almost(0.0, 1.0, 1e-9, 0.95143199268729286371, 1e-16, cdf(3.0));
+        test_almost(0.0, 1.0, -0.05, 0.0011975230872484933828, 1e-18, cdf(-2.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: GeneralizedExtremeValue| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 0.0, -1.5271796258079011047, 1e-15, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, 0.0, 4.6001492267765791051, 1e-14, inverse_cdf(0.99));
+        test_almost(1.0, 2.0, 0.2, -1.6319788620658195225, 1e-14, inverse_cdf(0.01));
+        test_almost(1.0, 2.0, 0.2, 16.093652817171562853, 1e-13, inverse_cdf(0.99));
+        test_almost(-1.0, 0.5, -0.3, -1.9685871738314212621, 1e-15, inverse_cdf(0.01));
+        test_almost(-1.0, 0.5, -0.3, 0.24738784894183696984, 1e-14, inverse_cdf(0.99));
+        test_almost(0.0, 1.0, 1e-9, 4.6001492373572655756, 1e-14, inverse_cdf(0.99));
+        test_case(1.0, 2.0, 0.2, -9.0, inverse_cdf(0.0));
+        test_case(1.0, 2.0, 0.2, f64::INFINITY, inverse_cdf(1.0));
+        test_case(-1.0, 0.5, -0.25, 1.0, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0, 0.0).checked_inverse_cdf(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_return_level() {
+        let return_level = |arg: f64| move |x: GeneralizedExtremeValue| x.return_level(arg).unwrap();
+        test_almost(0.0, 1.0, 0.0, 4.6001492267765799977, 1e-14, return_level(100.0));
+        test_almost(1.0, 2.0, 0.2, 16.093652817171567333, 1e-13, return_level(100.0));
+        test_almost(-1.0, 0.5, -0.3, 0.24738784894183708212, 1e-15, return_level(100.0));
+        assert!(try_create(0.0, 1.0, 0.0).return_level(1.0).is_err());
+        assert!(try_create(0.0, 1.0, 0.0).return_level(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 0.0), -5.0, 40.0);
+        tests::check_continuous_distribution(&try_create(1.0, 2.0, 0.2), -5.0, 200.0);
+        tests::check_continuous_distribution(&try_create(-1.0, 0.5, -0.3), -4.0, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        for &(location, scale, shape) in &[(0.0, 1.0, 0.0), (1.0, 2.0, 0.2), (-1.0, 0.5, -0.3)] {
+            let n = try_create(location, scale, shape);
+            let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+            let mean = samples.iter().sum::<f64>() / 100_000.0;
+            let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+            assert!((mean - n.mean().unwrap()).abs() < 0.05);
+            assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.1);
+        }
+    }
+}
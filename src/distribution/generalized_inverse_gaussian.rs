@@ -0,0 +1,672 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::bessel;
+use crate::statistics::*;
+use crate::{quadrature, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Generalized inverse Gaussian](https://en.wikipedia.org/wiki/Generalized_inverse_Gaussian_distribution)
+/// distribution, which includes the inverse Gaussian distribution as the
+/// case `p = -1 / 2`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedInverseGaussian, Continuous};
+/// use statrs::statistics::Mode;
+/// use statrs::prec;
+///
+/// let n = GeneralizedInverseGaussian::new(2.0, 1.0, 0.5).unwrap();
+/// assert!(prec::almost_eq(n.mode().unwrap(), 0.5, 1e-16));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.5178076796073089, 1e-14));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedInverseGaussian {
+    a: f64,
+    b: f64,
+    p: f64,
+    ln_normalizer: f64,
+}
+
+impl GeneralizedInverseGaussian {
+    /// Constructs a new generalized inverse Gaussian distribution with the
+    /// density proportional to `x^(p - 1) * e^(-(ax + b / x) / 2)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `a`, `b` or `p` are `NaN` or infinite, or if
+    /// `a <= 0.0` or `b <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedInverseGaussian;
+    ///
+    /// let mut result = GeneralizedInverseGaussian::new(2.0, 1.0, 0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedInverseGaussian::new(0.0, 1.0, 0.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(a: f64, b: f64, p: f64) -> Result<GeneralizedInverseGaussian> {
+        if !(a.is_finite() && b.is_finite() && p.is_finite()) || a <= 0.0 || b <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let ln_normalizer =
+            0.5 * p * (a / b).ln() - f64::consts::LN_2 - bessel::ln_bessel_k(p, (a * b).sqrt());
+        if ln_normalizer.is_finite() {
+            Ok(GeneralizedInverseGaussian {
+                a,
+                b,
+                p,
+                ln_normalizer,
+            })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the coefficient `a` of `x` in the exponent of the generalized
+    /// inverse Gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedInverseGaussian;
+    ///
+    /// let n = GeneralizedInverseGaussian::new(2.0, 1.0, 0.5).unwrap();
+    /// assert_eq!(n.a(), 2.0);
+    /// ```
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    /// Returns the coefficient `b` of `1 / x` in the exponent of the
+    /// generalized inverse Gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedInverseGaussian;
+    ///
+    /// let n = GeneralizedInverseGaussian::new(2.0, 1.0, 0.5).unwrap();
+    /// assert_eq!(n.b(), 1.0);
+    /// ```
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    /// Returns the power `p` of the generalized inverse Gaussian
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedInverseGaussian;
+    ///
+    /// let n = GeneralizedInverseGaussian::new(2.0, 1.0, 0.5).unwrap();
+    /// assert_eq!(n.p(), 0.5);
+    /// ```
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    /// Returns `E[X^k]`, given by `(b / a)^(k / 2) * K_(p + k)(ω) / K_p(ω)`
+    /// with `ω = sqrt(ab)`
+    fn raw_moment(&self, k: f64) -> f64 {
+        let omega = (self.a * self.b).sqrt();
+        (0.5 * k * (self.b / self.a).ln() + bessel::ln_bessel_k(self.p + k, omega)
+            - bessel::ln_bessel_k(self.p, omega))
+        .exp()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedInverseGaussian {
+    /// Draws a sample with the algorithms of Hörmann and Leydold (2014),
+    /// "Generating generalized inverse Gaussian random variates", applied to
+    /// the standardized density `x^(p - 1) * e^(-ω(x + 1 / x) / 2)`
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let omega = (self.a * self.b).sqrt();
+        let eta = (self.b / self.a).sqrt();
+        // 1 / X is GIG distributed with power -p
+        if self.p < 0.0 {
+            eta / sample_standard(r, -self.p, omega)
+        } else {
+            eta * sample_standard(r, self.p, omega)
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedInverseGaussian {
+    /// Calculates the cumulative distribution function for the generalized
+    /// inverse Gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ f(t) dt from 0 to x
+    /// ```
+    ///
+    /// where `f` is the pdf
+    ///
+    /// # Remarks
+    ///
+    /// The cdf has no closed form and is integrated numerically, over the
+    /// lower tail below the mode and the upper tail above it. Returns
+    /// `f64::NAN` if the integration fails to converge.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x == f64::INFINITY {
+            return 1.0;
+        }
+        let pdf = |t: f64| self.pdf(t);
+        if x <= self.mode().unwrap() {
+            quadrature::gauss_kronrod(pdf, 0.0, x, 1e-13, 1000).map_or(f64::NAN, |i| i.value)
+        } else {
+            quadrature::gauss_kronrod(pdf, x, f64::INFINITY, 1e-13, 1000)
+                .map_or(f64::NAN, |i| 1.0 - i.value)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized inverse Gaussian distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        internal::inverse_cdf_newton(self, p)
+    }
+}
+
+impl Min<f64> for GeneralizedInverseGaussian {
+    /// Returns the minimum value in the domain of the generalized inverse
+    /// Gaussian distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for GeneralizedInverseGaussian {
+    /// Returns the maximum value in the domain of the generalized inverse
+    /// Gaussian distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for GeneralizedInverseGaussian {
+    /// Returns the mean of the generalized inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(b / a) * K_(p + 1)(sqrt(ab)) / K_p(sqrt(ab))
+    /// ```
+    ///
+    /// where `K` is the modified Bessel function of the second kind
+    fn mean(&self) -> Option<f64> {
+        Some(self.raw_moment(1.0))
+    }
+    /// Returns the variance of the generalized inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (b / a) * (K_(p + 2)(ω) / K_p(ω) - (K_(p + 1)(ω) / K_p(ω))^2)
+    /// ```
+    ///
+    /// where `ω = sqrt(ab)` and `K` is the modified Bessel function of the
+    /// second kind
+    fn variance(&self) -> Option<f64> {
+        let mean = self.raw_moment(1.0);
+        Some(self.raw_moment(2.0) - mean * mean)
+    }
+    /// Returns the entropy of the generalized inverse Gaussian distribution
+    ///
+    /// # None
+    ///
+    /// If the numerical integration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The entropy involves the derivative of `K_p` in its order and is
+    /// computed as `E[-ln f(X)]` by numerical integration instead
+    fn entropy(&self) -> Option<f64> {
+        quadrature::expectation(self, |x| -self.ln_pdf(x)).ok()
+    }
+    /// Returns the skewness of the generalized inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (m_3 - 3m_1 * m_2 + 2m_1^3) / (m_2 - m_1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k = E[X^k] = (b / a)^(k / 2) * K_(p + k)(ω) / K_p(ω)`,
+    /// `ω = sqrt(ab)` and `K` is the modified Bessel function of the second
+    /// kind
+    fn skewness(&self) -> Option<f64> {
+        let m1 = self.raw_moment(1.0);
+        let m2 = self.raw_moment(2.0);
+        let m3 = self.raw_moment(3.0);
+        let variance = m2 - m1 * m1;
+        Some((m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / variance.powf(1.5))
+    }
+}
+
+impl Median<f64> for GeneralizedInverseGaussian {
+    /// Returns the median of the generalized inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 / 2)
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedInverseGaussian {
+    /// Returns the mode of the generalized inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (p - 1 + sqrt((p - 1)^2 + ab)) / a
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        let q = self.p - 1.0;
+        let root = (q * q + self.a * self.b).sqrt();
+        // pick whichever form avoids cancellation
+        if q >= 0.0 {
+            Some((q + root) / self.a)
+        } else {
+            Some(self.b / (root - q))
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedInverseGaussian {
+    /// Calculates the probability density function for the generalized
+    /// inverse Gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (a / b)^(p / 2) / (2K_p(sqrt(ab))) * x^(p - 1) * e^(-(ax + b / x) / 2)
+    /// ```
+    ///
+    /// where `K` is the modified Bessel function of the second kind
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// inverse Gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((a / b)^(p / 2) / (2K_p(sqrt(ab))) * x^(p - 1) * e^(-(ax + b / x) / 2))
+    /// ```
+    ///
+    /// where `K` is the modified Bessel function of the second kind
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        self.ln_normalizer + (self.p - 1.0) * x.ln() - 0.5 * (self.a * x + self.b / x)
+    }
+}
+
+/// Returns `ln(x^(p - 1) * e^(-ω(x + 1 / x) / 2))`, the log of the
+/// unnormalized standardized density
+fn ln_quasi_pdf(x: f64, p: f64, omega: f64) -> f64 {
+    (p - 1.0) * x.ln() - 0.5 * omega * (x + 1.0 / x)
+}
+
+/// Draws a sample from the standardized density with `p >= 0`, by ratio of
+/// uniforms with or without a mode shift where the density is concentrated
+/// enough, and by rejection from a three-piece envelope otherwise
+fn sample_standard<R: Rng + ?Sized>(r: &mut R, p: f64, omega: f64) -> f64 {
+    let q = p - 1.0;
+    let mode = omega / ((q * q + omega * omega).sqrt() - q);
+    if p >= 1.0 || omega > 1.0 {
+        sample_ratio_of_uniforms_shifted(r, p, omega, mode)
+    } else if omega >= (2.0 / 3.0 * (1.0 - p).sqrt()).min(0.5) {
+        sample_ratio_of_uniforms(r, p, omega, mode)
+    } else {
+        sample_rejection(r, p, omega, mode)
+    }
+}
+
+/// Ratio of uniforms around the mode, with the bounding rectangle found
+/// from the roots of a cubic
+fn sample_ratio_of_uniforms_shifted<R: Rng + ?Sized>(
+    r: &mut R,
+    p: f64,
+    omega: f64,
+    mode: f64,
+) -> f64 {
+    // the density is rescaled by its value at the mode, so that it can be
+    // evaluated for large p and ω
+    let ln_mode = ln_quasi_pdf(mode, p, omega);
+    let a2 = -2.0 * (p + 1.0) / omega - mode;
+    let a1 = 2.0 * mode * (p - 1.0) / omega - 1.0;
+    // the roots of x^3 + a2 * x^2 + a1 * x + mode by Cardano's formula
+    let p1 = a1 - a2 * a2 / 3.0;
+    let q1 = 2.0 * a2 * a2 * a2 / 27.0 - a2 * a1 / 3.0 + mode;
+    let phi = (-0.5 * q1 * (-27.0 / (p1 * p1 * p1)).sqrt()).acos();
+    let s1 = -(-4.0 * p1 / 3.0).sqrt();
+    let root1 = s1 * (phi / 3.0 + f64::consts::FRAC_PI_3).cos() - a2 / 3.0;
+    let root2 = -s1 * (phi / 3.0).cos() - a2 / 3.0;
+    let v_min = (root1 - mode) * (0.5 * (ln_quasi_pdf(root1, p, omega) - ln_mode)).exp();
+    let v_max = (root2 - mode) * (0.5 * (ln_quasi_pdf(root2, p, omega) - ln_mode)).exp();
+    loop {
+        let u = r.gen::<f64>();
+        let v = v_min + (v_max - v_min) * r.gen::<f64>();
+        let x = v / u + mode;
+        if x > 0.0 && 2.0 * u.ln() <= ln_quasi_pdf(x, p, omega) - ln_mode {
+            return x;
+        }
+    }
+}
+
+/// Ratio of uniforms around the origin
+fn sample_ratio_of_uniforms<R: Rng + ?Sized>(r: &mut R, p: f64, omega: f64, mode: f64) -> f64 {
+    let u_max = (0.5 * ln_quasi_pdf(mode, p, omega)).exp();
+    let x_plus = ((1.0 + p) + ((1.0 + p) * (1.0 + p) + omega * omega).sqrt()) / omega;
+    let v_max = x_plus * (0.5 * ln_quasi_pdf(x_plus, p, omega)).exp();
+    loop {
+        let u = u_max * r.gen::<f64>();
+        let v = v_max * r.gen::<f64>();
+        let x = v / u;
+        if 2.0 * u.ln() <= ln_quasi_pdf(x, p, omega) {
+            return x;
+        }
+    }
+}
+
+/// Rejection from an envelope that is constant below `x0 = ω / (1 - p)`,
+/// proportional to `x^(p - 1)` up to `2 / ω` and exponential beyond, for
+/// `0 <= p < 1` and small `ω`
+fn sample_rejection<R: Rng + ?Sized>(r: &mut R, p: f64, omega: f64, mode: f64) -> f64 {
+    let x0 = omega / (1.0 - p);
+    let xs = x0.max(2.0 / omega);
+    let k1 = ln_quasi_pdf(mode, p, omega).exp();
+    let area1 = k1 * x0;
+    let (k2, area2) = if x0 < 2.0 / omega {
+        let k2 = (-omega).exp();
+        let area2 = if p > 0.0 {
+            k2 * ((2.0 / omega).powf(p) - x0.powf(p)) / p
+        } else {
+            k2 * (2.0 / (omega * omega)).ln()
+        };
+        (k2, area2)
+    } else {
+        (0.0, 0.0)
+    };
+    let k3 = xs.powf(p - 1.0);
+    let area3 = 2.0 * k3 * (-0.5 * xs * omega).exp() / omega;
+    let area = area1 + area2 + area3;
+    loop {
+        let u = r.gen::<f64>();
+        let v = area * r.gen::<f64>();
+        let (x, envelope) = if v <= area1 {
+            (x0 * v / area1, k1)
+        } else if v <= area1 + area2 {
+            let v = v - area1;
+            let x = if p > 0.0 {
+                (x0.powf(p) + v * p / k2).powf(1.0 / p)
+            } else {
+                omega * (v * omega.exp()).exp()
+            };
+            (x, k2 * x.powf(p - 1.0))
+        } else {
+            let v = v - area1 - area2;
+            let z = (-0.5 * xs * omega).exp() - 0.5 * omega * v / k3;
+            let x = -2.0 / omega * z.ln();
+            (x, k3 * (-0.5 * x * omega).exp())
+        };
+        if (u * envelope).ln() <= ln_quasi_pdf(x, p, omega) {
+            return x;
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedInverseGaussian, InverseGaussian};
+    use crate::distribution::internal::*;
+
+    fn try_create(a: f64, b: f64, p: f64) -> GeneralizedInverseGaussian {
+        let n = GeneralizedInverseGaussian::new(a, b, p);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(a: f64, b: f64, p: f64) {
+        let n = try_create(a, b, p);
+        assert_eq!(a, n.a());
+        assert_eq!(b, n.b());
+        assert_eq!(p, n.p());
+    }
+
+    fn bad_create_case(a: f64, b: f64, p: f64) {
+        let n = GeneralizedInverseGaussian::new(a, b, p);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(a: f64, b: f64, p: f64, expected: f64, eval: F)
+        where F: Fn(GeneralizedInverseGaussian) -> f64
+    {
+        let n = try_create(a, b, p);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(a: f64, b: f64, p: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedInverseGaussian) -> f64
+    {
+        let n = try_create(a, b, p);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(2.0, 1.0, 0.5);
+        create_case(1.0, 3.0, -1.5);
+        create_case(0.5, 0.1, 0.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 1.0);
+        bad_create_case(1.0, f64::NAN, 1.0);
+        bad_create_case(1.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 1.0, 1.0);
+        bad_create_case(1.0, 0.0, 1.0);
+        bad_create_case(-1.0, 1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0);
+        bad_create_case(1.0, 1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: GeneralizedInverseGaussian| x.mean().unwrap();
+        test_almost(2.0, 1.0, 0.5, 1.2071067811865475244, 1e-14, mean);
+        test_almost(1.0, 3.0, -1.5, 1.0980762113533159403, 1e-14, mean);
+        test_almost(0.5, 0.1, 0.2, 1.5999961174011120321, 1e-14, mean);
+        test_almost(10.0, 10.0, 3.0, 1.3892728722073916354, 1e-14, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: GeneralizedInverseGaussian| x.variance().unwrap();
+        test_almost(2.0, 1.0, 0.5, 0.8535533905932737622, 1e-14, variance);
+        test_almost(1.0, 3.0, -1.5, 0.69615242270663188058, 1e-14, variance);
+        test_almost(0.5, 0.1, 0.2, 5.3199937878267046884, 1e-13, variance);
+        test_almost(10.0, 10.0, 3.0, 0.18133918431453777848, 1e-14, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: GeneralizedInverseGaussian| x.skewness().unwrap();
+        test_almost(2.0, 1.0, 0.5, 1.9406136360008053785, 1e-13, skewness);
+        test_almost(1.0, 3.0, -1.5, 2.7181983946056084623, 1e-13, skewness);
+        test_almost(0.5, 0.1, 0.2, 3.2624219608140758476, 1e-13, skewness);
+        test_almost(10.0, 10.0, 3.0, 0.8222567197105014691, 1e-12, skewness);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: GeneralizedInverseGaussian| x.entropy().unwrap();
+        test_almost(2.0, 1.0, 0.5, 1.036540777750783958, 1e-9, entropy);
+        test_almost(1.0, 3.0, -1.5, 0.84118774159019791488, 1e-9, entropy);
+        test_almost(0.5, 0.1, 0.2, 1.3572610553519978142, 1e-9, entropy);
+        test_almost(10.0, 10.0, 3.0, 0.50940069216460337302, 1e-9, entropy);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedInverseGaussian| x.median();
+        test_almost(2.0, 1.0, 0.5, 0.94871377341687134388, 1e-11, median);
+        test_almost(1.0, 3.0, -1.5, 0.85739487445749031452, 1e-11, median);
+        test_almost(0.5, 0.1, 0.2, 0.72506297265203022444, 1e-11, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: GeneralizedInverseGaussian| x.mode().unwrap();
+        test_case(2.0, 1.0, 0.5, 0.5, mode);
+        test_almost(1.0, 3.0, -1.5, 0.5413812651491098445, 1e-15, mode);
+        test_almost(0.5, 0.1, 0.2, 0.061324772583614973858, 1e-16, mode);
+        test_almost(10.0, 10.0, 3.0, 1.219803902718556966, 1e-15, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: GeneralizedInverseGaussian| x.min();
+        let max = |x: GeneralizedInverseGaussian| x.max();
+        test_case(2.0, 1.0, 0.5, 0.0, min);
+        test_case(2.0, 1.0, 0.5, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedInverseGaussian| x.pdf(arg);
+        test_almost(2.0, 1.0, 0.5, 0.044741274610001670541, 1e-15, pdf(0.1));
+        test_almost(2.0, 1.0, 0.5, 0.73229064320159853935, 1e-15, pdf(0.5));
+        test_almost(1.0, 3.0, -1.5, 0.0003946311215475181539, 1e-17, pdf(0.1));
+        test_almost(1.0, 3.0, -1.5, 0.13174463904146880077, 1e-15, pdf(2.0));
+        test_almost(0.5, 0.1, 0.2, 1.277456843283751243, 1e-14, pdf(0.1));
+        test_almost(0.5, 0.1, 0.2, 0.026789449509809433079, 1e-16, pdf(5.0));
+        test_case(2.0, 1.0, 0.5, 0.0, pdf(0.0));
+        test_case(2.0, 1.0, 0.5, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: GeneralizedInverseGaussian| x.ln_pdf(arg);
+        test_almost(2.0, 1.0, 0.5, -1.754724970831577693, 1e-14, ln_pdf(2.0));
+        test_almost(1.0, 3.0, -1.5, -7.8375590988908977101, 1e-14, ln_pdf(0.1));
+        test_almost(1.0, 3.0, -1.5, -5.3676166124612628567, 1e-14, ln_pdf(5.0));
+        test_almost(0.5, 0.1, 0.2, -0.7426790695875490921, 1e-15, ln_pdf(0.5));
+        test_case(2.0, 1.0, 0.5, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(2.0, 1.0, 0.5, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedInverseGaussian| x.cdf(arg);
+        test_almost(2.0, 1.0, 0.5, 0.00071781516096012672617, 1e-15, cdf(0.1));
+        test_almost(2.0, 1.0, 0.5, 0.52724280918212688927, 1e-13, cdf(1.0));
+        test_almost(2.0, 1.0, 0.5, 0.99409152009040045733, 1e-13, cdf(5.0));
+        test_almost(1.0, 3.0, -1.5, 2.7241792251847670719e-6, 1e-18, cdf(0.1));
+        test_almost(1.0, 3.0, -1.5, 0.8908617586768203684, 1e-13, cdf(2.0));
+        test_almost(0.5, 0.1, 0.2, 0.10524037922614493543, 1e-13, cdf(0.1));
+        test_almost(0.5, 0.1, 0.2, 0.92558918424197656638, 1e-13, cdf(5.0));
+        test_case(2.0, 1.0, 0.5, 0.0, cdf(0.0));
+        test_case(2.0, 1.0, 0.5, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: GeneralizedInverseGaussian| x.inverse_cdf(arg);
+        test_almost(2.0, 1.0, 0.5, 0.16795593637141987581, 1e-11, inverse_cdf(0.01));
+        test_almost(2.0, 1.0, 0.5, 4.5097808870320372661, 1e-10, inverse_cdf(0.99));
+        test_almost(1.0, 3.0, -1.5, 0.23640591983223295842, 1e-11, inverse_cdf(0.01));
+        test_almost(1.0, 3.0, -1.5, 4.300287720346272561, 1e-10, inverse_cdf(0.99));
+        test_almost(0.5, 0.1, 0.2, 0.027655214989902248343, 1e-11, inverse_cdf(0.01));
+        test_almost(0.5, 0.1, 0.2, 11.123962912690093199, 1e-10, inverse_cdf(0.99));
+        assert!(try_create(2.0, 1.0, 0.5).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_inverse_gaussian() {
+        // the inverse Gaussian with mean μ and shape λ has a = λ / μ^2,
+        // b = λ and p = -1 / 2
+        let gig = try_create(5.0 / 4.0, 5.0, -0.5);
+        let ig = InverseGaussian::new(2.0, 5.0).unwrap();
+        assert_almost_eq!(gig.mean().unwrap(), 2.0, 1e-14);
+        assert_almost_eq!(gig.variance().unwrap(), ig.variance().unwrap(), 1e-14);
+        for &x in &[0.1, 0.5, 2.0, 10.0] {
+            assert_almost_eq!(gig.ln_pdf(x), ig.ln_pdf(x), 1e-13);
+            assert_almost_eq!(gig.cdf(x), ig.cdf(x), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(2.0, 1.0, 0.5), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(1.0, 3.0, -1.5), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(0.5, 0.1, 0.2), 0.0, 20.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        // one case for each sampling method, and one for negative p
+        for &(a, b, p) in &[(2.0, 1.0, 0.5), (10.0, 10.0, 3.0), (1.0, 0.5, 0.5), (0.5, 0.1, 0.2), (1.0, 3.0, -1.5)] {
+            let mut r: StdRng = SeedableRng::seed_from_u64(42);
+            let n = try_create(a, b, p);
+            let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+            let mean = samples.iter().sum::<f64>() / 100_000.0;
+            assert!(samples.iter().all(|&x| x > 0.0));
+            assert!((mean - n.mean().unwrap()).abs() < 0.02 * n.variance().unwrap().sqrt().max(1.0));
+            let median = n.median();
+            let below = samples.iter().filter(|&&x| x < median).count() as f64 / 100_000.0;
+            assert!((below - 0.5).abs() < 0.01);
+        }
+    }
+}
@@ -0,0 +1,620 @@
+use crate::distribution::generalized_extreme_value::box_cox;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Generalized
+/// Pareto](https://en.wikipedia.org/wiki/Generalized_Pareto_distribution)
+/// distribution, the limiting distribution of exceedances over a high
+/// threshold. A shape of zero gives the [exponential](./struct.Exp.html)
+/// distribution shifted to the location.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedPareto, Continuous};
+/// use statrs::statistics::Distribution;
+///
+/// let n = GeneralizedPareto::new(0.0, 1.0, 0.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.0);
+/// assert_eq!(n.pdf(0.0), 1.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedPareto {
+    /// Constructs a new generalized Pareto distribution with a location (μ)
+    /// of `location`, a scale (σ) of `scale` and a shape (ξ) of `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the parameters are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let mut result = GeneralizedPareto::new(0.0, 1.0, 0.1);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedPareto::new(0.0, -1.0, 0.1);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedPareto> {
+        if location.is_finite() && scale.is_finite() && scale > 0.0 && shape.is_finite() {
+            Ok(GeneralizedPareto {
+                location,
+                scale,
+                shape,
+            })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the location of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.1).unwrap();
+    /// assert_eq!(n.shape(), 0.1);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the return level for a return period of `period`
+    /// exceedances, the level exceeded once every `period` exceedances of
+    /// the threshold on average
+    ///
+    /// # Errors
+    ///
+    /// If `period <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (T^ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `ξ` is the shape and `T`
+    /// is the period
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, GeneralizedPareto};
+    /// use statrs::prec;
+    ///
+    /// let n = GeneralizedPareto::new(10.0, 2.0, 0.1).unwrap();
+    /// let level = n.return_level(100.0).unwrap();
+    /// assert!(prec::almost_eq(n.cdf(level), 0.99, 1e-15));
+    /// ```
+    pub fn return_level(&self, period: f64) -> Result<f64> {
+        if period > 1.0 {
+            Ok(self.quantile_from_ln_sf(period.ln()))
+        } else {
+            Err(StatsError::ArgGt("period", 1.0))
+        }
+    }
+
+    /// Returns the value at which `-ln(1 - cdf)` equals `y`
+    fn quantile_from_ln_sf(&self, y: f64) -> f64 {
+        self.location + self.scale * box_cox(y, self.shape)
+    }
+
+    /// Returns `-ln(1 - cdf(x))`, or `None` outside the support
+    fn reduced_variate(&self, x: f64) -> Option<f64> {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 {
+            None
+        } else if self.shape == 0.0 {
+            Some(z)
+        } else if self.shape * z > -1.0 {
+            Some((self.shape * z).ln_1p() / self.shape)
+        } else {
+            None
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedPareto {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.quantile_from_ln_sf(-u.ln())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedPareto {
+    /// Calculates the cumulative distribution function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - (1 + ξz)^(-1 / ξ)
+    /// ```
+    ///
+    /// where `z = (x - μ) / σ`, `μ` is the location, `σ` is the scale and
+    /// `ξ` is the shape, and `(1 + ξz)^(-1 / ξ)` is taken as `e^-z` for
+    /// `ξ = 0`
+    fn cdf(&self, x: f64) -> f64 {
+        match self.reduced_variate(x) {
+            Some(y) => -(-y).exp_m1(),
+            None if x < self.location => 0.0,
+            None => 1.0,
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized Pareto distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * ((1 - p)^-ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `((1 - p)^-ξ - 1) / ξ` is taken as `-ln(1 - p)` for `ξ = 0`
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if (0.0..=1.0).contains(&p) {
+            Ok(self.quantile_from_ln_sf(-(-p).ln_1p()))
+        } else {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    /// Returns the minimum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    /// Returns the maximum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized Pareto distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ / (1 - ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale / (1.0 - self.shape))
+        }
+    }
+    /// Returns the variance of the generalized Pareto distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 0.5`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 / ((1 - ξ)^2 * (1 - 2ξ))
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn variance(&self) -> Option<f64> {
+        if self.shape >= 0.5 {
+            None
+        } else {
+            let a = 1.0 - self.shape;
+            Some(self.scale * self.scale / (a * a * (1.0 - 2.0 * self.shape)))
+        }
+    }
+    /// Returns the entropy of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + ξ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + self.shape + 1.0)
+    }
+    /// Returns the skewness of the generalized Pareto distribution
+    ///
+    /// # None
+    ///
+    /// If `ξ >= 1 / 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2(1 + ξ) * sqrt(1 - 2ξ) / (1 - 3ξ)
+    /// ```
+    ///
+    /// where `ξ` is the shape
+    fn skewness(&self) -> Option<f64> {
+        if self.shape >= 1.0 / 3.0 {
+            None
+        } else {
+            let xi = self.shape;
+            Some(2.0 * (1.0 + xi) * (1.0 - 2.0 * xi).sqrt() / (1.0 - 3.0 * xi))
+        }
+    }
+}
+
+impl Median<f64> for GeneralizedPareto {
+    /// Returns the median of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (2^ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `(2^ξ - 1) / ξ` is taken as `ln(2)` for `ξ = 0`
+    fn median(&self) -> f64 {
+        self.quantile_from_ln_sf(f64::consts::LN_2)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedPareto {
+    /// Returns the mode of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ >= -1 {
+    ///     μ
+    /// } else {
+    ///     μ - σ / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mode(&self) -> Option<f64> {
+        if self.shape >= -1.0 {
+            Some(self.location)
+        } else {
+            Some(self.max())
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    /// Calculates the probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) * (1 + ξz)^(-1 / ξ - 1)
+    /// ```
+    ///
+    /// where `z = (x - μ) / σ`, `μ` is the location, `σ` is the scale and
+    /// `ξ` is the shape, and `(1 + ξz)^(-1 / ξ - 1)` is taken as `e^-z` for
+    /// `ξ = 0`
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / σ) * (1 + ξz)^(-1 / ξ - 1))
+    /// ```
+    ///
+    /// where `z = (x - μ) / σ`, `μ` is the location, `σ` is the scale and
+    /// `ξ` is the shape, and `(1 + ξz)^(-1 / ξ - 1)` is taken as `e^-z` for
+    /// `ξ = 0`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        match self.reduced_variate(x) {
+            Some(y) => -self.scale.ln() - (1.0 + self.shape) * y,
+            None => f64::NEG_INFINITY,
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedPareto};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedPareto {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64) {
+        let n = try_create(location, scale, shape);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64) {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0, 0.0);
+        create_case(1.0, 2.0, 0.2);
+        create_case(-1.0, 0.5, -0.3);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 0.0);
+        bad_create_case(0.0, f64::NAN, 0.0);
+        bad_create_case(0.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 0.0, 0.0);
+        bad_create_case(0.0, -1.0, 0.0);
+        bad_create_case(f64::INFINITY, 1.0, 0.0);
+        bad_create_case(0.0, 1.0, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: GeneralizedPareto| x.mean().unwrap();
+        test_case(0.0, 1.0, 0.0, 1.0, mean);
+        test_almost(1.0, 2.0, 0.2, 3.5, 1e-15, mean);
+        test_almost(-1.0, 0.5, -0.3, -0.61538461538461538133, 1e-15, mean);
+        test_almost(0.0, 1.0, 1e-9, 1.000000001000000001, 1e-15, mean);
+        assert!(try_create(0.0, 1.0, 1.0).mean().is_none());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: GeneralizedPareto| x.variance().unwrap();
+        test_case(0.0, 1.0, 0.0, 1.0, variance);
+        test_almost(1.0, 2.0, 0.2, 10.416666666666667341, 1e-14, variance);
+        test_almost(-1.0, 0.5, -0.3, 0.092455621301775150791, 1e-16, variance);
+        assert!(try_create(0.0, 1.0, 0.5).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: GeneralizedPareto| x.entropy().unwrap();
+        test_case(0.0, 1.0, 0.0, 1.0, entropy);
+        test_almost(1.0, 2.0, 0.2, 1.8931471805599453205, 1e-15, entropy);
+        test_almost(-1.0, 0.5, -0.3, 0.006852819440054701685, 1e-16, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: GeneralizedPareto| x.skewness().unwrap();
+        test_case(0.0, 1.0, 0.0, 2.0, skewness);
+        test_almost(1.0, 2.0, 0.2, 4.6475800154489006062, 1e-14, skewness);
+        test_almost(-1.0, 0.5, -0.3, 0.93203973141804866987, 1e-15, skewness);
+        assert!(try_create(0.0, 1.0, 0.4).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedPareto| x.median();
+        test_case(0.0, 1.0, 0.0, f64::consts::LN_2, median);
+        test_almost(1.0, 2.0, 0.2, 2.4869835499703500738, 1e-15, median);
+        test_almost(-1.0, 0.5, -0.3, -0.68708732726039253652, 1e-15, median);
+        test_almost(0.0, 1.0, 1e-9, 0.69314718080017181643, 1e-16, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: GeneralizedPareto| x.mode().unwrap();
+        test_case(0.0, 1.0, 0.0, 0.0, mode);
+        test_case(1.0, 2.0, 0.2, 1.0, mode);
+        test_case(-1.0, 0.5, -0.3, -1.0, mode);
+        test_case(0.0, 1.0, -2.0, 0.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: GeneralizedPareto| x.min();
+        let max = |x: GeneralizedPareto| x.max();
+        test_case(1.0, 2.0, 0.2, 1.0, min);
+        test_case(1.0, 2.0, 0.2, f64::INFINITY, max);
+        test_case(-1.0, 0.5, -0.25, -1.0, min);
+        test_case(-1.0, 0.5, -0.25, 1.0, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedPareto| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.0, 0.6065306597126334236, 1e-16, pdf(0.5));
+        test_almost(0.0, 1.0, 0.0, 0.000045399929762484851536, 1e-19, pdf(10.0));
+        test_almost(1.0, 2.0, 0.2, 0.28223696502688871458, 1e-16, pdf(2.0));
+        test_almost(1.0, 2.0, 0.2, 0.010627922984373488727, 1e-17, pdf(10.0));
+        test_almost(-1.0, 0.5, -0.3, 0.87014592170774868818, 1e-15, pdf(-0.5));
+        test_almost(-1.0, 0.5, -0.3, 0.0092831776672255623625, 1e-16, pdf(0.5));
+        test_almost(0.0, 1.0, 1e-9, 0.6065306594851844263, 1e-15, pdf(0.5));
+        test_case(0.0, 1.0, 0.0, 0.0, pdf(-0.5));
+        test_case(-1.0, 0.5, -0.3, 0.0, pdf(1.0));
+        test_case(0.0, 1.0, -1.0, 1.0, pdf(0.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: GeneralizedPareto| x.ln_pdf(arg);
+        test_case(0.0, 1.0, 0.0, -10.0, ln_pdf(10.0));
+        test_almost(1.0, 2.0, 0.2, -4.544270497594313945, 1e-15, ln_pdf(10.0));
+        test_almost(-1.0, 0.5, -0.3, -4.6795513697594941268, 1e-14, ln_pdf(0.5));
+        test_almost(0.0, 1.0, 1e-9, -9.9999999600000002833, 1e-14, ln_pdf(10.0));
+        test_case(0.0, 1.0, 0.0, f64::NEG_INFINITY, ln_pdf(-0.5));
+        test_case(0.0, 1.0, 0.2, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+        test_case(-1.0, 0.5, -0.3, f64::NEG_INFINITY, ln_pdf(1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedPareto| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.0, 0.3934693402873665764, 1e-16, cdf(0.5));
+        test_almost(0.0, 1.0, 0.0, 0.99995460007023751515, 1e-15, cdf(10.0));
+        test_almost(1.0, 2.0, 0.2, 0.37907867694084482479, 1e-16, cdf(2.0));
+        test_almost(1.0, 2.0, 0.2, 0.95961389265938074178, 1e-15, cdf(10.0));
+        test_almost(-1.0, 0.5, -0.3, 0.69544892740228795431, 1e-15, cdf(-0.5));
+        test_almost(-1.0, 0.5, -0.3, 0.99953584111663872173, 1e-15, cdf(0.5));
+        test_almost(0.0, 1.0, 1e-9, 0.39346934021155024395, 1e-16, cdf(0.5));
+        test_case(0.0, 1.0, 0.0, 0.0, cdf(-0.5));
+        test_case(-1.0, 0.5, -0.3, 1.0, cdf(1.0));
+        test_case(1.0, 2.0, 0.2, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: GeneralizedPareto| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 0.0, 0.010050335853501441184, 1e-17, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, 0.0, 4.605170185988091368, 1e-14, inverse_cdf(0.99));
+        test_almost(1.0, 2.0, 0.2, 1.0201208870996530926, 1e-15, inverse_cdf(0.01));
+        test_almost(1.0, 2.0, 0.2, 16.118864315095801556, 1e-13, inverse_cdf(0.99));
+        test_almost(-1.0, 0.5, -0.3, -0.99498240015896583827, 1e-16, inverse_cdf(0.01));
+        test_almost(-1.0, 0.5, -0.3, 0.24801892808173667293, 1e-15, inverse_cdf(0.99));
+        test_almost(0.0, 1.0, 1e-9, 4.6051701965918876053, 1e-14, inverse_cdf(0.99));
+        test_case(1.0, 2.0, 0.2, 1.0, inverse_cdf(0.0));
+        test_case(1.0, 2.0, 0.2, f64::INFINITY, inverse_cdf(1.0));
+        test_case(-1.0, 0.5, -0.25, 1.0, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0, 0.0).checked_inverse_cdf(-0.1).is_err());
+    }
+
+    #[test]
+    fn test_return_level() {
+        let return_level = |arg: f64| move |x: GeneralizedPareto| x.return_level(arg).unwrap();
+        test_almost(0.0, 1.0, 0.0, 4.605170185988091368, 1e-15, return_level(100.0));
+        test_almost(1.0, 2.0, 0.2, 16.118864315095801556, 1e-13, return_level(100.0));
+        test_almost(-1.0, 0.5, -0.3, 0.24801892808173667293, 1e-15, return_level(100.0));
+        assert!(try_create(0.0, 1.0, 0.0).return_level(1.0).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 0.0), 0.0, 30.0);
+        tests::check_continuous_distribution(&try_create(1.0, 2.0, 0.2), 1.0, 200.0);
+        tests::check_continuous_distribution(&try_create(-1.0, 0.5, -0.3), -1.0, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        for &(location, scale, shape) in &[(0.0, 1.0, 0.0), (1.0, 2.0, 0.2), (-1.0, 0.5, -0.3)] {
+            let n = try_create(location, scale, shape);
+            let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+            let mean = samples.iter().sum::<f64>() / 100_000.0;
+            let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+            assert!((mean - n.mean().unwrap()).abs() < 0.05);
+            assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.1);
+        }
+    }
+}
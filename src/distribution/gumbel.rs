@@ -0,0 +1,458 @@
+use crate::consts;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// The skewness of the gumbel distribution, `12 * sqrt(6) * ζ(3) / π^3`
+const SKEWNESS: f64 = 1.139_547_099_404_648_657_492_793;
+
+/// Implements the [Gumbel](https://en.wikipedia.org/wiki/Gumbel_distribution)
+/// distribution, also known as the type I extreme value distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gumbel, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Gumbel::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.3678794411714423215955);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gumbel {
+    location: f64,
+    scale: f64,
+}
+
+impl Gumbel {
+    /// Constructs a new gumbel distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let mut result = Gumbel::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Gumbel::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Gumbel> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Gumbel { location, scale })
+        }
+    }
+
+    /// Returns the location of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Gumbel {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.location - self.scale * (-u.ln()).ln()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Gumbel {
+    /// Calculates the cumulative distribution function for the
+    /// gumbel distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-e^(-(x - μ) / β))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        (-(-(x - self.location) / self.scale).exp()).exp()
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gumbel distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β * ln(-ln(p))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else {
+            Ok(self.location - self.scale * (-p.ln()).ln())
+        }
+    }
+}
+
+impl Min<f64> for Gumbel {
+    /// Returns the minimum value in the domain of the gumbel
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Gumbel {
+    /// Returns the maximum value in the domain of the gumbel
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Gumbel {
+    /// Returns the mean of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + βγ
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `γ` is the
+    /// Euler-Mascheroni constant
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * consts::EULER_MASCHERONI)
+    }
+    /// Returns the variance of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// β^2 * π^2 / 6
+    /// ```
+    ///
+    /// where `β` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(self.scale * self.scale * f64::consts::PI * f64::consts::PI / 6.0)
+    }
+    /// Returns the entropy of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(β) + γ + 1
+    /// ```
+    ///
+    /// where `β` is the scale and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + consts::EULER_MASCHERONI + 1.0)
+    }
+    /// Returns the skewness of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 * sqrt(6) * ζ(3) / π^3
+    /// ```
+    ///
+    /// where `ζ` is the Riemann zeta function
+    fn skewness(&self) -> Option<f64> {
+        Some(SKEWNESS)
+    }
+}
+
+impl Median<f64> for Gumbel {
+    /// Returns the median of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β * ln(ln(2))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn median(&self) -> f64 {
+        self.location - self.scale * f64::consts::LN_2.ln()
+    }
+}
+
+impl Mode<Option<f64>> for Gumbel {
+    /// Returns the mode of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Gumbel {
+    /// Calculates the probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-(z + e^(-z))) / β
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            0.0
+        } else {
+            let z = (x - self.location) / self.scale;
+            (-(z + (-z).exp())).exp() / self.scale
+        }
+    }
+
+    /// Calculates the log probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(z + e^(-z)) - ln(β)
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            let z = (x - self.location) / self.scale;
+            -(z + (-z).exp()) - self.scale.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Gumbel};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Gumbel {
+        let n = Gumbel::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Gumbel::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Gumbel) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Gumbel) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(10.0, 11.0);
+        create_case(-5.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Gumbel| x.mean().unwrap();
+        test_almost(0.0, 1.0, 0.5772156649015328606065, 1e-16, mean);
+        test_almost(1.0, 2.0, 2.154431329803065721213, 1e-15, mean);
+        test_almost(-3.0, 0.5, -2.711392167549233569697, 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Gumbel| x.variance().unwrap();
+        test_almost(0.0, 1.0, 1.644934066848226436472, 1e-15, variance);
+        test_almost(1.0, 2.0, 6.57973626739290574589, 1e-14, variance);
+        test_almost(-3.0, 0.5, 0.4112335167120566091181, 1e-16, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Gumbel| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 1.577215664901532860607, 1e-15, entropy);
+        test_almost(1.0, 2.0, 2.270362845461478170024, 1e-15, entropy);
+        test_almost(-3.0, 0.5, 0.8840684843415875511893, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Gumbel| x.skewness().unwrap();
+        test_case(1.0, 2.0, 1.139547099404648657492793, skewness);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: Gumbel| x.median();
+        let mode = |x: Gumbel| x.mode().unwrap();
+        test_almost(0.0, 1.0, 0.3665129205816643270124, 1e-16, median);
+        test_almost(-3.0, 0.5, -2.816743539709167836494, 1e-15, median);
+        test_case(-3.0, 0.5, -3.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Gumbel| x.min();
+        let max = |x: Gumbel| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Gumbel| x.pdf(arg);
+        test_almost(0.0, 1.0, 5.205427108495624161333e-63, 1e-75, pdf(-5.0));
+        test_almost(0.0, 1.0, 0.3678794411714423215955, 1e-16, pdf(0.0));
+        test_almost(0.0, 1.0, 0.3307042988904180677448, 1e-16, pdf(0.5));
+        test_almost(0.0, 1.0, 0.00004539786865564981977096, 1e-19, pdf(10.0));
+        test_almost(1.0, 2.0, 1.900271252022178855356e-8, 1e-22, pdf(-5.0));
+        test_almost(1.0, 2.0, 0.1653521494452090338724, 1e-16, pdf(2.0));
+        test_almost(-3.0, 0.5, 0.03596645939342728713173, 1e-16, pdf(-1.0));
+        test_almost(-3.0, 0.5, 1.021817805607444385836e-11, 1e-25, pdf(10.0));
+        test_case(0.0, 1.0, 0.0, pdf(-1000.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Gumbel| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -143.4131591025766034211, 1e-12, ln_pdf(-5.0));
+        test_case(0.0, 1.0, -1.0, ln_pdf(0.0));
+        test_almost(1.0, 2.0, -1.727172597247686793491, 1e-15, ln_pdf(0.5));
+        test_almost(-3.0, 0.5, -25.30685281944516377961, 1e-14, ln_pdf(10.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-1000.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Gumbel| x.cdf(arg);
+        test_almost(0.0, 1.0, 3.50738919646462309642e-65, 1e-77, cdf(-5.0));
+        test_almost(0.0, 1.0, 0.3678794411714423215955, 1e-16, cdf(0.0));
+        test_almost(0.0, 1.0, 0.5452392118926050554202, 1e-16, cdf(0.5));
+        test_almost(0.0, 1.0, 0.9999546011007987305065, 1e-16, cdf(10.0));
+        test_almost(1.0, 2.0, 1.892178694838292633579e-9, 1e-23, cdf(-5.0));
+        test_almost(1.0, 2.0, 0.2769203340999089161701, 1e-16, cdf(0.5));
+        test_almost(-3.0, 0.5, 0.9818510730616664829201, 1e-16, cdf(-1.0));
+        test_almost(-3.0, 0.5, 0.9999999999948909109719, 1e-16, cdf(10.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Gumbel| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, -1.5271796258079011047, 1e-15, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, -0.1856267588623656745172, 1e-15, inverse_cdf(0.3));
+        test_almost(0.0, 1.0, 0.3665129205816643270124, 1e-15, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 2.250367327312445520489, 1e-15, inverse_cdf(0.9));
+        test_almost(1.0, 2.0, -2.0543592516158022094, 1e-14, inverse_cdf(0.01));
+        test_almost(1.0, 2.0, 5.500734654624891040979, 1e-14, inverse_cdf(0.9));
+        test_almost(-3.0, 0.5, -3.092813379431182837259, 1e-15, inverse_cdf(0.3));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -5.0, 40.0);
+        tests::check_continuous_distribution(&try_create(-3.0, 0.5), -6.0, 20.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(1.0, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!((mean - n.mean().unwrap()).abs() < 0.05);
+        assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.05);
+    }
+}
@@ -0,0 +1,403 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Half-Cauchy](https://en.wikipedia.org/wiki/Cauchy_distribution)
+/// distribution, the distribution of `|X|` for a Cauchy distributed `X`
+/// with a location of zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HalfCauchy, Continuous};
+/// use statrs::statistics::Median;
+/// use statrs::prec;
+///
+/// let n = HalfCauchy::new(1.0).unwrap();
+/// assert_eq!(n.median(), 1.0);
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.3183098861837907, 1e-16));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HalfCauchy {
+    scale: f64,
+}
+
+impl HalfCauchy {
+    /// Constructs a new half-Cauchy distribution with a scale (γ) of
+    /// `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfCauchy;
+    ///
+    /// let mut result = HalfCauchy::new(1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = HalfCauchy::new(-1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64) -> Result<HalfCauchy> {
+        if scale.is_finite() && scale > 0.0 {
+            Ok(HalfCauchy { scale })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the scale of the half-Cauchy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfCauchy;
+    ///
+    /// let n = HalfCauchy::new(2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for HalfCauchy {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.scale * (f64::consts::FRAC_PI_2 * r.gen::<f64>()).tan()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for HalfCauchy {
+    /// Calculates the cumulative distribution function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / π) * atan(x / γ)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            f64::consts::FRAC_2_PI * (x / self.scale).atan()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-Cauchy distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ * tan(πp / 2)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else if p == 1.0 {
+            Ok(f64::INFINITY)
+        } else {
+            Ok(self.scale * (f64::consts::FRAC_PI_2 * p).tan())
+        }
+    }
+}
+
+impl Min<f64> for HalfCauchy {
+    /// Returns the minimum value in the domain of the half-Cauchy
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfCauchy {
+    /// Returns the maximum value in the domain of the half-Cauchy
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for HalfCauchy {
+    /// Returns the mean of the half-Cauchy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the mean of the half-Cauchy distribution is infinite
+    fn mean(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the variance of the half-Cauchy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the variance of the half-Cauchy distribution is
+    /// infinite
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the skewness of the half-Cauchy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the skewness of the half-Cauchy distribution is
+    /// undefined
+    fn skewness(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the entropy of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(γ) + ln(2π)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some((2.0 * f64::consts::PI * self.scale).ln())
+    }
+}
+
+impl Median<f64> for HalfCauchy {
+    /// Returns the median of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn median(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Mode<Option<f64>> for HalfCauchy {
+    /// Returns the mode of the half-Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Continuous<f64, f64> for HalfCauchy {
+    /// Calculates the probability density function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 / (πγ * (1 + (x / γ)^2))
+    /// ```
+    ///
+    /// for `x >= 0`, where `γ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the half-Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / (πγ * (1 + (x / γ)^2)))
+    /// ```
+    ///
+    /// for `x >= 0`, where `γ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let z = x / self.scale;
+        // ln(1 + z^2), written so that z^2 cannot overflow
+        let ln_denominator = if z > 1.0 {
+            2.0 * z.ln() + (1.0 / (z * z)).ln_1p()
+        } else {
+            (z * z).ln_1p()
+        };
+        (f64::consts::FRAC_2_PI / self.scale).ln() - ln_denominator
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, HalfCauchy};
+    use crate::distribution::internal::*;
+
+    fn try_create(scale: f64) -> HalfCauchy {
+        let n = HalfCauchy::new(scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(scale: f64) {
+        let n = HalfCauchy::new(scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(scale: f64, expected: f64, eval: F)
+        where F: Fn(HalfCauchy) -> f64
+    {
+        let n = try_create(scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(HalfCauchy) -> f64
+    {
+        let n = try_create(scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(1.0).scale(), 1.0);
+        assert_eq!(try_create(2.0).scale(), 2.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN);
+        bad_create_case(0.0);
+        bad_create_case(-1.0);
+        bad_create_case(f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(1.0);
+        assert!(n.mean().is_none());
+        assert!(n.variance().is_none());
+        assert!(n.skewness().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: HalfCauchy| x.entropy().unwrap();
+        test_almost(1.0, 1.8378770664093454836, 1e-15, entropy);
+        test_almost(2.0, 2.531024246969290793, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: HalfCauchy| x.median();
+        test_case(1.0, 1.0, median);
+        test_case(2.0, 2.0, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: HalfCauchy| x.mode().unwrap();
+        test_case(1.0, 0.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: HalfCauchy| x.min();
+        let max = |x: HalfCauchy| x.max();
+        test_case(1.0, 0.0, min);
+        test_case(1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: HalfCauchy| x.pdf(arg);
+        test_almost(1.0, 0.63661977236758134308, 1e-16, pdf(0.0));
+        test_almost(1.0, 0.50929581789406507446, 1e-16, pdf(0.5));
+        test_almost(1.0, 0.0063031660630453598324, 1e-17, pdf(10.0));
+        test_almost(2.0, 0.15915494309189533577, 1e-16, pdf(2.0));
+        test_case(1.0, 0.0, pdf(-0.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: HalfCauchy| x.ln_pdf(arg);
+        test_almost(1.0, -0.45158270528945486473, 1e-15, ln_pdf(0.0));
+        test_almost(1.0, -2.0610206177235552393, 1e-15, ln_pdf(2.0));
+        test_almost(2.0, -4.4028264238708822196, 1e-15, ln_pdf(10.0));
+        test_almost(1.0, -1418.8439999896215962, 1e-12, ln_pdf(1e308));
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(-0.5));
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: HalfCauchy| x.cdf(arg);
+        test_case(1.0, 0.0, cdf(0.0));
+        test_almost(1.0, 0.29516723530086654835, 1e-16, cdf(0.5));
+        test_almost(1.0, 0.93654896513889286097, 1e-15, cdf(10.0));
+        test_almost(2.0, 0.5, 1e-16, cdf(2.0));
+        test_case(1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: HalfCauchy| x.inverse_cdf(arg);
+        test_almost(1.0, 0.015709255323664916323, 1e-17, inverse_cdf(0.01));
+        test_almost(1.0, 63.656741162871580995, 1e-12, inverse_cdf(0.99));
+        test_almost(2.0, 2.0, 1e-15, inverse_cdf(0.5));
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_case(1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0), 0.0, 1000.0);
+        tests::check_continuous_distribution(&try_create(2.0), 0.0, 2000.0);
+    }
+
+    #[test]
+    fn test_sample_median() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        let below = samples.iter().filter(|&&x| x < n.median()).count() as f64 / 100_000.0;
+        assert!((below - 0.5).abs() < 0.01);
+    }
+}
@@ -0,0 +1,430 @@
+use crate::distribution::{normal, Continuous, ContinuousCDF};
+use crate::function::erf;
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Half-normal](https://en.wikipedia.org/wiki/Half-normal_distribution)
+/// distribution, the distribution of `|X|` for a normally distributed `X`
+/// with a mean of zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HalfNormal, Continuous};
+/// use statrs::statistics::Mode;
+/// use statrs::prec;
+///
+/// let n = HalfNormal::new(1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.48394144903828673, 1e-16));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HalfNormal {
+    scale: f64,
+}
+
+impl HalfNormal {
+    /// Constructs a new half-normal distribution with a scale (σ) of
+    /// `scale`, the standard deviation of the underlying normal distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfNormal;
+    ///
+    /// let mut result = HalfNormal::new(1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = HalfNormal::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64) -> Result<HalfNormal> {
+        if scale.is_finite() && scale > 0.0 {
+            Ok(HalfNormal { scale })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the scale of the half-normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfNormal;
+    ///
+    /// let n = HalfNormal::new(2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for HalfNormal {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        normal::sample_unchecked(r, 0.0, self.scale).abs()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for HalfNormal {
+    /// Calculates the cumulative distribution function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// erf(x / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf` is the error function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            erf::erf(x / (self.scale * f64::consts::SQRT_2))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// half-normal distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2) * erf_inv(p)
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf_inv` is the inverse of the error
+    /// function
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if (0.0..=1.0).contains(&p) {
+            Ok(self.scale * f64::consts::SQRT_2 * erf::erf_inv(p))
+        } else {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        }
+    }
+}
+
+impl Min<f64> for HalfNormal {
+    /// Returns the minimum value in the domain of the half-normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfNormal {
+    /// Returns the maximum value in the domain of the half-normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for HalfNormal {
+    /// Returns the mean of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2 / π)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn mean(&self) -> Option<f64> {
+        Some(self.scale * (2.0 / f64::consts::PI).sqrt())
+    }
+    /// Returns the variance of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * (1 - 2 / π)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(self.scale * self.scale * (1.0 - 2.0 / f64::consts::PI))
+    }
+    /// Returns the entropy of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * ln(πσ^2 / 2) + 1 / 2
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + consts::LN_SQRT_2PIE - f64::consts::LN_2)
+    }
+    /// Returns the skewness of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2) * (4 - π) / (π - 2)^(3 / 2)
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(SKEWNESS)
+    }
+}
+
+impl Median<f64> for HalfNormal {
+    /// Returns the median of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2) * erf_inv(1 / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale and `erf_inv` is the inverse of the error
+    /// function
+    fn median(&self) -> f64 {
+        self.scale * MEDIAN
+    }
+}
+
+impl Mode<Option<f64>> for HalfNormal {
+    /// Returns the mode of the half-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Continuous<f64, f64> for HalfNormal {
+    /// Calculates the probability density function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (sqrt(2) / (σ * sqrt(π))) * e^(-x^2 / (2σ^2))
+    /// ```
+    ///
+    /// for `x >= 0`, where `σ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the half-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((sqrt(2) / (σ * sqrt(π))) * e^(-x^2 / (2σ^2)))
+    /// ```
+    ///
+    /// for `x >= 0`, where `σ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::consts::LN_2 + normal::ln_pdf_unchecked(x, 0.0, self.scale)
+        }
+    }
+}
+
+/// The skewness of the half-normal distribution, `sqrt(2) * (4 - π) / (π -
+/// 2)^(3 / 2)`
+const SKEWNESS: f64 = 0.995_271_746_431_156_042_44;
+
+/// The median of the half-normal distribution with unit scale,
+/// `sqrt(2) * erf_inv(1 / 2)`
+const MEDIAN: f64 = 0.674_489_750_196_081_743_2;
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, HalfNormal};
+    use crate::distribution::internal::*;
+
+    fn try_create(scale: f64) -> HalfNormal {
+        let n = HalfNormal::new(scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(scale: f64) {
+        let n = HalfNormal::new(scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(scale: f64, expected: f64, eval: F)
+        where F: Fn(HalfNormal) -> f64
+    {
+        let n = try_create(scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(HalfNormal) -> f64
+    {
+        let n = try_create(scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(try_create(1.0).scale(), 1.0);
+        assert_eq!(try_create(2.5).scale(), 2.5);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN);
+        bad_create_case(0.0);
+        bad_create_case(-1.0);
+        bad_create_case(f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: HalfNormal| x.mean().unwrap();
+        test_almost(1.0, 0.79788456080286535588, 1e-16, mean);
+        test_almost(2.5, 1.9947114020071633897, 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: HalfNormal| x.variance().unwrap();
+        test_almost(1.0, 0.36338022763241865692, 1e-16, variance);
+        test_almost(2.5, 2.2711264227026166058, 1e-15, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: HalfNormal| x.entropy().unwrap();
+        test_almost(1.0, 0.72579135264472743236, 1e-15, entropy);
+        test_almost(2.5, 1.6420820845188824975, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: HalfNormal| x.skewness().unwrap();
+        test_almost(1.0, 0.99527174643115604244, 1e-15, skewness);
+        test_almost(2.5, 0.99527174643115604244, 1e-15, skewness);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: HalfNormal| x.median();
+        test_almost(1.0, 0.6744897501960817432, 1e-16, median);
+        test_almost(2.5, 1.686224375490204358, 1e-15, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: HalfNormal| x.mode().unwrap();
+        test_case(1.0, 0.0, mode);
+        test_case(2.5, 0.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: HalfNormal| x.min();
+        let max = |x: HalfNormal| x.max();
+        test_case(1.0, 0.0, min);
+        test_case(1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: HalfNormal| x.pdf(arg);
+        test_almost(1.0, 0.79788456080286535588, 1e-15, pdf(0.0));
+        test_almost(1.0, 0.70413065352859895555, 1e-15, pdf(0.5));
+        test_almost(1.0, 1.5389197253412838693e-22, 1e-36, pdf(10.0));
+        test_almost(2.5, 0.23175324220918619049, 1e-15, pdf(2.0));
+        test_case(1.0, 0.0, pdf(-0.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: HalfNormal| x.ln_pdf(arg);
+        test_almost(1.0, -0.22579135264472743236, 1e-15, ln_pdf(0.0));
+        test_almost(1.0, -50.225791352644727432, 1e-13, ln_pdf(10.0));
+        test_almost(2.5, -9.1420820845188824975, 1e-14, ln_pdf(10.0));
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(-0.5));
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: HalfNormal| x.cdf(arg);
+        test_case(1.0, 0.0, cdf(0.0));
+        test_almost(1.0, 0.38292492254802620728, 1e-11, cdf(0.5));
+        test_almost(1.0, 0.9544997361036415856, 1e-11, cdf(2.0));
+        test_almost(2.5, 0.99993665751633376016, 1e-11, cdf(10.0));
+        test_case(1.0, 0.0, cdf(-1.0));
+        test_case(1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: HalfNormal| x.inverse_cdf(arg);
+        test_almost(1.0, 0.012533469508069263161, 1e-17, inverse_cdf(0.01));
+        test_almost(1.0, 2.575829303548900761, 1e-14, inverse_cdf(0.99));
+        test_almost(2.5, 1.686224375490204358, 1e-15, inverse_cdf(0.5));
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_case(1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(1.0).checked_inverse_cdf(-0.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(2.5), 0.0, 25.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(2.5);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert!((mean - n.mean().unwrap()).abs() < 0.02);
+        assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.02);
+    }
+}
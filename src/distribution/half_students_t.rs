@@ -0,0 +1,594 @@
+use crate::distribution::{Continuous, ContinuousCDF, StudentsT};
+use crate::function::{beta, erf, gamma};
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the half Student's t-distribution, the distribution of `|X|`
+/// for a [Student's t](./struct.StudentsT.html) distributed `X` with a
+/// location of zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{HalfStudentsT, Continuous};
+/// use statrs::statistics::Mode;
+/// use statrs::prec;
+///
+/// let n = HalfStudentsT::new(1.0, 3.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.7351051938957227, 1e-14));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HalfStudentsT {
+    students_t: StudentsT,
+}
+
+impl HalfStudentsT {
+    /// Constructs a new half Student's t-distribution with a scale (σ) of
+    /// `scale` and `freedom` (ν) degrees of freedom
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` or `freedom` are `NaN`, if `scale` is
+    /// infinite, or if `scale <= 0.0` or `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfStudentsT;
+    ///
+    /// let mut result = HalfStudentsT::new(1.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = HalfStudentsT::new(1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64, freedom: f64) -> Result<HalfStudentsT> {
+        if scale.is_finite() && scale > 0.0 && freedom > 0.0 {
+            Ok(HalfStudentsT {
+                students_t: StudentsT::new(0.0, scale, freedom)?,
+            })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the scale of the half Student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfStudentsT;
+    ///
+    /// let n = HalfStudentsT::new(2.0, 3.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.students_t.scale()
+    }
+
+    /// Returns the freedom of the half Student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::HalfStudentsT;
+    ///
+    /// let n = HalfStudentsT::new(2.0, 3.0).unwrap();
+    /// assert_eq!(n.freedom(), 3.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.students_t.freedom()
+    }
+
+    /// Returns `E[|T|^k]` for a standard Student's t distributed `T`,
+    /// which exists for `k < ν`
+    fn standard_abs_moment(&self, k: f64) -> f64 {
+        let freedom = self.freedom();
+        if freedom.is_infinite() {
+            // E[|Z|^k] for a standard normal Z
+            (k / 2.0 * f64::consts::LN_2 + gamma::ln_gamma((k + 1.0) / 2.0) - consts::LN_PI / 2.0)
+                .exp()
+        } else {
+            (k / 2.0 * freedom.ln()
+                + gamma::ln_gamma((k + 1.0) / 2.0)
+                + gamma::ln_gamma((freedom - k) / 2.0)
+                - gamma::ln_gamma(freedom / 2.0)
+                - consts::LN_PI / 2.0)
+                .exp()
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for HalfStudentsT {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        ::rand::distributions::Distribution::sample(&self.students_t, r).abs()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for HalfStudentsT {
+    /// Calculates the cumulative distribution function for the half
+    /// Student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I(k^2 / (ν + k^2), 1 / 2, ν / 2)
+    /// ```
+    ///
+    /// where `k = x / σ`, `σ` is the scale, `ν` is the freedom and `I` is
+    /// the regularized incomplete beta function. For small `k` it is summed
+    /// as the series
+    ///
+    /// ```ignore
+    /// x f(x) Σ_n ((ν + 1) / 2)_n / (3 / 2)_n y^n
+    /// ```
+    ///
+    /// where `f` is the pdf, `y = k^2 / (ν + k^2)` and `(a)_n` is the rising
+    /// factorial, since the incomplete beta function flushes small arguments
+    /// to zero
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        let freedom = self.freedom();
+        let k = x / self.scale();
+        if freedom.is_infinite() {
+            erf::erf(k / f64::consts::SQRT_2)
+        } else if k <= 1.0 && 2.0 * k * k <= freedom {
+            // the ratio of consecutive terms is at most 1 / 3
+            let y = k * k / (freedom + k * k);
+            let mut term = 1.0;
+            let mut sum = 1.0;
+            let mut n = 0.0;
+            while term > f64::EPSILON * sum {
+                term *= ((freedom + 1.0) / 2.0 + n) / (1.5 + n) * y;
+                sum += term;
+                n += 1.0;
+            }
+            x * self.pdf(x) * sum
+        } else if k * k < freedom {
+            beta::beta_reg(0.5, freedom / 2.0, k * k / (freedom + k * k))
+        } else {
+            1.0 - beta::beta_reg(freedom / 2.0, 0.5, freedom / (freedom + k * k))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// half Student's t-distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(ν * y / (1 - y))
+    /// ```
+    ///
+    /// where `y = I^-1(p, 1 / 2, ν / 2)`, `σ` is the scale, `ν` is the
+    /// freedom and `I^-1` is the inverse of the regularized incomplete beta
+    /// function
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        }
+        let freedom = self.freedom();
+        let k = if freedom.is_infinite() {
+            f64::consts::SQRT_2 * erf::erf_inv(p)
+        } else if p == 1.0 {
+            f64::INFINITY
+        } else if p <= 0.5 {
+            let y = beta::inv_beta_reg(0.5, freedom / 2.0, p);
+            (freedom * y / (1.0 - y)).sqrt()
+        } else {
+            let y = beta::inv_beta_reg(freedom / 2.0, 0.5, 1.0 - p);
+            (freedom * (1.0 - y) / y).sqrt()
+        };
+        Ok(self.scale() * k)
+    }
+}
+
+impl Min<f64> for HalfStudentsT {
+    /// Returns the minimum value in the domain of the half Student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for HalfStudentsT {
+    /// Returns the maximum value in the domain of the half Student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for HalfStudentsT {
+    /// Returns the mean of the half Student's t-distribution
+    ///
+    /// # None
+    ///
+    /// If `ν <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2σ * sqrt(ν / π) * Γ((ν + 1) / 2) / (Γ(ν / 2) * (ν - 1))
+    /// ```
+    ///
+    /// where `σ` is the scale, `ν` is the freedom and `Γ` is the gamma
+    /// function
+    fn mean(&self) -> Option<f64> {
+        if self.freedom() <= 1.0 {
+            None
+        } else {
+            Some(self.scale() * self.standard_abs_moment(1.0))
+        }
+    }
+    /// Returns the variance of the half Student's t-distribution
+    ///
+    /// # None
+    ///
+    /// If `ν <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * ν / (ν - 2) - μ^2
+    /// ```
+    ///
+    /// where `σ` is the scale, `ν` is the freedom and `μ` is the mean
+    fn variance(&self) -> Option<f64> {
+        let freedom = self.freedom();
+        if freedom <= 2.0 {
+            None
+        } else {
+            let m1 = self.standard_abs_moment(1.0);
+            let m2 = if freedom.is_infinite() {
+                1.0
+            } else {
+                freedom / (freedom - 2.0)
+            };
+            Some(self.scale() * self.scale() * (m2 - m1 * m1))
+        }
+    }
+    /// Returns the entropy of the half Student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (ν + 1) / 2 * (ψ((ν + 1) / 2) - ψ(ν / 2)) + ln(sqrt(ν) * B(ν / 2, 1 / 2) * σ / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale, `ν` is the freedom, `ψ` is the digamma function
+    /// and `B` is the beta function
+    fn entropy(&self) -> Option<f64> {
+        let freedom = self.freedom();
+        let shift = self.scale().ln() - f64::consts::LN_2;
+        if freedom.is_infinite() {
+            Some(consts::LN_SQRT_2PIE + shift)
+        } else {
+            let result = (freedom + 1.0) / 2.0
+                * (gamma::digamma((freedom + 1.0) / 2.0) - gamma::digamma(freedom / 2.0))
+                + 0.5 * freedom.ln()
+                + beta::ln_beta(freedom / 2.0, 0.5);
+            Some(result + shift)
+        }
+    }
+    /// Returns the skewness of the half Student's t-distribution
+    ///
+    /// # None
+    ///
+    /// If `ν <= 3.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (m_3 - 3m_1 * m_2 + 2m_1^3) / (m_2 - m_1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k = E[|T|^k] = ν^(k / 2) * Γ((k + 1) / 2) * Γ((ν - k) / 2) /
+    /// (sqrt(π) * Γ(ν / 2))`, `ν` is the freedom and `Γ` is the gamma
+    /// function
+    fn skewness(&self) -> Option<f64> {
+        let freedom = self.freedom();
+        if freedom <= 3.0 {
+            None
+        } else {
+            let m1 = self.standard_abs_moment(1.0);
+            let m2 = if freedom.is_infinite() {
+                1.0
+            } else {
+                freedom / (freedom - 2.0)
+            };
+            let m3 = self.standard_abs_moment(3.0);
+            let variance = m2 - m1 * m1;
+            Some((m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1) / variance.powf(1.5))
+        }
+    }
+}
+
+impl Median<f64> for HalfStudentsT {
+    /// Returns the median of the half Student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 / 2)
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for HalfStudentsT {
+    /// Returns the mode of the half Student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn mode(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Continuous<f64, f64> for HalfStudentsT {
+    /// Calculates the probability density function for the half Student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2Γ((ν + 1) / 2) / (sqrt(νπ) * σ * Γ(ν / 2)) * (1 + k^2 / ν)^(-(ν + 1) / 2)
+    /// ```
+    ///
+    /// for `x >= 0`, where `k = x / σ`, `σ` is the scale, `ν` is the freedom
+    /// and `Γ` is the gamma function
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the half
+    /// Student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2Γ((ν + 1) / 2) / (sqrt(νπ) * σ * Γ(ν / 2)) * (1 + k^2 / ν)^(-(ν + 1) / 2))
+    /// ```
+    ///
+    /// for `x >= 0`, where `k = x / σ`, `σ` is the scale, `ν` is the freedom
+    /// and `Γ` is the gamma function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let freedom = self.freedom();
+        let scale = self.scale();
+        if freedom >= 1e8 {
+            f64::consts::LN_2 + super::normal::ln_pdf_unchecked(x, 0.0, scale)
+        } else {
+            let k = x / scale;
+            // ln(1 + k^2 / ν), written so that k^2 cannot overflow
+            let ln_kernel = if k * k > freedom {
+                2.0 * k.ln() - freedom.ln() + (freedom / (k * k)).ln_1p()
+            } else {
+                (k * k / freedom).ln_1p()
+            };
+            f64::consts::LN_2 + gamma::ln_gamma((freedom + 1.0) / 2.0)
+                - gamma::ln_gamma(freedom / 2.0)
+                - 0.5 * (freedom * f64::consts::PI).ln()
+                - scale.ln()
+                - (freedom + 1.0) / 2.0 * ln_kernel
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, HalfCauchy, HalfNormal, HalfStudentsT};
+    use crate::distribution::internal::*;
+
+    fn try_create(scale: f64, freedom: f64) -> HalfStudentsT {
+        let n = HalfStudentsT::new(scale, freedom);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(scale: f64, freedom: f64) {
+        let n = try_create(scale, freedom);
+        assert_eq!(scale, n.scale());
+        assert_eq!(freedom, n.freedom());
+    }
+
+    fn bad_create_case(scale: f64, freedom: f64) {
+        let n = HalfStudentsT::new(scale, freedom);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(scale: f64, freedom: f64, expected: f64, eval: F)
+        where F: Fn(HalfStudentsT) -> f64
+    {
+        let n = try_create(scale, freedom);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, freedom: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(HalfStudentsT) -> f64
+    {
+        let n = try_create(scale, freedom);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1.0, 3.0);
+        create_case(2.0, 5.0);
+        create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: HalfStudentsT| x.mean().unwrap();
+        test_almost(1.0, 3.0, 1.102657790843584099, 1e-14, mean);
+        test_almost(2.0, 5.0, 1.8980334491124721559, 1e-13, mean);
+        test_almost(2.5, f64::INFINITY, HalfNormal::new(2.5).unwrap().mean().unwrap(), 1e-14, mean);
+        assert!(try_create(1.0, 1.0).mean().is_none());
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: HalfStudentsT| x.variance().unwrap();
+        test_almost(1.0, 3.0, 1.7841457962919467427, 1e-13, variance);
+        test_almost(2.0, 5.0, 3.0641356927168792376, 1e-13, variance);
+        test_almost(2.5, f64::INFINITY, HalfNormal::new(2.5).unwrap().variance().unwrap(), 1e-14, variance);
+        assert!(try_create(1.0, 2.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: HalfStudentsT| x.entropy().unwrap();
+        test_almost(1.0, 3.0, 1.0803303913033456387, 1e-14, entropy);
+        test_almost(2.0, 5.0, 1.6275026724143959811, 1e-14, entropy);
+        test_almost(1.0, 1.0, 1.8378770664093454836, 1e-14, entropy);
+        test_almost(2.5, f64::INFINITY, HalfNormal::new(2.5).unwrap().entropy().unwrap(), 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: HalfStudentsT| x.skewness().unwrap();
+        test_almost(2.0, 5.0, 2.5496442640620956526, 1e-13, skewness);
+        test_almost(2.5, f64::INFINITY, HalfNormal::new(2.5).unwrap().skewness().unwrap(), 1e-14, skewness);
+        assert!(try_create(1.0, 3.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: HalfStudentsT| x.median();
+        test_almost(1.0, 3.0, 0.76489232840434528066, 1e-14, median);
+        test_almost(2.0, 5.0, 1.453373687600845306, 1e-14, median);
+        test_almost(1.0, 1.0, 1.0, 1e-14, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: HalfStudentsT| x.mode().unwrap();
+        test_case(1.0, 3.0, 0.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: HalfStudentsT| x.min();
+        let max = |x: HalfStudentsT| x.max();
+        test_case(1.0, 3.0, 0.0, min);
+        test_case(1.0, 3.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: HalfStudentsT| x.pdf(arg);
+        test_almost(1.0, 3.0, 0.73510519389572273268, 1e-14, pdf(0.0));
+        test_almost(1.0, 3.0, 0.62636182201765723968, 1e-14, pdf(0.5));
+        test_almost(1.0, 3.0, 0.00062361643369417514165, 1e-17, pdf(10.0));
+        test_almost(2.0, 5.0, 0.2196797973509805736, 1e-14, pdf(2.0));
+        test_case(1.0, 3.0, 0.0, pdf(-0.5));
+        let cauchy = HalfCauchy::new(1.0).unwrap();
+        test_almost(1.0, 1.0, cauchy.pdf(2.0), 1e-15, pdf(2.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: HalfStudentsT| x.ln_pdf(arg);
+        test_almost(1.0, 3.0, -0.30774166906356440101, 1e-14, ln_pdf(0.0));
+        test_almost(1.0, 3.0, -7.3799750681866165598, 1e-14, ln_pdf(10.0));
+        test_almost(2.0, 5.0, -6.343897996738889127, 1e-14, ln_pdf(10.0));
+        test_almost(1.0, 3.0, -1840.1785914869638922, 1e-12, ln_pdf(1e200));
+        test_case(1.0, 3.0, f64::NEG_INFINITY, ln_pdf(-0.5));
+        test_case(1.0, 3.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: HalfStudentsT| x.cdf(arg);
+        test_case(1.0, 3.0, 0.0, cdf(0.0));
+        test_almost(1.0, 3.0, 0.34855203515184900556, 1e-14, cdf(0.5));
+        test_almost(1.0, 3.0, 0.86067403144115682315, 1e-14, cdf(2.0));
+        test_almost(1.0, 3.0, 0.99787160094158584994, 1e-14, cdf(10.0));
+        test_almost(2.0, 5.0, 0.18746586925587664698, 1e-14, cdf(0.5));
+        test_almost(2.0, 5.0, 0.99589528401994667758, 1e-14, cdf(10.0));
+        test_almost(1.0, 1.0, 0.29516723530086654835, 1e-14, cdf(0.5));
+        test_almost(2.0, 5.0, 3.7960668982249442929e-9, 1e-23, cdf(1e-8));
+        test_almost(1.0, 1.0, 6.3661977236758134308e-21, 1e-35, cdf(1e-20));
+        test_almost(1.0, 0.1, 2.9618424741883185771e-13, 1e-27, cdf(1e-12));
+        test_case(1.0, 3.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: HalfStudentsT| x.inverse_cdf(arg);
+        test_almost(1.0, 3.0, 0.013604054691036678374, 1e-15, inverse_cdf(0.01));
+        test_almost(1.0, 3.0, 5.8409093097333572607, 1e-12, inverse_cdf(0.99));
+        test_almost(2.0, 5.0, 0.026343969345293765312, 1e-15, inverse_cdf(0.01));
+        test_almost(2.0, 5.0, 8.0642859671104561567, 1e-12, inverse_cdf(0.99));
+        test_almost(1.0, 1.0, 63.656741162871580995, 1e-10, inverse_cdf(0.99));
+        test_case(1.0, 3.0, 0.0, inverse_cdf(0.0));
+        test_case(1.0, 3.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(1.0, 3.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 3.0), 0.0, 100.0);
+        tests::check_continuous_distribution(&try_create(2.0, 5.0), 0.0, 100.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(2.0, 5.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        assert!(samples.iter().all(|&x| x >= 0.0));
+        assert!((mean - n.mean().unwrap()).abs() < 0.03);
+    }
+}
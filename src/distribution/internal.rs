@@ -1,3 +1,8 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::{erf, gamma};
+use crate::{consts, quadrature, solve, Result, StatsError};
+use std::f64;
+
 /// Returns true if there are no elements in `x` in `arr`
 /// such that `x <= 0.0` or `x` is `f64::NAN` and `sum(arr) > 0.0`.
 /// IF `incl_zero` is true, it tests for `x < 0.0` instead of `x <= 0.0`
@@ -12,11 +17,339 @@ pub fn is_valid_multinomial(arr: &[f64], incl_zero: bool) -> bool {
     sum != 0.0
 }
 
+/// Computes the Poisson mixture `sum(e^-h * h^j / j! * term(j))` over
+/// `j = 0, 1, ...`, where `term` is bounded, as used by the noncentral
+/// distributions. Summation starts at the largest Poisson weight and moves
+/// outwards until the weights and the terms become negligible.
+pub fn poisson_mixture<F: Fn(f64) -> f64>(h: f64, term: F) -> f64 {
+    if h == 0.0 {
+        return term(0.0);
+    }
+    let mode = h.floor();
+    let mode_weight = (-h + mode * h.ln() - gamma::ln_gamma(mode + 1.0)).exp();
+    let negligible =
+        |weight: f64, value: f64, sum: f64| weight < 1e-17 && value <= f64::EPSILON * sum;
+
+    let mut sum = 0.0;
+    let (mut j, mut weight) = (mode, mode_weight);
+    loop {
+        let value = weight * term(j);
+        sum += value;
+        if weight == 0.0 || negligible(weight, value, sum) {
+            break;
+        }
+        j += 1.0;
+        weight *= h / j;
+    }
+    let (mut j, mut weight) = (mode, mode_weight);
+    while j > 0.0 {
+        weight *= j / h;
+        j -= 1.0;
+        let value = weight * term(j);
+        sum += value;
+        if weight == 0.0 || negligible(weight, value, sum) {
+            break;
+        }
+    }
+    sum
+}
+
+/// Computes the logarithm of the Poisson mixture
+/// `sum(e^-h * h^j / j! * e^ln_term(j))` over `j = 0, 1, ...` as
+/// [`ln_sum_log_concave`] does, for a `ln_term` that is concave in `j`, as
+/// used by the log densities of the noncentral distributions
+pub fn ln_poisson_mixture<F: Fn(f64) -> f64>(h: f64, ln_term: F) -> f64 {
+    if h == 0.0 {
+        return ln_term(0.0);
+    }
+    let ln_h = h.ln();
+    ln_sum_log_concave(|j| -h + j * ln_h - gamma::ln_gamma(j + 1.0) + ln_term(j))
+}
+
+/// Computes `ln(sum(e^ln_term(j)))` over `j = 0, 1, ...` for a `ln_term`
+/// that is concave in `j`, so that the terms neither underflow nor
+/// overflow. Summation starts at the largest term, found by bisecting on the
+/// differences of consecutive terms, and moves outwards until the terms
+/// become negligible.
+pub fn ln_sum_log_concave<F: Fn(f64) -> f64>(ln_term: F) -> f64 {
+    // the differences of consecutive terms decrease, so the largest term is
+    // the first one that is not followed by a larger one
+    let rising = |j: f64| ln_term(j + 1.0) > ln_term(j);
+    let mut peak = 0.0;
+    if rising(0.0) {
+        // beyond 2^52 consecutive indices are no longer distinct
+        let (mut low, mut high) = (0.0, 1.0);
+        while high < 4503599627370496.0 && rising(high) {
+            low = high;
+            high *= 2.0;
+        }
+        while high - low > 1.0 {
+            let mid = (0.5 * (low + high)).floor();
+            if rising(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        peak = high;
+    }
+    let max = ln_term(peak);
+    if !max.is_finite() {
+        return max;
+    }
+
+    let mut sum = 1.0;
+    let mut j = peak + 1.0;
+    loop {
+        let term = (ln_term(j) - max).exp();
+        sum += term;
+        if term.is_nan() || term <= f64::EPSILON * sum {
+            break;
+        }
+        j += 1.0;
+    }
+    let mut j = peak;
+    while j > 0.0 {
+        j -= 1.0;
+        let term = (ln_term(j) - max).exp();
+        sum += term;
+        if term.is_nan() || term <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    max + sum.ln()
+}
+
+/// Computes `ln(∫ e^ln_f(x) dx)` over the real line for an integrand that
+/// is log-concave, so that it neither underflows nor overflows. The maximum
+/// is bracketed by stepping outwards from `guess` with doubling steps and
+/// located with Brent's method. The integrand is then divided by its maximum
+/// and the variable on either side scaled by the distance over which the
+/// integrand falls by about a factor of `e`, so that the quadrature sees a
+/// peak of unit height and width however narrow or distant it is.
+///
+/// # Errors
+///
+/// Returns `StatsError::ComputationFailedToConverge` if the maximum cannot
+/// be bracketed or the quadrature fails
+pub fn ln_integral_log_concave<F: Fn(f64) -> f64>(ln_f: F, guess: f64) -> Result<f64> {
+    let (low, high) = if ln_f(guess - 1.0) <= ln_f(guess) && ln_f(guess + 1.0) <= ln_f(guess) {
+        (guess - 1.0, guess + 1.0)
+    } else {
+        let direction = if ln_f(guess + 1.0) > ln_f(guess) {
+            1.0
+        } else {
+            -1.0
+        };
+        let (mut last, mut current, mut step) = (guess, guess + direction, 1.0);
+        loop {
+            step *= 2.0;
+            let next = current + direction * step;
+            if !next.is_finite() {
+                return Err(StatsError::ComputationFailedToConverge);
+            }
+            if ln_f(next) <= ln_f(current) {
+                break (last.min(next), last.max(next));
+            }
+            last = current;
+            current = next;
+        }
+    };
+    let mode = solve::brent_min(|x| -ln_f(x), low, high, 0.0, solve::DEFAULT_MAX_ITERATIONS)?;
+    let max = ln_f(mode);
+    if max == f64::NEG_INFINITY {
+        return Ok(max);
+    } else if !max.is_finite() {
+        return Err(StatsError::ComputationFailedToConverge);
+    }
+
+    // the integrand is only known to a relative accuracy of about the
+    // rounding error of its log, which grows with the size of its maximum
+    let tolerance = 1e-12f64.max(16.0 * f64::EPSILON * max.abs());
+    let mut sum = 0.0;
+    for &direction in &[1.0, -1.0] {
+        let falls = |width: f64| ln_f(mode + direction * width) < max - 1.0;
+        let mut width = 1.0;
+        if falls(width) {
+            while width > f64::MIN_POSITIVE && falls(0.5 * width) {
+                width *= 0.5;
+            }
+        } else {
+            while width < 1e300 && !falls(width) {
+                width *= 2.0;
+            }
+        }
+        let integral = quadrature::gauss_kronrod(
+            |y| (ln_f(mode + direction * width * y) - max).exp(),
+            0.0,
+            f64::INFINITY,
+            tolerance,
+            1000,
+        )?;
+        sum += width * integral.value;
+    }
+    Ok(max + sum.ln())
+}
+
+/// Computes `inf { x | cdf(x) >= p }` on the domain `[min, max]`. The
+/// quantile is bracketed by stepping outwards, with doubling steps, from a
+/// finite end of the domain or from the origin, and the bracket is then
+/// refined with `solve`.
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgIntervalIncl` if `p` is not in `[0, 1]`, or
+/// `StatsError::ComputationFailedToConverge` if the quantile cannot be
+/// bracketed or `solve` fails
+pub fn inverse_cdf<C, S>(cdf: C, p: f64, min: f64, max: f64, solve: S) -> Result<f64>
+where
+    C: Fn(f64) -> f64,
+    S: FnOnce(&dyn Fn(f64) -> f64, f64, f64) -> Result<f64>,
+{
+    if !(0.0..=1.0).contains(&p) {
+        return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+    }
+    if p == 0.0 {
+        return Ok(min);
+    }
+    if p == 1.0 {
+        return Ok(max);
+    }
+    let f = |x: f64| cdf(x) - p;
+    if min.is_finite() && f(min) >= 0.0 {
+        return Ok(min);
+    }
+
+    let anchor = if min.is_finite() {
+        min
+    } else if max.is_finite() {
+        max
+    } else {
+        0.0
+    };
+    let mut low = min;
+    let mut width = 1.0;
+    while !low.is_finite() || f(low) >= 0.0 {
+        low = anchor - width;
+        if low.is_infinite() {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        width *= 2.0;
+    }
+    let mut high = max;
+    let mut width = 1.0;
+    while !high.is_finite() || f(high) < 0.0 {
+        if max.is_finite() {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        high = anchor + width;
+        if high.is_infinite() {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        width *= 2.0;
+    }
+
+    // a quantile far in a tail may be many orders of magnitude smaller than
+    // the bracket, and `solve` would need hundreds of bisections to reach it.
+    // Bisecting the ordered bit patterns of the ends instead halves the
+    // number of floats in the bracket at each step, which narrows the
+    // bracket until it is no wider than the magnitude of its ends in at most
+    // 64 steps.
+    while high - low > low.abs().min(high.abs()) {
+        let mid = from_ordered_bits(to_ordered_bits(low) / 2 + to_ordered_bits(high) / 2);
+        if mid <= low || mid >= high {
+            break;
+        }
+        if f(mid) >= 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    solve(&f, low, high)
+}
+
+/// Maps `x` to an integer such that the order of the integers matches the
+/// order of the floats, with adjacent floats mapping to adjacent integers
+fn to_ordered_bits(x: f64) -> i64 {
+    let bits = x.to_bits() as i64;
+    if bits < 0 {
+        -(bits & i64::MAX)
+    } else {
+        bits
+    }
+}
+
+/// The inverse of `to_ordered_bits`
+fn from_ordered_bits(bits: i64) -> f64 {
+    if bits < 0 {
+        -f64::from_bits((-bits) as u64)
+    } else {
+        f64::from_bits(bits as u64)
+    }
+}
+
+/// Computes the inverse cdf of `dist` at `p` as [`inverse_cdf`] does,
+/// refining the bracket with Newton steps on the pdf
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgIntervalIncl` if `p` is not in `[0, 1]`, or
+/// `StatsError::ComputationFailedToConverge` if the quantile cannot be
+/// bracketed or the iteration fails
+pub fn inverse_cdf_newton<D>(dist: &D, p: f64) -> Result<f64>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64>,
+{
+    inverse_cdf(
+        |x| dist.cdf(x),
+        p,
+        dist.min(),
+        dist.max(),
+        |f, low, high| {
+            solve::newton(
+                f,
+                |x| dist.pdf(x),
+                low,
+                high,
+                0.0,
+                solve::DEFAULT_MAX_ITERATIONS,
+            )
+        },
+    )
+}
+
+/// Returns `ln(Φ(x))`, the log of the standard normal cdf, without
+/// underflowing in the lower tail
+pub(crate) fn ln_standard_normal_cdf(x: f64) -> f64 {
+    if x > 0.0 {
+        (-0.5 * erf::erfc(x / f64::consts::SQRT_2)).ln_1p()
+    } else if x > -20.0 {
+        (0.5 * erf::erfc(-x / f64::consts::SQRT_2)).ln()
+    } else if x == f64::NEG_INFINITY {
+        f64::NEG_INFINITY
+    } else {
+        // asymptotic expansion Φ(x) = φ(x) / -x * Σ (-1)^k (2k - 1)!! / x^2k
+        let inv_x2 = 1.0 / (x * x);
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for k in 1..20 {
+            term *= -f64::from(2 * k - 1) * inv_x2;
+            sum += term;
+            if term.abs() < f64::EPSILON * sum {
+                break;
+            }
+        }
+        -0.5 * x * x - consts::LN_SQRT_2PI - (-x).ln() + sum.ln()
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::is_valid_multinomial;
     use crate::consts::ACC;
     use crate::distribution::{Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+    use crate::quadrature;
 
     /// cdf should be the integral of the pdf
     fn check_integrate_pdf_is_cdf<D: ContinuousCDF<f64, f64> + Continuous<f64, f64>>(
@@ -25,9 +358,13 @@ pub mod tests {
         x_max: f64,
         step: f64,
     ) {
+        let integrate = |a: f64, b: f64| {
+            quadrature::gauss_kronrod(|x| dist.pdf(x), a, b, 1e-10, 1000)
+                .unwrap()
+                .value
+        };
         let mut prev_x = x_min;
-        let mut prev_density = dist.pdf(x_min);
-        let mut sum = 0.0;
+        let mut sum = dist.cdf(x_min);
 
         loop {
             let x = prev_x + step;
@@ -39,11 +376,10 @@ pub mod tests {
 
             assert_almost_eq!(density.ln(), ln_density, 1e-10);
 
-            // triangle rule
-            sum += (prev_density + density) * step / 2.0;
+            sum += integrate(prev_x, x);
 
             let cdf = dist.cdf(x);
-            if (sum - cdf).abs() > 1e-3 {
+            if (sum - cdf).abs() > 1e-8 {
                 println!("Integral of pdf doesn't equal cdf!");
                 println!("Integration from {} by {} to {} = {}", x_min, step, x, sum);
                 println!("cdf = {}", cdf);
@@ -54,7 +390,6 @@ pub mod tests {
                 break;
             } else {
                 prev_x = x;
-                prev_density = density;
             }
         }
 
@@ -104,7 +439,7 @@ pub mod tests {
         assert_eq!(dist.cdf(f64::NEG_INFINITY), 0.0);
         assert_eq!(dist.cdf(f64::INFINITY), 1.0);
 
-        check_integrate_pdf_is_cdf(dist, x_min, x_max, (x_max - x_min) / 100000.0);
+        check_integrate_pdf_is_cdf(dist, x_min, x_max, (x_max - x_min) / 100.0);
     }
 
     /// Does a series of checks that all positive discrete distributions must
@@ -142,4 +477,34 @@ pub mod tests {
         let invalid = [5.2, 0.0, 1e-15, 1000000.12];
         assert!(!is_valid_multinomial(&invalid, false));
     }
+
+    #[test]
+    fn test_inverse_cdf_deep_tail() {
+        use super::inverse_cdf;
+        use crate::solve;
+
+        // the quantiles lie far below the bracket [0, 2] found for them
+        let cdf = |x: f64| x * x / 2.0;
+        let brent = |f: &dyn Fn(f64) -> f64, low, high| {
+            solve::brent(f, low, high, 0.0, solve::DEFAULT_MAX_ITERATIONS)
+        };
+        let newton = |f: &dyn Fn(f64) -> f64, low, high| {
+            solve::newton(f, |x| x, low, high, 0.0, solve::DEFAULT_MAX_ITERATIONS)
+        };
+        for &(p, expected) in &[
+            (1e-64, 1.4142135623730950e-32),
+            (1e-300, 1.4142135623730950e-150),
+        ] {
+            assert_almost_eq!(
+                inverse_cdf(cdf, p, 0.0, 2.0, brent).unwrap(),
+                expected,
+                expected * 1e-15
+            );
+            assert_almost_eq!(
+                inverse_cdf(cdf, p, 0.0, 2.0, newton).unwrap(),
+                expected,
+                expected * 1e-15
+            );
+        }
+    }
 }
@@ -115,6 +115,21 @@ impl ContinuousCDF<f64, f64> for InverseGamma {
             gamma::gamma_ur(self.shape, self.rate / x)
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// inverse gamma distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
 }
 
 impl Min<f64> for InverseGamma {
@@ -440,6 +455,13 @@ mod tests {
         test_almost(1.0, 1.0, 0.4345982085070782231613, 1e-14, cdf(1.2));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: InverseGamma| x.inverse_cdf(arg);
+        test_almost(3.0, 2.0, 0.37577595354094222716, 1e-15, inverse_cdf(0.1));
+        test_almost(3.0, 2.0, 1.8147744500565019907, 1e-14, inverse_cdf(0.9));
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 100.0);
@@ -0,0 +1,483 @@
+use crate::distribution::{internal, normal, Continuous, ContinuousCDF};
+use crate::function::exponential;
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Inverse Gaussian](https://en.wikipedia.org/wiki/Inverse_Gaussian_distribution)
+/// distribution, also known as the Wald distribution, the distribution of the
+/// first passage time of a Brownian motion with positive drift
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{InverseGaussian, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = InverseGaussian::new(1.0, 1.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.0);
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.3989422804014327, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InverseGaussian {
+    mean: f64,
+    shape: f64,
+}
+
+impl InverseGaussian {
+    /// Constructs a new inverse Gaussian distribution with a mean of `mean`
+    /// (μ) and a shape of `shape` (λ)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `shape` are `NaN` or infinite, or if
+    /// `mean <= 0.0` or `shape <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseGaussian;
+    ///
+    /// let mut result = InverseGaussian::new(1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = InverseGaussian::new(0.0, 2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(mean: f64, shape: f64) -> Result<InverseGaussian> {
+        if mean.is_finite() && shape.is_finite() && mean > 0.0 && shape > 0.0 {
+            Ok(InverseGaussian { mean, shape })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the shape of the inverse Gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseGaussian;
+    ///
+    /// let n = InverseGaussian::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.shape(), 2.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for InverseGaussian {
+    /// Draws a sample with the transformation method with multiple roots of
+    /// Michael, Schucany and Haas (1976)
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let z = normal::sample_unchecked(r, 0.0, 1.0);
+        let t = self.mean * z * z / (2.0 * self.shape);
+        // the smaller root μ(1 + t - sqrt(t(t + 2))), written without
+        // cancellation
+        let x = self.mean / (1.0 + t + (t * (t + 2.0)).sqrt());
+        if r.gen::<f64>() * (self.mean + x) <= self.mean {
+            x
+        } else {
+            self.mean * self.mean / x
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for InverseGaussian {
+    /// Calculates the cumulative distribution function for the inverse
+    /// Gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(sqrt(λ / x) * (x / μ - 1)) + e^(2λ / μ) * Φ(-sqrt(λ / x) * (x / μ + 1))
+    /// ```
+    ///
+    /// where `μ` is the mean, `λ` is the shape and `Φ` is the standard normal
+    /// cdf
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x == f64::INFINITY {
+            return 1.0;
+        }
+        let root = (self.shape / x).sqrt();
+        let lower = normal::cdf_unchecked(root * (x / self.mean - 1.0), 0.0, 1.0);
+        // the second term is formed in log space, where e^(2λ / μ) cannot
+        // overflow before it is offset by the normal tail
+        let upper = (2.0 * self.shape / self.mean
+            + internal::ln_standard_normal_cdf(-root * (x / self.mean + 1.0)))
+        .exp();
+        (lower + upper).min(1.0)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// inverse Gaussian distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        internal::inverse_cdf_newton(self, p)
+    }
+}
+
+impl Min<f64> for InverseGaussian {
+    /// Returns the minimum value in the domain of the inverse Gaussian
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for InverseGaussian {
+    /// Returns the maximum value in the domain of the inverse Gaussian
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for InverseGaussian {
+    /// Returns the mean of the inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn mean(&self) -> Option<f64> {
+        Some(self.mean)
+    }
+    /// Returns the variance of the inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ^3 / λ
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn variance(&self) -> Option<f64> {
+        Some(self.mean * self.mean * self.mean / self.shape)
+    }
+    /// Returns the entropy of the inverse Gaussian distribution
+    ///
+    /// # None
+    ///
+    /// If the exponential integral fails to converge
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * ln(2πeμ^3 / λ) - (3 / 2) * e^(2λ / μ) * E_1(2λ / μ)
+    /// ```
+    ///
+    /// where `μ` is the mean, `λ` is the shape and `E_1` is the exponential
+    /// integral
+    fn entropy(&self) -> Option<f64> {
+        let z = 2.0 * self.shape / self.mean;
+        let scaled_e1 = if z <= 700.0 {
+            z.exp() * exponential::integral(z, 1).ok()?
+        } else {
+            // asymptotic expansion e^z E_1(z) = Σ (-1)^k k! / z^(k + 1)
+            let mut term = 1.0 / z;
+            let mut sum = term;
+            for k in 1..10 {
+                term *= -f64::from(k) / z;
+                sum += term;
+            }
+            sum
+        };
+        Some(
+            consts::LN_SQRT_2PIE + 0.5 * (self.mean * self.mean * self.mean / self.shape).ln()
+                - 1.5 * scaled_e1,
+        )
+    }
+    /// Returns the skewness of the inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3 * sqrt(μ / λ)
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn skewness(&self) -> Option<f64> {
+        Some(3.0 * (self.mean / self.shape).sqrt())
+    }
+}
+
+impl Median<f64> for InverseGaussian {
+    /// Returns the median of the inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 / 2)
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for InverseGaussian {
+    /// Returns the mode of the inverse Gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ * (sqrt(1 + 9μ^2 / (4λ^2)) - 3μ / 2λ)
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn mode(&self) -> Option<f64> {
+        let k = 1.5 * self.mean / self.shape;
+        // μ(sqrt(1 + k^2) - k), written without cancellation
+        Some(self.mean / ((1.0 + k * k).sqrt() + k))
+    }
+}
+
+impl Continuous<f64, f64> for InverseGaussian {
+    /// Calculates the probability density function for the inverse Gaussian
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(λ / (2πx^3)) * e^(-λ(x - μ)^2 / (2μ^2 * x))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the inverse
+    /// Gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sqrt(λ / (2πx^3)) * e^(-λ(x - μ)^2 / (2μ^2 * x)))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let d = (x - self.mean) / self.mean;
+        0.5 * self.shape.ln() - consts::LN_SQRT_2PI - 1.5 * x.ln() - 0.5 * self.shape * d * d / x
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, InverseGaussian};
+    use crate::distribution::internal::*;
+
+    fn try_create(mean: f64, shape: f64) -> InverseGaussian {
+        let n = InverseGaussian::new(mean, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(mean: f64, shape: f64) {
+        let n = InverseGaussian::new(mean, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(mean: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(InverseGaussian) -> f64
+    {
+        let n = try_create(mean, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(mean: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(InverseGaussian) -> f64
+    {
+        let n = try_create(mean, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(2.0, 5.0);
+        assert_eq!(n.mean().unwrap(), 2.0);
+        assert_eq!(n.shape(), 5.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: InverseGaussian| x.variance().unwrap();
+        test_case(1.0, 1.0, 1.0, variance);
+        test_almost(2.0, 5.0, 1.6, 1e-15, variance);
+        test_case(3.0, 0.2, 135.0, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: InverseGaussian| x.entropy().unwrap();
+        test_almost(1.0, 1.0, 0.87694560787233886473, 1e-14, entropy);
+        test_almost(2.0, 5.0, 1.3983070834004422159, 1e-14, entropy);
+        test_almost(3.0, 0.2, 1.18632831297069673, 1e-14, entropy);
+        test_almost(1.0, 1000.0, -2.0356887316608344064, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: InverseGaussian| x.skewness().unwrap();
+        test_case(1.0, 1.0, 3.0, skewness);
+        test_almost(2.0, 5.0, 1.8973665961010275992, 1e-15, skewness);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: InverseGaussian| x.median();
+        test_almost(1.0, 1.0, 0.67584130569523911919, 1e-10, median);
+        test_almost(2.0, 5.0, 1.673116539652631097, 1e-10, median);
+        test_almost(3.0, 0.2, 0.380739793444906212, 1e-10, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: InverseGaussian| x.mode().unwrap();
+        test_almost(1.0, 1.0, 0.30277563773199464656, 1e-15, mode);
+        test_almost(2.0, 5.0, 1.1323807579381201883, 1e-15, mode);
+        test_almost(3.0, 0.2, 0.066633777331248663494, 1e-16, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: InverseGaussian| x.min();
+        let max = |x: InverseGaussian| x.max();
+        test_case(1.0, 1.0, 0.0, min);
+        test_case(1.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: InverseGaussian| x.pdf(arg);
+        test_almost(1.0, 1.0, 0.21979480031862670051, 1e-15, pdf(0.1));
+        test_almost(1.0, 1.0, 0.87878257893544479409, 1e-15, pdf(0.5));
+        test_almost(1.0, 1.0, 0.00021979480031862670051, 1e-18, pdf(10.0));
+        test_almost(2.0, 5.0, 4.4835892585853342985e-9, 1e-22, pdf(0.1));
+        test_almost(2.0, 5.0, 0.31539156525252000603, 1e-15, pdf(2.0));
+        test_almost(3.0, 0.2, 2.2161594175494587737, 1e-14, pdf(0.1));
+        test_case(1.0, 1.0, 0.0, pdf(0.0));
+        test_case(1.0, 1.0, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: InverseGaussian| x.ln_pdf(arg);
+        test_almost(1.0, 1.0, -0.91893853320467274178, 1e-15, ln_pdf(1.0));
+        test_almost(2.0, 5.0, -19.222841937496554028, 1e-14, ln_pdf(0.1));
+        test_almost(3.0, 0.2, -5.2319795733572358996, 1e-14, ln_pdf(10.0));
+        test_almost(0.5, 100.0, -315.16247580071955853, 1e-12, ln_pdf(0.1));
+        test_almost(0.5, 100.0, -1807.0702310797016956, 1e-11, ln_pdf(10.0));
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: InverseGaussian| x.cdf(arg);
+        test_almost(1.0, 1.0, 0.0040761113207110123523, 1e-12, cdf(0.1));
+        test_almost(1.0, 1.0, 0.36497554817295989059, 1e-10, cdf(0.5));
+        test_almost(1.0, 1.0, 0.99964958546279118085, 1e-10, cdf(10.0));
+        test_almost(2.0, 5.0, 1.7635520472635186058e-11, 1e-20, cdf(0.1));
+        test_almost(2.0, 5.0, 0.61616314718823253715, 1e-10, cdf(2.0));
+        test_almost(3.0, 0.2, 0.16802394513877303282, 1e-10, cdf(0.1));
+        test_almost(3.0, 0.2, 0.93779910927462494821, 1e-10, cdf(10.0));
+        test_almost(0.5, 100.0, 0.51408717437052565966, 1e-10, cdf(0.5));
+        test_case(1.0, 1.0, 0.0, cdf(0.0));
+        test_case(1.0, 1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf_large_shape() {
+        // e^(2λ / μ) alone overflows here
+        let n = try_create(0.5, 400.0);
+        assert!(n.cdf(0.5).is_finite());
+        assert!((n.cdf(0.5) - 0.50705016799168890681).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: InverseGaussian| x.inverse_cdf(arg);
+        test_almost(1.0, 1.0, 0.11984124059586299057, 1e-10, inverse_cdf(0.01));
+        test_almost(1.0, 1.0, 4.9840948434056703141, 1e-9, inverse_cdf(0.99));
+        test_almost(2.0, 5.0, 0.46614956172323725958, 1e-10, inverse_cdf(0.01));
+        test_almost(2.0, 5.0, 6.4838575406229313747, 1e-9, inverse_cdf(0.99));
+        test_almost(3.0, 0.2, 0.029615266285576592651, 1e-10, inverse_cdf(0.01));
+        test_almost(3.0, 0.2, 50.453231679898097185, 1e-7, inverse_cdf(0.99));
+        test_case(1.0, 1.0, 0.0, inverse_cdf(0.0));
+        assert!(try_create(1.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 1.0), 0.0, 20.0);
+        tests::check_continuous_distribution(&try_create(2.0, 5.0), 0.0, 20.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(2.0, 5.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!(samples.iter().all(|&x| x > 0.0));
+        assert!((mean - 2.0).abs() < 0.02);
+        assert!((variance - 1.6).abs() < 0.05);
+    }
+}
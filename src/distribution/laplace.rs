@@ -0,0 +1,453 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::OpenClosed01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Laplace](https://en.wikipedia.org/wiki/Laplace_distribution)
+/// distribution, also known as the double exponential distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Laplace, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Laplace::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Laplace {
+    location: f64,
+    scale: f64,
+}
+
+impl Laplace {
+    /// Constructs a new laplace distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let mut result = Laplace::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Laplace::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Laplace> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Laplace { location, scale })
+        }
+    }
+
+    /// Returns the location of the laplace distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let n = Laplace::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the laplace distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let n = Laplace::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Laplace {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        // a standard exponential variate with a random sign
+        let u: f64 = r.sample(OpenClosed01);
+        if r.gen::<bool>() {
+            self.location - self.scale * u.ln()
+        } else {
+            self.location + self.scale * u.ln()
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Laplace {
+    /// Calculates the cumulative distribution function for the
+    /// laplace distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^((x - μ) / b) / 2          if x < μ
+    /// 1 - e^(-(x - μ) / b) / 2     otherwise
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// laplace distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + b * ln(2p)           if p <= 0.5
+    /// μ - b * ln(2(1 - p))     otherwise
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else if p <= 0.5 {
+            Ok(self.location + self.scale * (2.0 * p).ln())
+        } else {
+            Ok(self.location - self.scale * (2.0 * (1.0 - p)).ln())
+        }
+    }
+}
+
+impl Min<f64> for Laplace {
+    /// Returns the minimum value in the domain of the laplace
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Laplace {
+    /// Returns the maximum value in the domain of the laplace
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Laplace {
+    /// Returns the mean of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2b^2
+    /// ```
+    ///
+    /// where `b` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * self.scale * self.scale)
+    }
+    /// Returns the entropy of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2eb)
+    /// ```
+    ///
+    /// where `b` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some((2.0 * self.scale).ln() + 1.0)
+    }
+    /// Returns the skewness of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Median<f64> for Laplace {
+    /// Returns the median of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for Laplace {
+    /// Returns the mode of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Laplace {
+    /// Calculates the probability density function for the laplace
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-|x - μ| / b) / 2b
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        (-(x - self.location).abs() / self.scale).exp() / (2.0 * self.scale)
+    }
+
+    /// Calculates the log probability density function for the laplace
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -|x - μ| / b - ln(2b)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        -(x - self.location).abs() / self.scale - (2.0 * self.scale).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Laplace};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Laplace {
+        let n = Laplace::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Laplace::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Laplace) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Laplace) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(10.0, 11.0);
+        create_case(-5.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Laplace| x.mean().unwrap();
+        test_case(0.0, 1.0, 0.0, mean);
+        test_case(-3.0, 0.5, -3.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Laplace| x.variance().unwrap();
+        test_case(0.0, 1.0, 2.0, variance);
+        test_case(1.0, 2.0, 8.0, variance);
+        test_case(-3.0, 0.5, 0.5, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Laplace| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 1.693147180559945309417, 1e-15, entropy);
+        test_almost(1.0, 2.0, 2.386294361119890618834, 1e-15, entropy);
+        test_almost(-3.0, 0.5, 1.0, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Laplace| x.skewness().unwrap();
+        test_case(1.0, 2.0, 0.0, skewness);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: Laplace| x.median();
+        let mode = |x: Laplace| x.mode().unwrap();
+        test_case(-3.0, 0.5, -3.0, median);
+        test_case(-3.0, 0.5, -3.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Laplace| x.min();
+        let max = |x: Laplace| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Laplace| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-18, pdf(-5.0));
+        test_case(0.0, 1.0, 0.5, pdf(0.0));
+        test_almost(0.0, 1.0, 0.3032653298563167118019, 1e-16, pdf(0.5));
+        test_almost(0.0, 1.0, 0.0000226999648812424257678, 1e-20, pdf(10.0));
+        test_almost(1.0, 2.0, 0.09196986029286058039888, 1e-16, pdf(-1.0));
+        test_almost(1.0, 2.0, 0.1516326649281583559009, 1e-16, pdf(2.0));
+        test_almost(-3.0, 0.5, 0.01831563888873418029372, 1e-17, pdf(-5.0));
+        test_almost(-3.0, 0.5, 5.109089028063324719874e-12, 1e-26, pdf(10.0));
+        test_case(0.0, 1.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Laplace| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -5.693147180559945309417, 1e-15, ln_pdf(-5.0));
+        test_almost(0.0, 1.0, -0.6931471805599453094172, 1e-15, ln_pdf(0.0));
+        test_almost(1.0, 2.0, -1.636294361119890618834, 1e-15, ln_pdf(0.5));
+        test_almost(-3.0, 0.5, -26.0, 1e-14, ln_pdf(10.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Laplace| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-18, cdf(-5.0));
+        test_case(0.0, 1.0, 0.5, cdf(0.0));
+        test_almost(0.0, 1.0, 0.6967346701436832881981, 1e-16, cdf(0.5));
+        test_almost(0.0, 1.0, 0.9999773000351187575742, 1e-16, cdf(10.0));
+        test_almost(1.0, 2.0, 0.02489353418393197148967, 1e-17, cdf(-5.0));
+        test_almost(1.0, 2.0, 0.3894003915357024341226, 1e-16, cdf(0.5));
+        test_almost(-3.0, 0.5, 0.9908421805556329098531, 1e-16, cdf(-1.0));
+        test_almost(-3.0, 0.5, 0.999999999997445455486, 1e-16, cdf(10.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Laplace| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, -3.912023005428146037802, 1e-15, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, -0.5108256237659907202129, 1e-15, inverse_cdf(0.3));
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 1.609437912434100596645, 1e-15, inverse_cdf(0.9));
+        test_almost(1.0, 2.0, -6.824046010856292075604, 1e-14, inverse_cdf(0.01));
+        test_almost(1.0, 2.0, 4.218875824868201193291, 1e-14, inverse_cdf(0.9));
+        test_almost(-3.0, 0.5, -3.255412811882995360106, 1e-15, inverse_cdf(0.3));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -40.0, 40.0);
+        tests::check_continuous_distribution(&try_create(-3.0, 0.5), -25.0, 20.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(1.0, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!((mean - n.mean().unwrap()).abs() < 0.05);
+        assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.05);
+    }
+}
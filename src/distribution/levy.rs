@@ -0,0 +1,440 @@
+use crate::distribution::{normal, Continuous, ContinuousCDF};
+use crate::function::erf;
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Lévy](https://en.wikipedia.org/wiki/L%C3%A9vy_distribution)
+/// distribution, the stable distribution with a stability of `1 / 2` and a
+/// skewness of `1`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Levy, Continuous};
+/// use statrs::statistics::Mode;
+/// use statrs::prec;
+///
+/// let n = Levy::new(0.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.mode().unwrap(), 1.0 / 3.0, 1e-16));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.24197072451914335, 1e-16));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Levy {
+    location: f64,
+    scale: f64,
+}
+
+impl Levy {
+    /// Constructs a new Lévy distribution with a location (μ) of `location`
+    /// and a scale (c) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `scale` are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Levy;
+    ///
+    /// let mut result = Levy::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Levy::new(0.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Levy> {
+        if location.is_finite() && scale.is_finite() && scale > 0.0 {
+            Ok(Levy { location, scale })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the location of the Lévy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Levy;
+    ///
+    /// let n = Levy::new(-1.0, 2.0).unwrap();
+    /// assert_eq!(n.location(), -1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the Lévy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Levy;
+    ///
+    /// let n = Levy::new(-1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Levy {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let z = normal::sample_unchecked(r, 0.0, 1.0);
+        self.location + self.scale / (z * z)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Levy {
+    /// Calculates the cumulative distribution function for the Lévy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// erfc(sqrt(c / (2(x - μ))))
+    /// ```
+    ///
+    /// where `μ` is the location, `c` is the scale and `erfc` is the
+    /// complementary error function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            0.0
+        } else {
+            erf::erfc((self.scale / (2.0 * (x - self.location))).sqrt())
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the Lévy
+    /// distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + c / (2 * erfc_inv(p)^2)
+    /// ```
+    ///
+    /// where `μ` is the location, `c` is the scale and `erfc_inv` is the
+    /// inverse of the complementary error function
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else if p == 0.0 {
+            Ok(self.location)
+        } else {
+            let z = erf::erfc_inv(p);
+            Ok(self.location + self.scale / (2.0 * z * z))
+        }
+    }
+}
+
+impl Min<f64> for Levy {
+    /// Returns the minimum value in the domain of the Lévy distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for Levy {
+    /// Returns the maximum value in the domain of the Lévy distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Levy {
+    /// Returns the mean of the Lévy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the mean of the Lévy distribution is infinite
+    fn mean(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the variance of the Lévy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the variance of the Lévy distribution is infinite
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the entropy of the Lévy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 + 3γ + ln(16πc^2)) / 2
+    /// ```
+    ///
+    /// where `c` is the scale and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(
+            0.5 * (1.0
+                + 3.0 * consts::EULER_MASCHERONI
+                + (16.0 * f64::consts::PI).ln()
+                + 2.0 * self.scale.ln()),
+        )
+    }
+    /// Returns the skewness of the Lévy distribution
+    ///
+    /// # None
+    ///
+    /// Always, since the skewness of the Lévy distribution is undefined
+    fn skewness(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl Median<f64> for Levy {
+    /// Returns the median of the Lévy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + c / (2 * erfc_inv(1 / 2)^2)
+    /// ```
+    ///
+    /// where `μ` is the location, `c` is the scale and `erfc_inv` is the
+    /// inverse of the complementary error function
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for Levy {
+    /// Returns the mode of the Lévy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + c / 3
+    /// ```
+    ///
+    /// where `μ` is the location and `c` is the scale
+    fn mode(&self) -> Option<f64> {
+        Some(self.location + self.scale / 3.0)
+    }
+}
+
+impl Continuous<f64, f64> for Levy {
+    /// Calculates the probability density function for the Lévy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(c / 2π) * e^(-c / (2(x - μ))) / (x - μ)^(3 / 2)
+    /// ```
+    ///
+    /// for `x > μ`, where `μ` is the location and `c` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the Lévy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sqrt(c / 2π) * e^(-c / (2(x - μ))) / (x - μ)^(3 / 2))
+    /// ```
+    ///
+    /// for `x > μ`, where `μ` is the location and `c` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= self.location || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let d = x - self.location;
+        0.5 * self.scale.ln() - consts::LN_SQRT_2PI - 0.5 * self.scale / d - 1.5 * d.ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Levy};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Levy {
+        let n = Levy::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Levy::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Levy) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Levy) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0);
+        create_case(-1.0, 2.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(0.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(0.0, f64::INFINITY);
+        bad_create_case(0.0, 0.0);
+        bad_create_case(0.0, -1.0);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(0.0, 1.0);
+        assert!(n.mean().is_none());
+        assert!(n.variance().is_none());
+        assert!(n.skewness().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Levy| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 3.3244828013968899968, 1e-15, entropy);
+        test_almost(-1.0, 2.0, 4.0176299819568353062, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Levy| x.median();
+        test_almost(0.0, 1.0, 2.198109338317732404, 1e-13, median);
+        test_almost(-1.0, 2.0, 3.396218676635464808, 1e-13, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Levy| x.mode().unwrap();
+        test_almost(0.0, 1.0, 1.0 / 3.0, 1e-16, mode);
+        test_almost(-1.0, 2.0, -1.0 / 3.0, 1e-16, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Levy| x.min();
+        let max = |x: Levy| x.max();
+        test_case(-1.0, 2.0, -1.0, min);
+        test_case(-1.0, 2.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Levy| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.085003666025203418128, 1e-16, pdf(0.1));
+        test_almost(0.0, 1.0, 0.41510749742059470334, 1e-15, pdf(0.5));
+        test_almost(0.0, 1.0, 0.012000389484301359765, 1e-17, pdf(10.0));
+        test_almost(-1.0, 2.0, 0.19702569609455964954, 1e-15, pdf(0.1));
+        test_almost(-1.0, 2.0, 0.014120651001872745061, 1e-17, pdf(10.0));
+        test_case(0.0, 1.0, 0.0, pdf(0.0));
+        test_case(0.0, 1.0, 0.0, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Levy| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -2.4650608937136042158, 1e-15, ln_pdf(0.1));
+        test_almost(0.0, 1.0, -35.457714928165358002, 1e-13, ln_pdf(1e10));
+        test_almost(-1.0, 2.0, -2.5536167092601979575, 1e-15, ln_pdf(2.0));
+        test_almost(-1.0, 2.0, -35.111141338085385347, 1e-13, ln_pdf(1e10));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Levy| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.0015654022580025496775, 1e-10, cdf(0.1));
+        test_almost(0.0, 1.0, 0.15729920705028513066, 1e-10, cdf(0.5));
+        test_almost(0.0, 1.0, 0.75182963404584927583, 1e-10, cdf(10.0));
+        test_almost(0.0, 1.0, 0.99999202115439210433, 1e-10, cdf(1e10));
+        test_almost(-1.0, 2.0, 0.17752985241215346685, 1e-10, cdf(0.1));
+        test_almost(-1.0, 2.0, 0.41421617824252511665, 1e-10, cdf(2.0));
+        test_case(0.0, 1.0, 0.0, cdf(0.0));
+        test_case(0.0, 1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Levy| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 0.15071824930113971063, 1e-13, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, 6365.8643851062312243, 1e-8, inverse_cdf(0.99));
+        test_almost(-1.0, 2.0, -0.69856350139772057874, 1e-13, inverse_cdf(0.01));
+        test_almost(-1.0, 2.0, 12730.728770212462449, 1e-8, inverse_cdf(0.99));
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), 0.0, 10_000.0);
+        tests::check_continuous_distribution(&try_create(-1.0, 2.0), -1.0, 20_000.0);
+    }
+
+    #[test]
+    fn test_sample_median() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(-1.0, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| x >= -1.0));
+        let below = samples.iter().filter(|&&x| x < n.median()).count() as f64 / 100_000.0;
+        assert!((below - 0.5).abs() < 0.01);
+    }
+}
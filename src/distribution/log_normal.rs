@@ -83,6 +83,29 @@ impl ContinuousCDF<f64, f64> for LogNormal {
             0.5 * erf::erfc((self.location - x.ln()) / (self.scale * f64::consts::SQRT_2))
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// log-normal distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ - sqrt(2) * σ * erfc_inv(2x))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `erfc_inv` is the
+    /// inverse of the complementary error function
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&x) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else {
+            Ok((self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x)).exp())
+        }
+    }
 }
 
 impl Min<f64> for LogNormal {
@@ -590,6 +613,17 @@ mod tests {
         test_almost(2.5, 2.5, 0.13802019192453118732001307556787218421918336849121, 1e-11, cdf(0.8));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: LogNormal| x.inverse_cdf(arg);
+        test_almost(0.5, 1.5, 0.24115211830713785700, 1e-15, inverse_cdf(0.1));
+        test_almost(0.5, 1.5, 11.272062827152809890, 1e-13, inverse_cdf(0.9));
+        test_almost(0.5, 1.5, 2.2841888230582157447e-14, 1e-27, inverse_cdf(1e-100));
+        test_case(0.5, 1.5, 0.0, inverse_cdf(0.0));
+        test_case(0.5, 1.5, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(0.5, 1.5).checked_inverse_cdf(1.5).is_err());
+    }
+
     #[test]
     fn test_neg_cdf() {
         let cdf = |arg: f64| move |x: LogNormal| x.cdf(arg);
@@ -0,0 +1,439 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::logistic;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Logistic](https://en.wikipedia.org/wiki/Logistic_distribution)
+/// distribution, whose cdf is the logistic function
+/// (referenced [here](../function/logistic/index.html))
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Logistic, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Logistic::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.25);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    /// Constructs a new logistic distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let mut result = Logistic::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Logistic::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Logistic> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Logistic { location, scale })
+        }
+    }
+
+    /// Returns the location of the logistic distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let n = Logistic::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the logistic distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let n = Logistic::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Logistic {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.location + self.scale * (u / (1.0 - u)).ln()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Logistic {
+    /// Calculates the cumulative distribution function for the
+    /// logistic distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (1 + e^(-(x - μ) / s))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        logistic::logistic((x - self.location) / self.scale)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// logistic distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + s * ln(p / (1 - p))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        Ok(self.location + self.scale * logistic::checked_logit(p)?)
+    }
+}
+
+impl Min<f64> for Logistic {
+    /// Returns the minimum value in the domain of the logistic
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Logistic {
+    /// Returns the maximum value in the domain of the logistic
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Logistic {
+    /// Returns the mean of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s^2 * π^2 / 3
+    /// ```
+    ///
+    /// where `s` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(self.scale * self.scale * f64::consts::PI * f64::consts::PI / 3.0)
+    }
+    /// Returns the entropy of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(s) + 2
+    /// ```
+    ///
+    /// where `s` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + 2.0)
+    }
+    /// Returns the skewness of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Median<f64> for Logistic {
+    /// Returns the median of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for Logistic {
+    /// Returns the mode of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Logistic {
+    /// Calculates the probability density function for the logistic
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-|x - μ| / s) / (s * (1 + e^(-|x - μ| / s))^2)
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        let e = (-((x - self.location) / self.scale).abs()).exp();
+        e / (self.scale * (1.0 + e) * (1.0 + e))
+    }
+
+    /// Calculates the log probability density function for the logistic
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -|x - μ| / s - ln(s) - 2 * ln(1 + e^(-|x - μ| / s))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = ((x - self.location) / self.scale).abs();
+        -z - self.scale.ln() - 2.0 * (-z).exp().ln_1p()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Logistic};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Logistic {
+        let n = Logistic::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Logistic::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Logistic) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Logistic) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(10.0, 11.0);
+        create_case(-5.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Logistic| x.mean().unwrap();
+        test_case(0.0, 1.0, 0.0, mean);
+        test_case(-3.0, 0.5, -3.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Logistic| x.variance().unwrap();
+        test_almost(0.0, 1.0, 3.289868133696452872945, 1e-15, variance);
+        test_almost(1.0, 2.0, 13.15947253478581149178, 1e-14, variance);
+        test_almost(-3.0, 0.5, 0.8224670334241132182362, 1e-15, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Logistic| x.entropy().unwrap();
+        test_case(0.0, 1.0, 2.0, entropy);
+        test_almost(1.0, 2.0, 2.693147180559945309417, 1e-15, entropy);
+        test_almost(-3.0, 0.5, 1.306852819440054690583, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Logistic| x.skewness().unwrap();
+        test_case(1.0, 2.0, 0.0, skewness);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: Logistic| x.median();
+        let mode = |x: Logistic| x.mode().unwrap();
+        test_case(-3.0, 0.5, -3.0, median);
+        test_case(-3.0, 0.5, -3.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Logistic| x.min();
+        let max = |x: Logistic| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Logistic| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.006648056670790154913999, 1e-17, pdf(-5.0));
+        test_case(0.0, 1.0, 0.25, pdf(0.0));
+        test_almost(0.0, 1.0, 0.2350037122015944890693, 1e-16, pdf(0.5));
+        test_almost(0.0, 1.0, 0.00004539580773595167103244, 1e-19, pdf(10.0));
+        test_almost(1.0, 2.0, 0.02258832986545606632468, 1e-17, pdf(-5.0));
+        test_almost(1.0, 2.0, 0.1175018561007972445347, 1e-16, pdf(2.0));
+        test_almost(-3.0, 0.5, 0.03532541242658223284312, 1e-16, pdf(-1.0));
+        test_almost(-3.0, 0.5, 1.021817805602223827696e-11, 1e-25, pdf(10.0));
+        test_case(0.0, 1.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Logistic| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -5.013430696978236137233, 1e-15, ln_pdf(-5.0));
+        test_almost(0.0, 1.0, -1.386294361119890618834, 1e-15, ln_pdf(0.0));
+        test_almost(1.0, 2.0, -2.095026020317632433833, 1e-15, ln_pdf(0.5));
+        test_almost(-3.0, 0.5, -25.30685281945027286864, 1e-14, ln_pdf(10.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Logistic| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.006692850924284855559362, 1e-17, cdf(-5.0));
+        test_case(0.0, 1.0, 0.5, cdf(0.0));
+        test_almost(0.0, 1.0, 0.6224593312018545646389, 1e-16, cdf(0.5));
+        test_almost(0.0, 1.0, 0.9999546021312975656055, 1e-16, cdf(10.0));
+        test_almost(1.0, 2.0, 0.04742587317756678087885, 1e-17, cdf(-5.0));
+        test_almost(1.0, 2.0, 0.4378234991142018959727, 1e-16, cdf(0.5));
+        test_almost(-3.0, 0.5, 0.9820137900379084419732, 1e-16, cdf(-1.0));
+        test_almost(-3.0, 0.5, 0.999999999994890910972, 1e-15, cdf(10.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Logistic| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, -4.595119850134589905825, 1e-15, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, -0.8472978603872036665779, 1e-15, inverse_cdf(0.3));
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 2.197224577336219629507, 1e-15, inverse_cdf(0.9));
+        test_almost(1.0, 2.0, -8.190239700269179811651, 1e-14, inverse_cdf(0.01));
+        test_almost(1.0, 2.0, 5.394449154672439259013, 1e-14, inverse_cdf(0.9));
+        test_almost(-3.0, 0.5, -3.423648930193601833289, 1e-15, inverse_cdf(0.3));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(0.0, 1.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -40.0, 40.0);
+        tests::check_continuous_distribution(&try_create(-3.0, 0.5), -25.0, 20.0);
+    }
+
+    #[test]
+    fn test_sample_moments() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(1.0, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 100_000.0;
+        assert!((mean - n.mean().unwrap()).abs() < 0.05);
+        assert!((variance / n.variance().unwrap() - 1.0).abs() < 0.05);
+    }
+}
@@ -18,19 +18,37 @@ pub use self::empirical::Empirical;
 pub use self::erlang::Erlang;
 pub use self::exponential::Exp;
 pub use self::fisher_snedecor::FisherSnedecor;
+pub use self::folded_normal::FoldedNormal;
+pub use self::frechet::Frechet;
 pub use self::gamma::Gamma;
+pub use self::generalized_extreme_value::GeneralizedExtremeValue;
+pub use self::generalized_inverse_gaussian::GeneralizedInverseGaussian;
+pub use self::generalized_pareto::GeneralizedPareto;
 pub use self::geometric::Geometric;
+pub use self::gumbel::Gumbel;
+pub use self::half_cauchy::HalfCauchy;
+pub use self::half_normal::HalfNormal;
+pub use self::half_students_t::HalfStudentsT;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
+pub use self::inverse_gaussian::InverseGaussian;
+pub use self::laplace::Laplace;
+pub use self::levy::Levy;
 pub use self::log_normal::LogNormal;
+pub use self::logistic::Logistic;
 pub use self::multinomial::Multinomial;
 pub use self::multivariate_normal::MultivariateNormal;
 pub use self::negative_binomial::NegativeBinomial;
+pub use self::noncentral_beta::NoncentralBeta;
+pub use self::noncentral_chi_squared::NoncentralChiSquared;
+pub use self::noncentral_fisher_snedecor::NoncentralFisherSnedecor;
+pub use self::noncentral_students_t::NoncentralStudentsT;
 pub use self::normal::Normal;
 pub use self::pareto::Pareto;
 pub use self::poisson::Poisson;
 pub use self::students_t::StudentsT;
 pub use self::triangular::Triangular;
+pub use self::truncated::Truncated;
 pub use self::uniform::Uniform;
 pub use self::weibull::Weibull;
 
@@ -48,26 +66,44 @@ mod empirical;
 mod erlang;
 mod exponential;
 mod fisher_snedecor;
+mod folded_normal;
+mod frechet;
 mod gamma;
+mod generalized_extreme_value;
+mod generalized_inverse_gaussian;
+mod generalized_pareto;
 mod geometric;
+mod gumbel;
+mod half_cauchy;
+mod half_normal;
+mod half_students_t;
 mod hypergeometric;
 mod internal;
 mod inverse_gamma;
+mod inverse_gaussian;
+mod laplace;
+mod levy;
 mod log_normal;
+mod logistic;
 mod multinomial;
 mod multivariate_normal;
 mod negative_binomial;
+mod noncentral_beta;
+mod noncentral_chi_squared;
+mod noncentral_fisher_snedecor;
+mod noncentral_students_t;
 mod normal;
 mod pareto;
 mod poisson;
 mod students_t;
 mod triangular;
+mod truncated;
 mod uniform;
 mod weibull;
 mod ziggurat;
 mod ziggurat_tables;
 
-use crate::Result;
+use crate::{solve, Result, StatsError};
 
 /// The `ContinuousCDF` trait is used to specify an interface for univariate
 /// distributions for which cdf float arguments are sensible.
@@ -85,39 +121,66 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
     /// assert_eq!(0.5, n.cdf(0.5));
     /// ```
     fn cdf(&self, x: K) -> T;
-    /// Due to issues with rounding and floating-point accuracy the default
-    /// implementation may be ill-behaved.
-    /// Specialized inverse cdfs should be used whenever possible.
-    /// Performs a binary search on the domain of `cdf` to obtain an approximation
-    /// of `F^-1(p) := inf { x | F(x) >= p }`. Needless to say, performance may
-    /// may be lacking.
+    /// Returns the inverse cumulative distribution function calculated at
+    /// `p` for a given distribution, `F^-1(p) := inf { x | F(x) >= p }`.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not in `[0, 1]` or the default implementation fails to
+    /// converge, see `checked_inverse_cdf`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Gamma};
+    /// use statrs::prec;
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!(prec::almost_eq(n.cdf(n.inverse_cdf(0.9)), 0.9, 1e-15));
+    /// ```
     fn inverse_cdf(&self, p: T) -> K {
-        if p == T::zero() {
-            return self.min();
-        };
-        if p == T::one() {
-            return self.max();
-        };
-        let two = K::one() + K::one();
-        let mut high = two;
-        let mut low = -high;
-        while self.cdf(low) > p {
-            low = low + low;
-        }
-        while self.cdf(high) < p {
-            high = high + high;
-        }
-        let mut i = 16;
-        while i != 0 {
-            let mid = (high + low) / two;
-            if self.cdf(mid) >= p {
-                high = mid;
-            } else {
-                low = mid;
-            }
-            i -= 1;
-        }
-        (high + low) / two
+        self.checked_inverse_cdf(p).unwrap()
+    }
+    /// Returns the inverse cumulative distribution function calculated at
+    /// `p` for a given distribution, `F^-1(p) := inf { x | F(x) >= p }`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StatsError::ArgIntervalIncl` if `p` is not in `[0, 1]`, or
+    /// `StatsError::ComputationFailedToConverge` if the quantile could not
+    /// be found
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation brackets the quantile within
+    /// `[min(), max()]` and refines it to near machine precision with
+    /// Brent's method. Distributions with a closed-form inverse override
+    /// `inverse_cdf`, and those with a density usually override this method
+    /// to use Newton steps on the pdf instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Gamma};
+    ///
+    /// let n = Gamma::new(3.0, 2.0).unwrap();
+    /// assert!(n.checked_inverse_cdf(0.5).is_ok());
+    /// assert!(n.checked_inverse_cdf(1.5).is_err());
+    /// ```
+    fn checked_inverse_cdf(&self, p: T) -> Result<K> {
+        let to_f64 = |x: K| x.to_f64().unwrap_or(f64::NAN);
+        let x = internal::inverse_cdf(
+            |x| {
+                K::from(x)
+                    .and_then(|x| self.cdf(x).to_f64())
+                    .unwrap_or(f64::NAN)
+            },
+            p.to_f64().unwrap_or(f64::NAN),
+            to_f64(self.min()),
+            to_f64(self.max()),
+            |f, low, high| solve::brent(f, low, high, 0.0, solve::DEFAULT_MAX_ITERATIONS),
+        )?;
+        K::from(x).ok_or(StatsError::ComputationFailedToConverge)
     }
 }
 
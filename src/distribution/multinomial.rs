@@ -163,7 +163,7 @@ impl VarianceN<DMatrix<f64>> for Multinomial {
 //     }
 // }
 
-impl<'a> Discrete<&'a [u64], f64> for Multinomial {
+impl Discrete<&[u64], f64> for Multinomial {
     /// Calculates the probability mass function for the multinomial
     /// distribution
     /// with the given `x`'s corresponding to the probabilities for this
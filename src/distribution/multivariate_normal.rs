@@ -110,7 +110,6 @@ impl ::rand::distributions::Distribution<DVector<f64>> for MultivariateNormal {
     /// where `L` is the Cholesky decomposition of the covariance matrix,
     /// `Z` is a vector of normally distributed random variables, and
     /// `μ` is the mean vector
-
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVector<f64> {
         let d = Normal::new(0., 1.).unwrap();
         let z = DVector::<f64>::from_distribution(self.dim, &d, rng);
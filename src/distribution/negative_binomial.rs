@@ -48,7 +48,7 @@ impl NegativeBinomial {
     /// assert!(result.is_err());
     /// ```
     pub fn new(r: f64, p: f64) -> Result<NegativeBinomial> {
-        if p.is_nan() || p < 0.0 || p > 1.0 || r.is_nan() || r < 0.0 {
+        if p.is_nan() || !(0.0..=1.0).contains(&p) || r.is_nan() || r < 0.0 {
             Err(StatsError::BadParams)
         } else {
             Ok(NegativeBinomial { p, r })
@@ -141,7 +141,7 @@ impl Max<u64> for NegativeBinomial {
     /// u64::MAX
     /// ```
     fn max(&self) -> u64 {
-        std::u64::MAX
+        u64::MAX
     }
 }
 
@@ -341,7 +341,7 @@ mod tests {
         let min = |x: NegativeBinomial| x.min();
         let max = |x: NegativeBinomial| x.max();
         test_case(1.0, 0.5, 0, min);
-        test_case(1.0, 0.3, std::u64::MAX, max);
+        test_case(1.0, 0.3, u64::MAX, max);
     }
 
     #[test]
@@ -0,0 +1,441 @@
+use crate::distribution::internal::{ln_poisson_mixture, poisson_mixture};
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::beta;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// beta](https://en.wikipedia.org/wiki/Noncentral_beta_distribution)
+/// distribution (of type I), the distribution of `X / (X + Y)` for a
+/// noncentral chi-squared variable `X` with `2α` degrees of freedom and
+/// noncentrality `λ` and an independent central chi-squared variable `Y`
+/// with `2β` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralBeta, ContinuousCDF};
+/// use statrs::prec;
+///
+/// let n = NoncentralBeta::new(2.0, 3.0, 4.0).unwrap();
+/// assert!(prec::almost_eq(n.cdf(0.3), 0.116743441475307, 1e-14));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralBeta {
+    shape_a: f64,
+    shape_b: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralBeta {
+    /// Constructs a new noncentral beta distribution with shapeA (α) of
+    /// `shape_a`, shapeB (β) of `shape_b` and noncentrality parameter
+    /// `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape_a` or `shape_b` is not finite and
+    /// positive, or if `noncentrality` is not finite and non-negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let mut result = NoncentralBeta::new(2.0, 2.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralBeta::new(2.0, 2.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(shape_a: f64, shape_b: f64, noncentrality: f64) -> Result<NoncentralBeta> {
+        if !shape_a.is_finite()
+            || shape_a <= 0.0
+            || !shape_b.is_finite()
+            || shape_b <= 0.0
+            || !noncentrality.is_finite()
+            || noncentrality < 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralBeta {
+                shape_a,
+                shape_b,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the shapeA (α) of the noncentral beta distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.shape_a(), 2.0);
+    /// ```
+    pub fn shape_a(&self) -> f64 {
+        self.shape_a
+    }
+
+    /// Returns the shapeB (β) of the noncentral beta distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.shape_b(), 3.0);
+    /// ```
+    pub fn shape_b(&self) -> f64 {
+        self.shape_b
+    }
+
+    /// Returns the noncentrality parameter of the noncentral beta
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralBeta {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = super::noncentral_chi_squared::sample_unchecked(
+            rng,
+            2.0 * self.shape_a,
+            self.noncentrality,
+        );
+        let y = super::gamma::sample_unchecked(rng, self.shape_b, 0.5);
+        x / (x + y)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralBeta {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// beta distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * I_x(α + j, β)
+    /// ```
+    ///
+    /// where `λ` is the noncentrality and `I` is the regularized incomplete
+    /// beta function
+    fn cdf(&self, x: f64) -> f64 {
+        cdf_unchecked(x, self.shape_a, self.shape_b, self.noncentrality)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral beta distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
+}
+
+impl Min<f64> for NoncentralBeta {
+    /// Returns the minimum value in the domain of the noncentral beta
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralBeta {
+    /// Returns the maximum value in the domain of the noncentral beta
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn max(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Distribution<f64> for NoncentralBeta {
+    /// Returns the mean of the noncentral beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * (α + j) / (α + β + j)
+    /// ```
+    ///
+    /// where `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        let (a, b) = (self.shape_a, self.shape_b);
+        Some(poisson_mixture(self.noncentrality / 2.0, |j| {
+            (a + j) / (a + b + j)
+        }))
+    }
+    /// Returns the variance of the noncentral beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * (α + j) (α + j + 1) / ((α + β + j) (α + β + j + 1)) - μ^2
+    /// ```
+    ///
+    /// where `λ` is the noncentrality and `μ` is the mean
+    fn variance(&self) -> Option<f64> {
+        let (a, b) = (self.shape_a, self.shape_b);
+        let second_moment = poisson_mixture(self.noncentrality / 2.0, |j| {
+            (a + j) * (a + j + 1.0) / ((a + b + j) * (a + b + j + 1.0))
+        });
+        let mean = self.mean()?;
+        Some(second_moment - mean * mean)
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralBeta {
+    /// Calculates the probability density function for the noncentral beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * x^(α + j - 1) (1 - x)^(β - 1) / B(α + j, β)
+    /// ```
+    ///
+    /// where `λ` is the noncentrality and `B` is the beta function
+    fn pdf(&self, x: f64) -> f64 {
+        pdf_unchecked(x, self.shape_a, self.shape_b, self.noncentrality)
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// beta distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The mixture is summed in log space around its largest term, so the
+    /// result stays finite near the ends of the domain, where the pdf
+    /// underflows
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Σ_j e^(-λ / 2) (λ / 2)^j / j! * x^(α + j - 1) (1 - x)^(β - 1) / B(α + j, β))
+    /// ```
+    ///
+    /// where `λ` is the noncentrality and `B` is the beta function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x >= 1.0 {
+            self.pdf(x).ln()
+        } else {
+            ln_pdf_unchecked(
+                x.ln(),
+                (-x).ln_1p(),
+                self.shape_a,
+                self.shape_b,
+                self.noncentrality,
+            )
+        }
+    }
+}
+
+/// performs an unchecked cdf calculation for a noncentral beta distribution
+/// with the given shapes and noncentrality at x
+pub fn cdf_unchecked(x: f64, shape_a: f64, shape_b: f64, noncentrality: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x >= 1.0 {
+        1.0
+    } else {
+        poisson_mixture(noncentrality / 2.0, |j| {
+            beta::beta_reg(shape_a + j, shape_b, x)
+        })
+        .min(1.0)
+    }
+}
+
+/// performs an unchecked pdf calculation for a noncentral beta distribution
+/// with the given shapes and noncentrality at x
+pub fn pdf_unchecked(x: f64, shape_a: f64, shape_b: f64, noncentrality: f64) -> f64 {
+    let h = noncentrality / 2.0;
+    if !(0.0..=1.0).contains(&x) {
+        0.0
+    } else if x == 0.0 {
+        // only the first term is nonzero at the lower end
+        if shape_a < 1.0 {
+            f64::INFINITY
+        } else if shape_a > 1.0 {
+            0.0
+        } else {
+            shape_b * (-h).exp()
+        }
+    } else if x == 1.0 {
+        if shape_b < 1.0 {
+            f64::INFINITY
+        } else if shape_b > 1.0 {
+            0.0
+        } else {
+            shape_a + h
+        }
+    } else {
+        poisson_mixture(h, |j| {
+            ((shape_a + j - 1.0) * x.ln() + (shape_b - 1.0) * (-x).ln_1p()
+                - beta::ln_beta(shape_a + j, shape_b))
+            .exp()
+        })
+    }
+}
+
+/// performs an unchecked log pdf calculation for a noncentral beta
+/// distribution with the given shapes and noncentrality at the point `x`
+/// in `(0, 1)` given by `ln_x = ln(x)` and `ln_1m_x = ln(1 - x)`, which
+/// callers can supply without rounding `x` to an end of the domain
+pub fn ln_pdf_unchecked(
+    ln_x: f64,
+    ln_1m_x: f64,
+    shape_a: f64,
+    shape_b: f64,
+    noncentrality: f64,
+) -> f64 {
+    ln_poisson_mixture(noncentrality / 2.0, |j| {
+        (shape_a + j - 1.0) * ln_x + (shape_b - 1.0) * ln_1m_x - beta::ln_beta(shape_a + j, shape_b)
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NoncentralBeta};
+    use crate::distribution::internal::*;
+
+    fn try_create(shape_a: f64, shape_b: f64, noncentrality: f64) -> NoncentralBeta {
+        let n = NoncentralBeta::new(shape_a, shape_b, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(shape_a: f64, shape_b: f64, noncentrality: f64) {
+        let n = NoncentralBeta::new(shape_a, shape_b, noncentrality);
+        assert!(n.is_err());
+    }
+
+    // reference values computed to 30 digits in mpmath
+
+    #[test]
+    fn test_create() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_eq!(n.shape_a(), 2.0);
+        assert_eq!(n.shape_b(), 3.0);
+        assert_eq!(n.noncentrality(), 4.0);
+        try_create(0.5, 0.5, 0.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0, 1.0);
+        bad_create_case(1.0, -1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0);
+        bad_create_case(1.0, f64::NAN, 1.0);
+        bad_create_case(1.0, 1.0, -1.0);
+        bad_create_case(1.0, 1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.mean().unwrap(), 0.55450438728237856, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 0.038472237096051253, 1e-14);
+        // without noncentrality these are the moments of the beta distribution
+        let n = try_create(2.0, 3.0, 0.0);
+        assert_almost_eq!(n.mean().unwrap(), 0.4, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 0.04, 1e-15);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.pdf(0.2), 0.52181689489255454, 1e-14);
+        assert_almost_eq!(n.pdf(0.6), 1.8593160642536371, 1e-13);
+        assert_eq!(n.pdf(0.0), 0.0);
+        assert_eq!(n.pdf(1.0), 0.0);
+        assert_eq!(n.pdf(-0.5), 0.0);
+        assert_almost_eq!(try_create(1.0, 3.0, 4.0).pdf(0.0), 3.0 * (-2f64).exp(), 1e-15);
+        assert_eq!(try_create(2.0, 1.0, 4.0).pdf(1.0), 4.0);
+        assert_eq!(try_create(0.5, 1.0, 4.0).pdf(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.ln_pdf(0.6), 1.8593160642536371f64.ln(), 1e-13);
+        // near the ends of the domain, where the pdf underflows
+        assert_almost_eq!(n.ln_pdf(1e-200), -460.03211194902114, 1e-12);
+        assert_almost_eq!(n.ln_pdf(1.0 - 2f64.powi(-50)), -64.983984715708204, 1e-12);
+        assert_eq!(n.ln_pdf(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.cdf(0.3), 0.11674344147530745, 1e-14);
+        assert_almost_eq!(n.cdf(0.7), 0.74119096938715419, 1e-14);
+        assert_eq!(n.cdf(0.0), 0.0);
+        assert_eq!(n.cdf(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.inverse_cdf(0.25), 0.41595639419947967, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.75), 0.70508493801324715, 1e-13);
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), 1.0);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(2.0, 3.0, 4.0), 0.0, 1.0);
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(2.0, 3.0, 4.0);
+        let mean = (0..10_000).map(|_| n.sample(&mut r)).sum::<f64>() / 10_000.0;
+        // the standard error of the mean is about 0.002
+        assert!((mean - n.mean().unwrap()).abs() < 0.01);
+    }
+}
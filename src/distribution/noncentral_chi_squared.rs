@@ -0,0 +1,460 @@
+use crate::distribution::internal::{ln_poisson_mixture, poisson_mixture};
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// The multiple of `1 + ν^2` above which `√(λx)` is large enough for the
+/// log density to use the asymptotic expansion of the Bessel function
+/// rather than the Poisson mixture
+const ASYMPTOTIC_MIN_Z: f64 = 1e4;
+
+/// Implements the [noncentral
+/// chi-squared](https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution)
+/// distribution, the distribution of the sum of squares of independent
+/// normal variables with unit variance and nonzero means
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralChiSquared, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralChiSquared::new(4.0, 3.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 7.0);
+/// assert!(prec::almost_eq(n.pdf(7.0), 0.0854058041449211, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralChiSquared {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralChiSquared {
+    /// Constructs a new noncentral chi-squared distribution with `freedom`
+    /// degrees of freedom and noncentrality parameter `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` is not finite and positive, or if
+    /// `noncentrality` is not finite and non-negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let mut result = NoncentralChiSquared::new(3.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralChiSquared::new(3.0, -2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralChiSquared> {
+        if !freedom.is_finite()
+            || freedom <= 0.0
+            || !noncentrality.is_finite()
+            || noncentrality < 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralChiSquared {
+                freedom,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the degrees of freedom of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.freedom(), 3.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality parameter of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 2.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralChiSquared {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.freedom, self.noncentrality)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralChiSquared {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * P(k / 2 + j, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and `P`
+    /// is the regularized lower incomplete gamma function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x.is_infinite() {
+            1.0
+        } else {
+            let k = self.freedom / 2.0;
+            poisson_mixture(self.noncentrality / 2.0, |j| {
+                gamma::gamma_lr(k + j, x / 2.0)
+            })
+            .min(1.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral chi-squared distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
+}
+
+impl Min<f64> for NoncentralChiSquared {
+    /// Returns the minimum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralChiSquared {
+    /// Returns the maximum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralChiSquared {
+    /// Returns the mean of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k + λ
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        Some(self.freedom + self.noncentrality)
+    }
+    /// Returns the variance of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 (k + 2λ)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * (self.freedom + 2.0 * self.noncentrality))
+    }
+    /// Returns the skewness of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^(3 / 2) (k + 3λ) / (k + 2λ)^(3 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn skewness(&self) -> Option<f64> {
+        let k = self.freedom;
+        let lambda = self.noncentrality;
+        Some(8f64.sqrt() * (k + 3.0 * lambda) / (k + 2.0 * lambda).powf(1.5))
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralChiSquared {
+    /// Calculates the probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * f_(k + 2j)(x)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f_ν` is the pdf of the central chi-squared distribution with `ν`
+    /// degrees of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        let k = self.freedom / 2.0;
+        let h = self.noncentrality / 2.0;
+        if x < 0.0 || x.is_infinite() {
+            0.0
+        } else if x == 0.0 {
+            // only the central term is nonzero at the origin
+            if k < 1.0 {
+                f64::INFINITY
+            } else if k > 1.0 {
+                0.0
+            } else {
+                0.5 * (-h).exp()
+            }
+        } else {
+            poisson_mixture(h, |j| {
+                0.5 * ((k + j - 1.0) * (x / 2.0).ln() - x / 2.0 - gamma::ln_gamma(k + j)).exp()
+            })
+        }
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The mixture is summed in log space around its largest term, so the
+    /// result stays finite far in the tails, where the pdf underflows. For
+    /// large `√(λx)` the mixture is evaluated in its closed form through the
+    /// asymptotic expansion of the modified Bessel function `I_(k/2 - 1)`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Σ_j e^(-λ / 2) (λ / 2)^j / j! * f_(k + 2j)(x))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f_ν` is the pdf of the central chi-squared distribution with `ν`
+    /// degrees of freedom
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let k = self.freedom / 2.0;
+        if x <= 0.0 || x.is_infinite() {
+            return self.pdf(x).ln();
+        }
+        let order = k - 1.0;
+        let z = (self.noncentrality * x).sqrt();
+        if z > ASYMPTOTIC_MIN_Z * (1.0 + order * order) {
+            // the mixture in closed form, e^(-(x + λ) / 2) (x / λ)^(ν / 2)
+            // I_ν(√(λx)) / 2 with ν one less than half the freedom
+            -0.5 * (x + self.noncentrality)
+                + 0.5 * order * (x.ln() - self.noncentrality.ln())
+                + ln_bessel_i_large(order, z)
+                - f64::consts::LN_2
+        } else {
+            let ln_half_x = (x / 2.0).ln();
+            -0.5 * x - f64::consts::LN_2
+                + ln_poisson_mixture(self.noncentrality / 2.0, |j| {
+                    (k + j - 1.0) * ln_half_x - gamma::ln_gamma(k + j)
+                })
+        }
+    }
+}
+
+/// Computes `ln(I_ν(z))`, the log of the modified Bessel function of the
+/// first kind, from its asymptotic expansion
+/// `e^z / √(2πz) Σ_m (-1)^m Π_i (4ν^2 - (2i - 1)^2) / (m! (8z)^m)`, which
+/// converges to machine precision for `z` well above `ν^2`
+fn ln_bessel_i_large(order: f64, z: f64) -> f64 {
+    let mu = 4.0 * order * order;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for m in 1..30 {
+        let m = f64::from(m);
+        term *= -(mu - (2.0 * m - 1.0).powi(2)) / (8.0 * m * z);
+        sum += term;
+        if term.abs() < f64::EPSILON * sum {
+            break;
+        }
+    }
+    z - 0.5 * (2.0 * f64::consts::PI * z).ln() + sum.ln()
+}
+
+/// draws a sample from a noncentral chi-squared distribution as a central
+/// chi-squared variable whose degrees of freedom are increased by twice a
+/// Poisson variable with mean `noncentrality / 2`
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, freedom: f64, noncentrality: f64) -> f64 {
+    let j = if noncentrality > 0.0 {
+        super::poisson::sample_unchecked(rng, noncentrality / 2.0)
+    } else {
+        0.0
+    };
+    super::gamma::sample_unchecked(rng, freedom / 2.0 + j, 0.5)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NoncentralChiSquared};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralChiSquared {
+        let n = NoncentralChiSquared::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(freedom: f64, noncentrality: f64) {
+        let n = NoncentralChiSquared::new(freedom, noncentrality);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(freedom: f64, noncentrality: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NoncentralChiSquared) -> f64
+    {
+        let x = eval(try_create(freedom, noncentrality));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    // reference values computed to 30 digits in mpmath
+
+    #[test]
+    fn test_create() {
+        let n = try_create(3.0, 2.0);
+        assert_eq!(n.freedom(), 3.0);
+        assert_eq!(n.noncentrality(), 2.0);
+        try_create(0.5, 0.0);
+        try_create(100.0, 1000.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(4.0, 3.0);
+        assert_eq!(n.mean().unwrap(), 7.0);
+        assert_eq!(n.variance().unwrap(), 20.0);
+        assert_almost_eq!(n.skewness().unwrap(), 8f64.sqrt() * 13.0 / 10f64.powf(1.5), 1e-15);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |x: f64| move |n: NoncentralChiSquared| n.pdf(x);
+        test_almost(4.0, 3.0, 0.026057227178267314, 1e-15, pdf(0.5));
+        test_almost(4.0, 3.0, 0.085405804144921103, 1e-15, pdf(7.0));
+        test_almost(4.0, 3.0, 0.004115009563369725, 1e-15, pdf(20.0));
+        test_almost(1.0, 10.0, 0.028094956442914781, 1e-15, pdf(0.01));
+        test_almost(2.0, 1.0, 0.17472016746112834, 1e-15, pdf(2.0));
+        test_almost(2.0, 1.0, 0.5 * (-0.5f64).exp(), 1e-15, pdf(0.0));
+        assert_eq!(try_create(4.0, 3.0).pdf(0.0), 0.0);
+        assert_eq!(try_create(1.0, 3.0).pdf(0.0), f64::INFINITY);
+        assert_eq!(try_create(4.0, 3.0).pdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |x: f64| move |n: NoncentralChiSquared| n.ln_pdf(x);
+        test_almost(4.0, 3.0, 0.085405804144921103f64.ln(), 1e-14, ln_pdf(7.0));
+        // far in the tails, where the pdf underflows
+        test_almost(3.0, 5.0, -904.91680466998167, 1e-12, ln_pdf(2000.0));
+        test_almost(3.0, 5.0, -348.80670248231153, 1e-12, ln_pdf(1e-300));
+        test_almost(3.0, 4.0, -249999955278644.75, 0.1, ln_pdf(5e14));
+        test_almost(3.0, 4.0, -499999936754451.10, 0.1, ln_pdf(1e15));
+        test_almost(3.0, 4.0, -5e299, 1e284, ln_pdf(1e300));
+        // the log density keeps decreasing where the mixture gives way to
+        // the asymptotic Bessel form
+        let n = try_create(3.0, 4.0);
+        let xs = [1e6, 1e7, 3.8e7, 3.95e7, 1e8, 1e15, 2f64.powi(63), 1e30, 1e300];
+        for pair in xs.windows(2) {
+            assert!(n.ln_pdf(pair[1]) < n.ln_pdf(pair[0]));
+        }
+        assert_eq!(try_create(4.0, 3.0).ln_pdf(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |x: f64| move |n: NoncentralChiSquared| n.cdf(x);
+        test_almost(4.0, 3.0, 0.091091621189890606, 1e-14, cdf(2.0));
+        test_almost(4.0, 3.0, 0.57861919063129345, 1e-14, cdf(7.0));
+        test_almost(4.0, 3.0, 0.98658970272264798, 1e-14, cdf(20.0));
+        test_almost(1.0, 10.0, 0.015282635860871632, 1e-14, cdf(1.0));
+        test_almost(1.0, 10.0, 0.98969210865646914, 1e-14, cdf(30.0));
+        test_almost(4.0, 10.0, 1.0 - 0.18168808048289899, 1e-12, cdf(20.0));
+        test_almost(5.0, 250.0, 1.0 - 0.08253251154809704, 1e-11, cdf(300.0));
+        // without noncentrality this is the central chi-squared distribution
+        test_almost(3.0, 0.0, 0.6083748237289110, 1e-14, cdf(3.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |x: f64| move |n: NoncentralChiSquared| n.inverse_cdf(x);
+        test_almost(4.0, 3.0, 1.4355326823767199, 1e-12, inverse_cdf(0.05));
+        test_almost(4.0, 3.0, 6.1267615219203749, 1e-12, inverse_cdf(0.5));
+        test_almost(4.0, 3.0, 20.949909803329611, 1e-11, inverse_cdf(0.99));
+        assert_eq!(try_create(4.0, 3.0).inverse_cdf(0.0), 0.0);
+        assert_eq!(try_create(4.0, 3.0).inverse_cdf(1.0), f64::INFINITY);
+        assert!(try_create(4.0, 3.0).checked_inverse_cdf(-0.1).is_err());
+        assert!(try_create(4.0, 3.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(4.0, 3.0), 0.0, 40.0);
+        tests::check_continuous_distribution(&try_create(10.0, 20.0), 0.0, 100.0);
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(4.0, 3.0);
+        let mean = (0..10_000).map(|_| n.sample(&mut r)).sum::<f64>() / 10_000.0;
+        // the standard error of the mean is sqrt(20 / 10000) ≈ 0.045
+        assert!((mean - 7.0).abs() < 0.2);
+    }
+}
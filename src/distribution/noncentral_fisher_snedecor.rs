@@ -0,0 +1,437 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// F-distribution](https://en.wikipedia.org/wiki/Noncentral_F-distribution),
+/// the distribution of `(X / d1) / (Y / d2)` for a noncentral chi-squared
+/// variable `X` with `d1` degrees of freedom and noncentrality `λ` and an
+/// independent central chi-squared variable `Y` with `d2` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralFisherSnedecor, ContinuousCDF};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralFisherSnedecor::new(3.0, 10.0, 2.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 25.0 / 12.0, 1e-15));
+/// assert!(prec::almost_eq(n.cdf(1.0), 0.347510865721186, 1e-13));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralFisherSnedecor {
+    freedom_1: f64,
+    freedom_2: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralFisherSnedecor {
+    /// Constructs a new noncentral fisher-snedecor distribution with degrees
+    /// of freedom `freedom_1` and `freedom_2` and noncentrality parameter
+    /// `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom_1` or `freedom_2` is not finite and
+    /// positive, or if `noncentrality` is not finite and non-negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let mut result = NoncentralFisherSnedecor::new(3.0, 10.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralFisherSnedecor::new(0.0, 10.0, 2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        freedom_1: f64,
+        freedom_2: f64,
+        noncentrality: f64,
+    ) -> Result<NoncentralFisherSnedecor> {
+        if !freedom_1.is_finite()
+            || freedom_1 <= 0.0
+            || !freedom_2.is_finite()
+            || freedom_2 <= 0.0
+            || !noncentrality.is_finite()
+            || noncentrality < 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralFisherSnedecor {
+                freedom_1,
+                freedom_2,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the first degree of freedom for the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.freedom_1(), 2.0);
+    /// ```
+    pub fn freedom_1(&self) -> f64 {
+        self.freedom_1
+    }
+
+    /// Returns the second degree of freedom for the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.freedom_2(), 3.0);
+    /// ```
+    pub fn freedom_2(&self) -> f64 {
+        self.freedom_2
+    }
+
+    /// Returns the noncentrality parameter of the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(2.0, 3.0, 1.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Maps `x` to the corresponding noncentral beta variable
+    /// `d1 x / (d1 x + d2)`
+    fn beta_variable(&self, x: f64) -> f64 {
+        self.freedom_1 * x / (self.freedom_1 * x + self.freedom_2)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralFisherSnedecor {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        (super::noncentral_chi_squared::sample_unchecked(rng, self.freedom_1, self.noncentrality)
+            * self.freedom_2)
+            / (super::gamma::sample_unchecked(rng, self.freedom_2 / 2.0, 0.5) * self.freedom_1)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralFisherSnedecor {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ_j e^(-λ / 2) (λ / 2)^j / j! * I_((d1 * x) / (d1 * x + d2))(d1 / 2 + j, d2 / 2)
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom, `λ` is the noncentrality and `I` is the regularized
+    /// incomplete beta function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x.is_infinite() {
+            1.0
+        } else {
+            super::noncentral_beta::cdf_unchecked(
+                self.beta_variable(x),
+                self.freedom_1 / 2.0,
+                self.freedom_2 / 2.0,
+                self.noncentrality,
+            )
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral fisher-snedecor distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
+}
+
+impl Min<f64> for NoncentralFisherSnedecor {
+    /// Returns the minimum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralFisherSnedecor {
+    /// Returns the maximum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralFisherSnedecor {
+    /// Returns the mean of the noncentral fisher-snedecor distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom_2 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d2 (d1 + λ) / (d1 (d2 - 2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        if self.freedom_2 <= 2.0 {
+            None
+        } else {
+            let (d1, d2) = (self.freedom_1, self.freedom_2);
+            Some(d2 * (d1 + self.noncentrality) / (d1 * (d2 - 2.0)))
+        }
+    }
+    /// Returns the variance of the noncentral fisher-snedecor distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom_2 <= 4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 (d2 / d1)^2 ((d1 + λ)^2 + (d1 + 2λ) (d2 - 2)) / ((d2 - 2)^2 (d2 - 4))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        if self.freedom_2 <= 4.0 {
+            None
+        } else {
+            let (d1, d2) = (self.freedom_1, self.freedom_2);
+            let lambda = self.noncentrality;
+            let ratio = d2 / d1;
+            Some(
+                2.0 * ratio
+                    * ratio
+                    * ((d1 + lambda) * (d1 + lambda) + (d1 + 2.0 * lambda) * (d2 - 2.0))
+                    / ((d2 - 2.0) * (d2 - 2.0) * (d2 - 4.0)),
+            )
+        }
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralFisherSnedecor {
+    /// Calculates the probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d1 d2 / (d1 x + d2)^2 * g((d1 * x) / (d1 * x + d2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `g` is the pdf of the noncentral beta distribution
+    /// with shapes `d1 / 2` and `d2 / 2` and the same noncentrality
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 || x.is_infinite() {
+            0.0
+        } else {
+            let (d1, d2) = (self.freedom_1, self.freedom_2);
+            let denom = d1 * x + d2;
+            d1 * d2 / (denom * denom)
+                * super::noncentral_beta::pdf_unchecked(
+                    self.beta_variable(x),
+                    d1 / 2.0,
+                    d2 / 2.0,
+                    self.noncentrality,
+                )
+        }
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The log pdf of the noncentral beta distribution is evaluated at the
+    /// logs of `d1 x / (d1 x + d2)` and `d2 / (d1 x + d2)`, which are
+    /// computed directly so that large `x` are not rounded to the end of its
+    /// domain
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(d1 d2 / (d1 x + d2)^2 * g((d1 * x) / (d1 * x + d2)))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `g` is the pdf of the noncentral beta distribution
+    /// with shapes `d1 / 2` and `d2 / 2` and the same noncentrality
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x.is_infinite() {
+            self.pdf(x).ln()
+        } else {
+            let (d1, d2) = (self.freedom_1, self.freedom_2);
+            let ln_d1_x = d1.ln() + x.ln();
+            let ln_d2 = d2.ln();
+            // ln(d1 x + d2), without overflowing for large x
+            let ln_denom = ln_d1_x.max(ln_d2) + (-(ln_d1_x - ln_d2).abs()).exp().ln_1p();
+            d1.ln() + ln_d2 - 2.0 * ln_denom
+                + super::noncentral_beta::ln_pdf_unchecked(
+                    ln_d1_x - ln_denom,
+                    ln_d2 - ln_denom,
+                    d1 / 2.0,
+                    d2 / 2.0,
+                    self.noncentrality,
+                )
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NoncentralFisherSnedecor};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom_1: f64, freedom_2: f64, noncentrality: f64) -> NoncentralFisherSnedecor {
+        let n = NoncentralFisherSnedecor::new(freedom_1, freedom_2, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(freedom_1: f64, freedom_2: f64, noncentrality: f64) {
+        let n = NoncentralFisherSnedecor::new(freedom_1, freedom_2, noncentrality);
+        assert!(n.is_err());
+    }
+
+    // reference values computed to 30 digits in mpmath
+
+    #[test]
+    fn test_create() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_eq!(n.freedom_1(), 3.0);
+        assert_eq!(n.freedom_2(), 10.0);
+        assert_eq!(n.noncentrality(), 2.0);
+        try_create(0.5, 0.5, 0.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0, 1.0);
+        bad_create_case(1.0, -1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0);
+        bad_create_case(1.0, f64::NAN, 1.0);
+        bad_create_case(1.0, 1.0, -1.0);
+        bad_create_case(1.0, 1.0, f64::NAN);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_almost_eq!(n.mean().unwrap(), 25.0 / 12.0, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 4.6875, 1e-14);
+        assert!(try_create(3.0, 2.0, 2.0).mean().is_none());
+        assert!(try_create(3.0, 4.0, 2.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_almost_eq!(n.pdf(0.5), 0.39378206392788663, 1e-13);
+        assert_almost_eq!(n.pdf(2.0), 0.21156750179827033, 1e-13);
+        assert_eq!(n.pdf(0.0), 0.0);
+        assert_eq!(n.pdf(-1.0), 0.0);
+        assert_eq!(try_create(1.0, 10.0, 2.0).pdf(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_almost_eq!(n.ln_pdf(2.0), 0.21156750179827033f64.ln(), 1e-13);
+        // far in the tails, where the pdf underflows
+        let n = try_create(3.0, 5.0, 2.0);
+        assert_almost_eq!(n.ln_pdf(1e200), -1607.7446687069465, 1e-11);
+        assert_almost_eq!(n.ln_pdf(1e-200), -230.39688889866317, 1e-12);
+        assert_eq!(n.ln_pdf(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_almost_eq!(n.cdf(1.0), 0.347510865721186, 1e-13);
+        assert_almost_eq!(n.cdf(4.0), 0.87547614340749103, 1e-13);
+        assert_eq!(n.cdf(0.0), 0.0);
+        assert_eq!(n.cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3.0, 10.0, 2.0);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.4706131923604282, 1e-11);
+        assert_almost_eq!(n.cdf(n.inverse_cdf(0.95)), 0.95, 1e-13);
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(3.0, 10.0, 2.0), 0.0, 100.0);
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(3.0, 10.0, 2.0);
+        let mean = (0..10_000).map(|_| n.sample(&mut r)).sum::<f64>() / 10_000.0;
+        // the standard error of the mean is about 0.02
+        assert!((mean - n.mean().unwrap()).abs() < 0.1);
+    }
+}
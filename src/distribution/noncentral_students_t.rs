@@ -0,0 +1,539 @@
+use crate::distribution::{Continuous, ContinuousCDF, StudentsT};
+use crate::function::{beta, gamma};
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// t-distribution](https://en.wikipedia.org/wiki/Noncentral_t-distribution),
+/// the distribution of `(Z + δ) / sqrt(V / ν)` for a standard normal `Z`
+/// and an independent chi-squared variable `V` with `ν` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralStudentsT, ContinuousCDF};
+/// use statrs::prec;
+///
+/// let n = NoncentralStudentsT::new(10.0, 1.5).unwrap();
+/// assert!(prec::almost_eq(n.cdf(1.5), 0.485509153252857, 1e-10));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralStudentsT {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralStudentsT {
+    /// Constructs a new noncentral t-distribution with `freedom` degrees of
+    /// freedom and noncentrality parameter `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` is `NaN` or not positive, or if
+    /// `noncentrality` is not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let mut result = NoncentralStudentsT::new(5.0, -1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralStudentsT::new(0.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralStudentsT> {
+        if freedom.is_nan() || freedom <= 0.0 || !noncentrality.is_finite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralStudentsT {
+                freedom,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the degrees of freedom of the noncentral t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let n = NoncentralStudentsT::new(5.0, -1.0).unwrap();
+    /// assert_eq!(n.freedom(), 5.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality parameter of the noncentral t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let n = NoncentralStudentsT::new(5.0, -1.0).unwrap();
+    /// assert_eq!(n.noncentrality(), -1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralStudentsT {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = super::normal::sample_unchecked(rng, self.noncentrality, 1.0);
+        if self.freedom.is_infinite() {
+            z
+        } else {
+            let v = super::gamma::sample_unchecked(rng, self.freedom / 2.0, 0.5);
+            z / (v / self.freedom).sqrt()
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralStudentsT {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// t-distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The lower tail for positive noncentrality uses the series of Lenth
+    /// (1989). Elsewhere the series would cancel, so the tail on the side of
+    /// `x` is instead integrated numerically as the expectation below, which
+    /// keeps both tails accurate relative to their size. A normal
+    /// approximation is used when the noncentrality exceeds about 37.6 or
+    /// the degrees of freedom exceed `4e5`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(-δ) + Σ_j (p_j I_y(j + 1/2, ν / 2) + q_j I_y(j + 1, ν / 2)) / 2
+    /// 1 - E[Q(x U - δ)]
+    /// ```
+    ///
+    /// where `y = x^2 / (x^2 + ν)`, `p_j = e^(-δ^2 / 2) (δ^2 / 2)^j / j!`,
+    /// `q_j = δ e^(-δ^2 / 2) (δ^2 / 2)^j / (sqrt(2) Γ(j + 3/2))`, `Φ` and
+    /// `Q` are the standard normal cdf and survival function, `I` is the
+    /// regularized incomplete beta function and `U = sqrt(V / ν)` for a
+    /// chi-squared variable `V` with `ν` degrees of freedom. Negative `x`
+    /// use `F(x; ν, δ) = E[Q(-x U + δ)]`.
+    fn cdf(&self, x: f64) -> f64 {
+        cdf_unchecked(x, self.freedom, self.noncentrality)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral t-distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if the iteration fails to converge
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically with Newton steps on the pdf,
+    /// safeguarded by bisection
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        super::internal::inverse_cdf_newton(self, x)
+    }
+}
+
+impl Min<f64> for NoncentralStudentsT {
+    /// Returns the minimum value in the domain of the noncentral
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NoncentralStudentsT {
+    /// Returns the maximum value in the domain of the noncentral
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralStudentsT {
+    /// Returns the mean of the noncentral t-distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// δ sqrt(ν / 2) Γ((ν - 1) / 2) / Γ(ν / 2)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom and `δ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        if self.freedom <= 1.0 {
+            None
+        } else if self.freedom.is_infinite() {
+            Some(self.noncentrality)
+        } else {
+            let v = self.freedom;
+            Some(
+                self.noncentrality
+                    * (v / 2.0).sqrt()
+                    * (gamma::ln_gamma((v - 1.0) / 2.0) - gamma::ln_gamma(v / 2.0)).exp(),
+            )
+        }
+    }
+    /// Returns the variance of the noncentral t-distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ν (1 + δ^2) / (ν - 2) - μ^2
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `δ` is the noncentrality and `μ`
+    /// is the mean
+    fn variance(&self) -> Option<f64> {
+        if self.freedom <= 2.0 {
+            None
+        } else if self.freedom.is_infinite() {
+            Some(1.0)
+        } else {
+            let v = self.freedom;
+            let mean = self.mean()?;
+            Some(v * (1.0 + self.noncentrality * self.noncentrality) / (v - 2.0) - mean * mean)
+        }
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralStudentsT {
+    /// Calculates the probability density function for the noncentral
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// f_ν(x) e^(-δ^2 / 2) Σ_k c^k Γ((ν + k + 1) / 2) / (Γ((ν + 1) / 2) k!)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `δ` is the noncentrality, `f_ν`
+    /// is the pdf of the central t-distribution and
+    /// `c = x δ sqrt(2 / (x^2 + ν))`
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// t-distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The series is summed in log space around its largest term. Its terms
+    /// alternate in sign when `x` and `δ` have opposite signs, so there the
+    /// pdf is instead integrated numerically as `E[U φ(x U - δ)]` over
+    /// `U = sqrt(V / ν)` for a chi-squared variable `V` with `ν` degrees of
+    /// freedom, where `φ` is the standard normal pdf. Both stay finite far in
+    /// the tails, where the pdf underflows.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f_ν(x)) - δ^2 / 2 + ln(Σ_k c^k Γ((ν + k + 1) / 2) / (Γ((ν + 1) / 2) k!))
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `δ` is the noncentrality, `f_ν`
+    /// is the pdf of the central t-distribution and
+    /// `c = x δ sqrt(2 / (x^2 + ν))`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let v = self.freedom;
+        let delta = self.noncentrality;
+        if x.is_infinite() {
+            f64::NEG_INFINITY
+        } else if v.is_infinite() || v > 1e8 {
+            super::normal::ln_pdf_unchecked(x, delta, 1.0)
+        } else if x * delta < 0.0 {
+            // by symmetry, take x > 0 and δ < 0
+            let (x, delta) = (x.abs(), -delta.abs());
+            let guess = if x > 1.0 { -x.ln() } else { 0.0 };
+            super::internal::ln_integral_log_concave(
+                |r| {
+                    let z = x * r.exp() - delta;
+                    ln_pdf_ln_scale(r, v) + r - 0.5 * z * z
+                },
+                guess,
+            )
+            .map_or(f64::NAN, |integral| integral - consts::LN_SQRT_2PI)
+        } else {
+            let ratio = x / v.sqrt();
+            // ln(1 + x^2 / ν), without overflowing for large x
+            let ln_1p_ratio2 = if ratio.abs() < 1e150 {
+                (ratio * ratio).ln_1p()
+            } else {
+                2.0 * ratio.abs().ln()
+            };
+            let ln_central = gamma::ln_gamma((v + 1.0) / 2.0)
+                - gamma::ln_gamma(v / 2.0)
+                - 0.5 * (f64::consts::PI * v).ln()
+                - 0.5 * (v + 1.0) * ln_1p_ratio2;
+            let c = f64::consts::SQRT_2 * (delta * ratio / ratio.hypot(1.0)).abs();
+            let ln_series = if c == 0.0 {
+                0.0
+            } else {
+                let ln_c = c.ln();
+                let ln_gamma_0 = gamma::ln_gamma((v + 1.0) / 2.0);
+                super::internal::ln_sum_log_concave(|k| {
+                    k * ln_c + gamma::ln_gamma((v + k + 1.0) / 2.0)
+                        - ln_gamma_0
+                        - gamma::ln_gamma(k + 1.0)
+                })
+            };
+            ln_central - 0.5 * delta * delta + ln_series
+        }
+    }
+}
+
+/// performs an unchecked cdf calculation for a noncentral t-distribution
+/// with the given degrees of freedom and noncentrality at x
+pub fn cdf_unchecked(x: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if noncentrality == 0.0 {
+        return StudentsT::new(0.0, 1.0, freedom).unwrap().cdf(x);
+    }
+    if x.is_infinite() {
+        return if x < 0.0 { 0.0 } else { 1.0 };
+    }
+
+    // beyond this point the terms of the series underflow, so use the normal
+    // approximation of Abramowitz and Stegun 26.7.10
+    if freedom > 4e5 || noncentrality * noncentrality > 2.0 * f64::consts::LN_2 * 1021.0 {
+        let s = 1.0 / (4.0 * freedom);
+        return super::normal::cdf_unchecked(
+            x * (1.0 - s),
+            noncentrality,
+            1f64.hypot(x * (2.0 * s).sqrt()),
+        );
+    }
+
+    // -T follows the noncentral t-distribution with noncentrality -δ
+    if x < 0.0 {
+        return ln_sf_unchecked(-x, freedom, -noncentrality).exp().min(1.0);
+    }
+    if noncentrality > 0.0 {
+        let lower = lower_series(x, freedom, noncentrality);
+        if lower <= 0.5 {
+            return lower;
+        }
+    }
+    (-ln_sf_unchecked(x, freedom, noncentrality).exp_m1()).max(0.0)
+}
+
+/// Computes the lower tail `F(t; ν, δ)` for `t >= 0` and `δ > 0` with the
+/// series of Lenth (1989), whose terms are then all positive. The series is
+/// summed in log space around its largest term, with each incomplete beta
+/// function evaluated directly rather than by recurrence, so that the result
+/// stays accurate relative to its size far in the tail.
+fn lower_series(t: f64, freedom: f64, delta: f64) -> f64 {
+    let normal = super::normal::cdf_unchecked(-delta, 0.0, 1.0);
+    let r = t / t.hypot(freedom.sqrt());
+    let y = r * r;
+    if y == 0.0 {
+        return normal;
+    }
+    // the terms p_j and q_j interleave as k = 2j and k = 2j + 1
+    let h = delta * delta / 2.0;
+    let ln_c = (delta / f64::consts::SQRT_2).ln();
+    let ln_series = super::internal::ln_sum_log_concave(|k| {
+        -h + k * ln_c - gamma::ln_gamma(k / 2.0 + 1.0)
+            + beta::beta_reg((k + 1.0) / 2.0, freedom / 2.0, y).ln()
+    });
+    (normal + 0.5 * ln_series.exp()).min(1.0)
+}
+
+/// Computes `ln(P(T > t))` for `t >= 0` as the expectation `E[Q(t U - δ)]`,
+/// where `Q` is the standard normal survival function and `U = sqrt(V / ν)`
+/// for a chi-squared variable `V` with `ν` degrees of freedom. It is
+/// integrated numerically over `ln(U)`, in which the integrand is
+/// log-concave, so that the result is accurate far in the tail, where the
+/// series alternates in sign for `δ < 0` and its complement cancels.
+fn ln_sf_unchecked(t: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if t == 0.0 {
+        return super::internal::ln_standard_normal_cdf(noncentrality);
+    }
+    let guess = if t > 1.0 { -t.ln() } else { 0.0 };
+    super::internal::ln_integral_log_concave(
+        |r| {
+            ln_pdf_ln_scale(r, freedom)
+                + super::internal::ln_standard_normal_cdf(noncentrality - t * r.exp())
+        },
+        guess,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+/// Returns the log density at `r` of `ln(U)`, where `U = sqrt(V / ν)` for a
+/// chi-squared variable `V` with `ν` degrees of freedom
+fn ln_pdf_ln_scale(r: f64, freedom: f64) -> f64 {
+    let a = freedom / 2.0;
+    f64::consts::LN_2 + a * a.ln() - gamma::ln_gamma(a) + freedom * r - a * (2.0 * r).exp()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NoncentralStudentsT};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralStudentsT {
+        let n = NoncentralStudentsT::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(freedom: f64, noncentrality: f64) {
+        let n = NoncentralStudentsT::new(freedom, noncentrality);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(freedom: f64, noncentrality: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NoncentralStudentsT) -> f64
+    {
+        let x = eval(try_create(freedom, noncentrality));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    // reference values computed to 30 digits by numerical integration in
+    // mpmath; the cdf is limited by the accuracy of erfc in its normal term
+
+    #[test]
+    fn test_create() {
+        let n = try_create(5.0, -1.0);
+        assert_eq!(n.freedom(), 5.0);
+        assert_eq!(n.noncentrality(), -1.0);
+        try_create(0.5, 0.0);
+        try_create(f64::INFINITY, 2.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(10.0, 1.5);
+        assert_almost_eq!(n.mean().unwrap(), 1.6255834619087155, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 1.4199784083688759, 1e-13);
+        assert!(try_create(1.0, 1.5).mean().is_none());
+        assert!(try_create(2.0, 1.5).variance().is_none());
+        assert_eq!(try_create(f64::INFINITY, 1.5).mean().unwrap(), 1.5);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |x: f64| move |n: NoncentralStudentsT| n.pdf(x);
+        test_almost(10.0, 1.5, 0.12632499692439211, 1e-14, pdf(0.0));
+        test_almost(10.0, 1.5, 0.36915985358985717, 1e-11, pdf(1.5));
+        test_almost(10.0, 1.5, 0.052287609361936925, 1e-11, pdf(-0.5));
+        test_almost(3.0, -2.0, 0.28778335780668807, 1e-11, pdf(-2.0));
+        assert_eq!(try_create(3.0, 1.0).pdf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |x: f64| move |n: NoncentralStudentsT| n.ln_pdf(x);
+        let n = try_create(10.0, 1.5);
+        assert_almost_eq!(n.ln_pdf(1.0), n.pdf(1.0).ln(), 1e-14);
+        // far in the tails, where the pdf underflows
+        test_almost(1.0, 2.0, -1386.5456306729893, 1e-10, ln_pdf(-1e300));
+        test_almost(0.5, -30.0, -1035.4042811345626, 1e-10, ln_pdf(-1e300));
+        test_almost(10.0, 1.5, -247.04569699864186, 1e-10, ln_pdf(-1e10));
+        test_almost(3.0, -2.0, -924.82516016442839, 1e-10, ln_pdf(1e100));
+        test_almost(10.0, 10.0, -48.818928799865418, 1e-10, ln_pdf(0.2));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |x: f64| move |n: NoncentralStudentsT| n.cdf(x);
+        test_almost(10.0, 1.5, 0.0077790953543366217, 1e-10, cdf(-1.0));
+        test_almost(10.0, 1.5, 0.48550915325285696, 1e-10, cdf(1.5));
+        test_almost(10.0, 1.5, 0.96621430374802822, 1e-10, cdf(4.0));
+        test_almost(3.0, -2.0, 0.97724986805182079, 1e-10, cdf(0.0));
+        test_almost(3.0, -2.0, 0.11094793256172279, 1e-10, cdf(-5.0));
+        test_almost(1.5, 10.0, 0.05, 1e-10, |n| n.cdf(n.inverse_cdf(0.05)));
+        test_almost(10.0, 2.0, 0.3047854473760423, 1e-10, cdf(1.5));
+        test_almost(5.0, 0.5, 0.08244409105672346, 1e-10, cdf(-1.0));
+        test_almost(4.0, -1.0, 0.9978199145162535, 1e-10, cdf(3.0));
+        test_almost(4.0, 1.0, 0.15865525393145707, 1e-10, cdf(0.0));
+        // normal approximation for large noncentrality
+        test_almost(30.0, 45.0, 0.7557113545570090, 1e-2, cdf(50.0));
+        // without noncentrality this is the central t-distribution
+        test_almost(4.0, 0.0, 0.91782252936497493, 1e-12, cdf(1.7));
+        // far in the tails, relative to the reference
+        let rel_cdf = |x: f64, expected: f64| move |n: NoncentralStudentsT| n.cdf(x) / expected;
+        test_almost(1.0, 2.0, 1.0, 1e-10, rel_cdf(-1e300, 6.7746005283368549e-303));
+        test_almost(0.5, -30.0, 1.0, 1e-10, rel_cdf(-1e300, 4.2723237942418466e-150));
+        test_almost(10.0, 1.5, 1.0, 1e-10, rel_cdf(-1e10, 5.1217339545447651e-99));
+        test_almost(10.0, 10.0, 1.0, 1e-10, rel_cdf(0.2, 5.9106186063631191e-23));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |x: f64| move |n: NoncentralStudentsT| n.inverse_cdf(x);
+        test_almost(10.0, 1.5, 0.22238510472376294, 1e-9, inverse_cdf(0.1));
+        test_almost(10.0, 1.5, 3.128736121253221, 1e-9, inverse_cdf(0.9));
+        assert_eq!(try_create(10.0, 1.5).inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(try_create(10.0, 1.5).inverse_cdf(1.0), f64::INFINITY);
+        // quantiles far in the tails are finite and round trip
+        let n = try_create(10.0, 1.5);
+        let x = n.inverse_cdf(1.0 - 1e-12);
+        assert!(x.is_finite());
+        assert_almost_eq!(n.cdf(x), 1.0 - 1e-12, 1e-15);
+        // the lower tail of the df = 1 cdf is 6.7746005283368549e-3 / -x
+        test_almost(1.0, 2.0, 1.0, 1e-9, |n| n.inverse_cdf(1e-300) / -6.7746005283368549e297);
+        assert!(n.checked_inverse_cdf(-0.1).is_err());
+        assert!(n.checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(10.0, 1.5), -4.0, 12.0);
+        tests::check_continuous_distribution(&try_create(5.0, -3.0), -40.0, 5.0);
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        let n = try_create(10.0, 1.5);
+        let mean = (0..10_000).map(|_| n.sample(&mut r)).sum::<f64>() / 10_000.0;
+        // the standard error of the mean is about 0.012
+        assert!((mean - n.mean().unwrap()).abs() < 0.05);
+    }
+}
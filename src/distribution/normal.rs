@@ -77,7 +77,7 @@ impl ContinuousCDF<f64, f64> for Normal {
     /// Calculates the inverse cumulative distribution function for the
     /// normal distribution at `x`
     ///
-    /// # Panics
+    /// # Errors
     ///
     /// If `x < 0.0` or `x > 1.0`
     ///
@@ -89,11 +89,11 @@ impl ContinuousCDF<f64, f64> for Normal {
     ///
     /// where `μ` is the mean, `σ` is the standard deviation and `erfc_inv` is
     /// the inverse of the complementary error function
-    fn inverse_cdf(&self, x: f64) -> f64 {
-        if x < 0.0 || x > 1.0 {
-            panic!("x must be in [0, 1]");
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&x) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
         } else {
-            self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
+            Ok(self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x)))
         }
     }
 }
@@ -463,5 +463,8 @@ mod tests {
         test_almost(5.0, 2.0, 6.0, 1e-14, inverse_cdf(0.69146246127401310363770461060833773988360217555457859));
         test_almost(5.0, 2.0, 10.0, 1e-14, inverse_cdf(0.9937903346742238648330218954258077788721022530769078));
         test_case(5.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        let n = try_create(5.0, 2.0);
+        assert_eq!(n.checked_inverse_cdf(0.3).unwrap(), n.inverse_cdf(0.3));
+        assert!(n.checked_inverse_cdf(1.5).is_err());
     }
 }
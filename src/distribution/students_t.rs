@@ -1,5 +1,5 @@
 use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::{beta, gamma};
+use crate::function::{beta, erf, gamma};
 use crate::is_zero;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -153,17 +153,25 @@ impl ContinuousCDF<f64, f64> for StudentsT {
 
     /// Calculates the inverse cumulative distribution function for the
     /// student's t-distribution at `x`
-    fn inverse_cdf(&self, x: f64) -> f64 {
-        assert!(x >= 0.0 && x <= 1.0);
-        let x = 2. * x.min(1. - x);
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&x) {
+            return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        }
+        if self.freedom.is_infinite() {
+            return Ok(self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x));
+        }
         let a = 0.5 * self.freedom;
         let b = 0.5;
-        let y = beta::inv_beta_reg(a, b, x);
+        let y = beta::inv_beta_reg(a, b, 2. * x.min(1. - x));
         let y = (self.freedom * (1. - y) / y).sqrt();
-        if x <= 0.5 {
-            y
+        if x < 0.5 {
+            Ok(self.location - self.scale * y)
         } else {
-            -y
+            Ok(self.location + self.scale * y)
         }
     }
 }
@@ -1056,4 +1064,25 @@ mod tests {
         test(0.999, 120.0, 3.160);
         test(0.9995, 120.0, 3.373);
     }
+
+    #[test]
+    fn test_inv_cdf_lower_tail_and_location_scale() {
+        let d = try_create(0.0, 1.0, 5.0);
+        for &x in &[0.001, 0.1, 0.3, 0.5, 0.6, 0.7, 0.9, 0.999] {
+            assert_almost_eq!(d.cdf(d.inverse_cdf(x)), x, 1e-12);
+        }
+        assert_almost_eq!(d.inverse_cdf(0.1), -1.475884048824481, 1e-12);
+        let d = try_create(2.0, 3.0, 5.0);
+        assert_almost_eq!(d.inverse_cdf(0.1), 2.0 - 3.0 * 1.475884048824481, 1e-12);
+        assert_almost_eq!(d.cdf(d.inverse_cdf(0.7)), 0.7, 1e-12);
+        let d = try_create(1.0, 2.0, f64::INFINITY);
+        assert_almost_eq!(d.inverse_cdf(0.975), 1.0 + 2.0 * 1.959963984540054, 1e-12);
+    }
+
+    #[test]
+    fn test_checked_inv_cdf() {
+        let d = try_create(2.0, 3.0, 5.0);
+        assert_eq!(d.checked_inverse_cdf(0.7).unwrap(), d.inverse_cdf(0.7));
+        assert!(d.checked_inverse_cdf(1.5).is_err());
+    }
 }
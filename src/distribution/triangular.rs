@@ -460,6 +460,15 @@ mod tests {
         test_case(-5.0, -3.0, -4.0, 0.875, cdf(-3.5));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Triangular| x.inverse_cdf(arg);
+        test_almost(0.0, 2.0, 1.0, 0.0, 1e-16, inverse_cdf(0.0));
+        test_almost(0.0, 2.0, 1.0, 1.0, 1e-15, inverse_cdf(0.5));
+        test_almost(0.0, 2.0, 1.0, 2.0 - 0.2f64.sqrt(), 1e-15, inverse_cdf(0.9));
+        test_almost(0.0, 2.0, 1.0, 1.4142135623730950488e-32, 1e-46, inverse_cdf(1e-64));
+    }
+
     #[test]
     fn test_cdf_lower_bound() {
         let cdf = |arg: f64| move |x: Triangular| x.cdf(arg);
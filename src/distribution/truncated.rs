@@ -0,0 +1,602 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF, Normal};
+use crate::function::erf;
+use crate::statistics::*;
+use crate::{consts, Result, StatsError};
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements a [truncated](https://en.wikipedia.org/wiki/Truncated_distribution)
+/// distribution, the distribution of a continuous random variable
+/// conditioned on lying within `[lower, upper]`
+///
+/// # Remarks
+///
+/// The pdf and cdf are those of the parent distribution renormalized by the
+/// mass of the parent within the bounds, and samples are drawn by inverting
+/// the cdf. Both are accurate as long as the parent cdf resolves the
+/// bounds, so truncating far in the upper tail of a distribution whose cdf
+/// rounds to one there loses precision.
+///
+/// A truncated [Normal](./struct.Normal.html) constructed with
+/// [`new_normal`](#method.new_normal) is handled separately: the mass and
+/// cdf are computed from differences of the log cdf, which stay accurate
+/// arbitrarily far in either tail, and samples are drawn with the rejection
+/// samplers of Robert (1995), "Simulation of truncated normal variables".
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, ContinuousCDF, Normal, Truncated};
+/// use statrs::prec;
+///
+/// let n = Truncated::new_normal(Normal::new(0.0, 1.0).unwrap(), 0.0, f64::INFINITY).unwrap();
+/// assert_eq!(n.cdf(0.0), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.7978845608028654, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Truncated<D> {
+    dist: D,
+    lower: f64,
+    upper: f64,
+    cdf_lower: f64,
+    mass: f64,
+    ln_mass: f64,
+    normal: Option<StandardNormalBounds>,
+}
+
+/// The bounds of a truncated normal distribution in units of standard
+/// deviations from the mean
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct StandardNormalBounds {
+    mean: f64,
+    std_dev: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl StandardNormalBounds {
+    fn standardize(&self, x: f64) -> f64 {
+        (x - self.mean) / self.std_dev
+    }
+}
+
+impl<D> Truncated<D>
+where
+    D: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    /// Constructs a new distribution from `dist` truncated to the interval
+    /// `[lower, upper]`. Either bound may be infinite, and bounds beyond the
+    /// domain of `dist` are clamped to it.
+    ///
+    /// A `Normal` parent is treated like any other distribution here, so
+    /// prefer [`new_normal`](#method.new_normal) for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` or `upper` are `NaN`, if
+    /// `lower >= upper`, or if `dist` has no mass within the bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Truncated};
+    ///
+    /// let gamma = Gamma::new(3.0, 2.0).unwrap();
+    /// let mut result = Truncated::new(gamma, 0.5, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Truncated::new(gamma, -2.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, lower: f64, upper: f64) -> Result<Truncated<D>> {
+        Truncated::with_bounds(dist, lower, upper, None)
+    }
+
+    /// Constructs the distribution, standardizing the bounds with
+    /// `normal_params`, the mean and standard deviation, when `dist` is a
+    /// normal distribution
+    fn with_bounds(
+        dist: D,
+        lower: f64,
+        upper: f64,
+        normal_params: Option<(f64, f64)>,
+    ) -> Result<Truncated<D>> {
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return Err(StatsError::BadParams);
+        }
+        let lower = lower.max(dist.min());
+        let upper = upper.min(dist.max());
+        if lower >= upper {
+            return Err(StatsError::BadParams);
+        }
+
+        let normal = normal_params.map(|(mean, std_dev)| StandardNormalBounds {
+            mean,
+            std_dev,
+            lower: (lower - mean) / std_dev,
+            upper: (upper - mean) / std_dev,
+        });
+        let cdf_lower = dist.cdf(lower);
+        let (mass, ln_mass) = match normal {
+            Some(bounds) => {
+                let ln_mass = ln_standard_normal_mass(bounds.lower, bounds.upper);
+                (ln_mass.exp(), ln_mass)
+            }
+            None => {
+                let mass = dist.cdf(upper) - cdf_lower;
+                (mass, mass.ln())
+            }
+        };
+        if ln_mass > f64::NEG_INFINITY {
+            Ok(Truncated {
+                dist,
+                lower,
+                upper,
+                cdf_lower,
+                mass,
+                ln_mass,
+                normal,
+            })
+        } else {
+            Err(StatsError::BadParams)
+        }
+    }
+
+    /// Returns the untruncated parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let normal = Normal::new(0.0, 1.0).unwrap();
+    /// let n = Truncated::new(normal, -1.0, 1.0).unwrap();
+    /// assert_eq!(*n.distribution(), normal);
+    /// ```
+    pub fn distribution(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the lower bound of the truncated distribution, clamped to the
+    /// domain of the parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Truncated};
+    ///
+    /// let gamma = Gamma::new(3.0, 2.0).unwrap();
+    /// let n = Truncated::new(gamma, -1.0, 2.0).unwrap();
+    /// assert_eq!(n.lower(), 0.0);
+    /// ```
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Returns the upper bound of the truncated distribution, clamped to the
+    /// domain of the parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Truncated};
+    ///
+    /// let gamma = Gamma::new(3.0, 2.0).unwrap();
+    /// let n = Truncated::new(gamma, -1.0, 2.0).unwrap();
+    /// assert_eq!(n.upper(), 2.0);
+    /// ```
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Returns the probability that the parent distribution lies within
+    /// the bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let normal = Normal::new(0.0, 1.0).unwrap();
+    /// let n = Truncated::new(normal, 0.0, f64::INFINITY).unwrap();
+    /// assert_eq!(n.mass(), 0.5);
+    /// ```
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+}
+
+impl Truncated<Normal> {
+    /// Constructs a new normal distribution from `dist` truncated to the
+    /// interval `[lower, upper]`. Either bound may be infinite.
+    ///
+    /// Unlike [`new`](#method.new), the mass and cdf stay accurate when the
+    /// bounds lie far in either tail of `dist`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` or `upper` are `NaN`, if
+    /// `lower >= upper`, or if the bounds are so far in the tail that the
+    /// mass within them underflows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let normal = Normal::new(0.0, 1.0).unwrap();
+    /// let mut result = Truncated::new_normal(normal, 10.0, 12.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Truncated::new(normal, 10.0, 12.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new_normal(dist: Normal, lower: f64, upper: f64) -> Result<Truncated<Normal>> {
+        let mean = dist.mean().unwrap();
+        let std_dev = dist.std_dev().unwrap();
+        Truncated::with_bounds(dist, lower, upper, Some((mean, std_dev)))
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for Truncated<D>
+where
+    D: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        match self.normal {
+            Some(bounds) => {
+                let z = sample_standard_normal(r, bounds.lower, bounds.upper);
+                (bounds.mean + bounds.std_dev * z).clamp(self.lower, self.upper)
+            }
+            None => {
+                let u: f64 = r.sample(Open01);
+                self.inverse_cdf(u)
+            }
+        }
+    }
+}
+
+impl<D> ContinuousCDF<f64, f64> for Truncated<D>
+where
+    D: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    /// Calculates the cumulative distribution function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (F(x) - F(a)) / (F(b) - F(a))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution, `a` is the lower
+    /// bound and `b` is the upper bound
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            0.0
+        } else if x >= self.upper {
+            1.0
+        } else {
+            let p = match self.normal {
+                Some(bounds) => {
+                    let ln_mass = ln_standard_normal_mass(bounds.lower, bounds.standardize(x));
+                    (ln_mass - self.ln_mass).exp()
+                }
+                None => (self.dist.cdf(x) - self.cdf_lower) / self.mass,
+            };
+            p.clamp(0.0, 1.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated distribution at `p`
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if the inverse cdf of the parent
+    /// distribution fails
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(F(a) + p * (F(b) - F(a)))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution, `a` is the lower
+    /// bound and `b` is the upper bound. The truncated normal distribution
+    /// instead inverts its own cdf with Newton steps on the pdf.
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else if self.normal.is_some() {
+            internal::inverse_cdf_newton(self, p)
+        } else {
+            let q = (self.cdf_lower + p * self.mass).min(1.0);
+            let x = self.dist.checked_inverse_cdf(q)?;
+            Ok(x.clamp(self.lower, self.upper))
+        }
+    }
+}
+
+impl<D> Min<f64> for Truncated<D> {
+    /// Returns the minimum value in the domain of the truncated
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// a
+    /// ```
+    ///
+    /// where `a` is the lower bound
+    fn min(&self) -> f64 {
+        self.lower
+    }
+}
+
+impl<D> Max<f64> for Truncated<D> {
+    /// Returns the maximum value in the domain of the truncated
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// b
+    /// ```
+    ///
+    /// where `b` is the upper bound
+    fn max(&self) -> f64 {
+        self.upper
+    }
+}
+
+impl<D> Continuous<f64, f64> for Truncated<D>
+where
+    D: Continuous<f64, f64> + ContinuousCDF<f64, f64>,
+{
+    /// Calculates the probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// f(x) / (F(b) - F(a))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `f` and `F` are the pdf and cdf of the
+    /// parent distribution, `a` is the lower bound and `b` is the upper
+    /// bound
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x)) - ln(F(b) - F(a))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `f` and `F` are the pdf and cdf of the
+    /// parent distribution, `a` is the lower bound and `b` is the upper
+    /// bound
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            f64::NEG_INFINITY
+        } else {
+            self.dist.ln_pdf(x) - self.ln_mass
+        }
+    }
+}
+
+/// Returns `ln(Φ(b) - Φ(a))` for `a <= b`, the log of the standard normal
+/// mass within `[a, b]`, working in whichever tail avoids cancellation
+fn ln_standard_normal_mass(a: f64, b: f64) -> f64 {
+    if a > 0.0 {
+        ln_standard_normal_mass(-b, -a)
+    } else if b <= 0.0 {
+        let ln_cdf_b = internal::ln_standard_normal_cdf(b);
+        let ln_cdf_a = internal::ln_standard_normal_cdf(a);
+        if ln_cdf_a == f64::NEG_INFINITY {
+            ln_cdf_b
+        } else {
+            ln_cdf_b + (-(ln_cdf_a - ln_cdf_b).exp_m1()).ln()
+        }
+    } else {
+        let tails =
+            0.5 * erf::erfc(-a / f64::consts::SQRT_2) + 0.5 * erf::erfc(b / f64::consts::SQRT_2);
+        (-tails).ln_1p()
+    }
+}
+
+/// Draws a sample from the standard normal distribution truncated to
+/// `[a, b]` using the rejection samplers of Robert (1995): normal rejection
+/// for wide intervals around the mean, uniform rejection for narrow
+/// intervals and exponential rejection in the tails
+fn sample_standard_normal<R: Rng + ?Sized>(r: &mut R, a: f64, b: f64) -> f64 {
+    if a >= 0.0 {
+        sample_standard_normal_tail(r, a, b)
+    } else if b <= 0.0 {
+        -sample_standard_normal_tail(r, -b, -a)
+    } else if b - a >= consts::SQRT_2PI {
+        loop {
+            let z = super::ziggurat::sample_std_normal(r);
+            if a <= z && z <= b {
+                return z;
+            }
+        }
+    } else {
+        loop {
+            let u: f64 = r.gen();
+            let z = a + (b - a) * u;
+            let v: f64 = r.sample(Open01);
+            if v.ln() <= -0.5 * z * z {
+                return z;
+            }
+        }
+    }
+}
+
+/// Draws a sample from the standard normal distribution truncated to
+/// `[a, b]` for `0 <= a < b`
+fn sample_standard_normal_tail<R: Rng + ?Sized>(r: &mut R, a: f64, b: f64) -> f64 {
+    let rate = 0.5 * (a + (a * a + 4.0).sqrt());
+    let uniform_width = (0.25 * a * (a - (a * a + 4.0).sqrt()) + 0.5).exp() / rate;
+    if b - a < uniform_width {
+        loop {
+            let u: f64 = r.gen();
+            let z = a + (b - a) * u;
+            let v: f64 = r.sample(Open01);
+            if v.ln() <= 0.5 * (a * a - z * z) {
+                return z;
+            }
+        }
+    } else {
+        loop {
+            let u: f64 = r.sample(Open01);
+            let z = a - u.ln() / rate;
+            let v: f64 = r.sample(Open01);
+            if z <= b && v.ln() <= -0.5 * (z - rate) * (z - rate) {
+                return z;
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Exp, Gamma, Normal, Truncated};
+    use crate::distribution::internal::*;
+
+    fn normal(lower: f64, upper: f64) -> Truncated<Normal> {
+        let n = Truncated::new_normal(Normal::new(0.0, 1.0).unwrap(), lower, upper);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn gamma(lower: f64, upper: f64) -> Truncated<Gamma> {
+        let n = Truncated::new(Gamma::new(3.0, 2.0).unwrap(), lower, upper);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let n = gamma(-1.0, 2.0);
+        assert_eq!(n.lower(), 0.0);
+        assert_eq!(n.upper(), 2.0);
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), 2.0);
+        let n = normal(f64::NEG_INFINITY, 1.0);
+        assert_eq!(n.min(), f64::NEG_INFINITY);
+        assert_eq!(n.max(), 1.0);
+        assert_eq!(*n.distribution(), Normal::new(0.0, 1.0).unwrap());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(Truncated::new(n, f64::NAN, 1.0).is_err());
+        assert!(Truncated::new(n, 0.0, f64::NAN).is_err());
+        assert!(Truncated::new(n, 1.0, 1.0).is_err());
+        assert!(Truncated::new(n, 2.0, 1.0).is_err());
+        assert!(Truncated::new_normal(n, f64::NAN, 1.0).is_err());
+        assert!(Truncated::new_normal(n, 1.0, 1.0).is_err());
+        assert!(Truncated::new_normal(n, 1e200, f64::INFINITY).is_err());
+        assert!(Truncated::new(n, 10.0, 12.0).is_err());
+        let g = Gamma::new(3.0, 2.0).unwrap();
+        assert!(Truncated::new(g, -2.0, -1.0).is_err());
+        assert!(Truncated::new(g, -2.0, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_mass() {
+        assert_almost_eq!(normal(-1.0, 2.0).mass(), 0.81859461412036374138, 1e-10);
+        assert_almost_eq!(normal(10.0, 12.0).mass(), 7.6198530223840439549e-24, 1e-33);
+        assert_almost_eq!(gamma(0.5, 2.0).mass(), 0.68159529737506146017, 1e-14);
+        let n = Truncated::new(Normal::new(0.0, 1.0).unwrap(), -1.0, 2.0).unwrap();
+        assert_almost_eq!(n.mass(), 0.81859461412036374138, 1e-10);
+    }
+
+    #[test]
+    fn test_pdf() {
+        assert_almost_eq!(normal(-1.0, 2.0).pdf(0.5), 0.43008507592322471465, 1e-10);
+        assert_almost_eq!(normal(10.0, 12.0).pdf(10.1), 3.6963528509044686763, 1e-9);
+        assert_almost_eq!(gamma(0.5, 2.0).pdf(1.0), 0.79422662543483918689, 1e-14);
+        assert_eq!(normal(-1.0, 2.0).pdf(2.5), 0.0);
+        assert_eq!(gamma(0.5, 2.0).pdf(0.25), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        assert_almost_eq!(normal(-1.0, 2.0).ln_pdf(0.5), -0.84377223888021016183, 1e-10);
+        assert_almost_eq!(normal(-40.0, -39.0).ln_pdf(-39.5), -15.960781968827128331, 1e-10);
+        assert_almost_eq!(gamma(0.5, 2.0).ln_pdf(1.0), -0.2303864359982290698, 1e-14);
+        assert_eq!(normal(-1.0, 2.0).ln_pdf(-1.5), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        assert_almost_eq!(normal(-1.0, 2.0).cdf(0.5), 0.65088042133662712997, 1e-10);
+        assert_almost_eq!(normal(10.0, 12.0).cdf(10.1), 0.63751145043427149634, 1e-10);
+        assert_almost_eq!(normal(-12.0, -10.0).cdf(-10.1), 0.36248854956572850481, 1e-10);
+        assert_almost_eq!(gamma(0.5, 2.0).cdf(1.0), 0.3565490954551209589, 1e-14);
+        assert_eq!(normal(-1.0, 2.0).cdf(-1.0), 0.0);
+        assert_eq!(normal(-1.0, 2.0).cdf(2.0), 1.0);
+        let n = Truncated::new(Exp::new(1.0).unwrap(), 1.0, f64::INFINITY).unwrap();
+        assert_almost_eq!(n.cdf(2.0), 0.63212055882855768, 1e-15);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        assert_almost_eq!(normal(-1.0, 2.0).inverse_cdf(0.65088042133662712997), 0.5, 1e-10);
+        assert_almost_eq!(normal(10.0, 12.0).inverse_cdf(0.63751145043427149634), 10.1, 1e-10);
+        assert_almost_eq!(gamma(0.5, 2.0).inverse_cdf(0.3565490954551209589), 1.0, 1e-14);
+        assert_eq!(gamma(0.5, 2.0).inverse_cdf(0.0), 0.5);
+        assert_eq!(gamma(0.5, 2.0).inverse_cdf(1.0), 2.0);
+        assert!(gamma(0.5, 2.0).checked_inverse_cdf(1.5).is_err());
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&normal(-1.0, 2.0), -1.0, 2.0);
+        tests::check_continuous_distribution(&normal(10.0, 12.0), 10.0, 12.0);
+        tests::check_continuous_distribution(&gamma(0.5, 2.0), 0.5, 2.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand::distributions::Distribution;
+
+        // truncated standard normal means, (φ(a) - φ(b)) / (Φ(b) - Φ(a))
+        let cases = [
+            (-1.0, 2.0, 0.22963717909132896862),
+            (f64::NEG_INFINITY, -3.0, -3.2830986549304365069),
+            (5.0, f64::INFINITY, 5.1865039671258421156),
+            (0.5, 0.6, 0.54954184251023176940),
+            (-0.1, 0.3, 0.098673799212582222255),
+        ];
+        let mut r: StdRng = SeedableRng::seed_from_u64(42);
+        for &(lower, upper, expected) in &cases {
+            let n = normal(lower, upper);
+            let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| lower <= x && x <= upper));
+            let mean = samples.iter().sum::<f64>() / 100_000.0;
+            assert!((mean - expected).abs() < 0.01);
+        }
+
+        let n = gamma(0.5, 2.0);
+        let samples: Vec<f64> = (0..100_000).map(|_| n.sample(&mut r)).collect();
+        assert!(samples.iter().all(|&x| (0.5..=2.0).contains(&x)));
+        let mean = samples.iter().sum::<f64>() / 100_000.0;
+        assert!((mean - 1.2049856964957347573).abs() < 0.01);
+    }
+}
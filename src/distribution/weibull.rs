@@ -115,6 +115,28 @@ impl ContinuousCDF<f64, f64> for Weibull {
             -(-x.powf(self.shape) * self.scale_pow_shape_inv).exp_m1()
         }
     }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// weibull distribution at `x`
+    ///
+    /// # Errors
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (-ln(1 - x))^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn checked_inverse_cdf(&self, x: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&x) {
+            Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+        } else {
+            Ok(self.scale * (-(-x).ln_1p()).powf(1.0 / self.shape))
+        }
+    }
 }
 
 impl Min<f64> for Weibull {
@@ -483,6 +505,19 @@ mod tests {
         test_case(10.0, 1.0, 1.0, cdf(10.0));
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Weibull| x.inverse_cdf(arg);
+        test_almost(1.5, 2.0, 0.44615105127383415883, 1e-15, inverse_cdf(0.1));
+        test_almost(1.5, 2.0, 3.4874430271928231778, 1e-14, inverse_cdf(0.9));
+        test_almost(1.5, 2.0, 11.515283160439568751, 1e-13, inverse_cdf(0.999999));
+        test_almost(0.5, 1.0, 1e-62, 1e-76, inverse_cdf(1e-31));
+        test_almost(2.0, 1.0, 3.1622776601683793320e-45, 1e-59, inverse_cdf(1e-89));
+        test_case(1.5, 2.0, 0.0, inverse_cdf(0.0));
+        test_case(1.5, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        assert!(try_create(1.5, 2.0).checked_inverse_cdf(-0.1).is_err());
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 0.2), 0.0, 10.0);
@@ -0,0 +1,329 @@
+//! Provides the modified [Bessel](https://en.wikipedia.org/wiki/Bessel_function)
+//! function of the second kind
+
+use crate::error::StatsError;
+use crate::function::evaluate;
+use crate::Result;
+use std::f64;
+
+/// Coefficients of `-(1 / Γ(1 - μ) - 1 / Γ(1 + μ)) / 2μ` as a polynomial in
+/// `μ^2`, the odd Taylor coefficients of `1 / Γ(1 + μ)`
+const GAM1_COEFFS: &[f64] = &[
+    0.57721566490153286061,
+    -0.042002635034095235529,
+    -0.042197734555544336748,
+    0.0072189432466630995424,
+    -0.00021524167411495097282,
+    -0.000020134854780788238656,
+    1.1330272319816958824e-6,
+    6.1160951044814158179e-9,
+    -1.1812745704870201446e-9,
+    7.782263439905071254e-12,
+    5.100370287454475979e-13,
+    -5.3481225394230179824e-15,
+    -1.1812593016974587695e-16,
+    1.4123806553180317816e-18,
+];
+
+/// Coefficients of `(1 / Γ(1 - μ) + 1 / Γ(1 + μ)) / 2` as a polynomial in
+/// `μ^2`, the even Taylor coefficients of `1 / Γ(1 + μ)`
+const GAM2_COEFFS: &[f64] = &[
+    1.0,
+    -0.65587807152025388108,
+    0.1665386113822914895,
+    -0.0096219715278769735621,
+    -0.0011651675918590651121,
+    0.00012805028238811618615,
+    -1.2504934821426706573e-6,
+    -2.0563384169776071035e-7,
+    5.0020076444692229301e-9,
+    1.0434267116911005105e-10,
+    -3.6968056186422057082e-12,
+    -2.0583260535665067832e-14,
+    1.2267786282382607902e-15,
+    1.1866922547516003326e-18,
+];
+
+/// The argument below which `K_μ` is evaluated by Temme's series rather
+/// than Steed's continued fraction
+const SERIES_MAX_X: f64 = 2.0;
+
+/// The magnitude above which the forward recurrence is rescaled to avoid
+/// overflow
+const RESCALE_THRESHOLD: f64 = 1e250;
+
+/// Computes the modified Bessel function of the second kind `K_ν(x)` of
+/// real order `ν` for `x > 0`.
+///
+/// # Panics
+///
+/// if `x <= 0.0`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`. The result
+/// overflows to `f64::INFINITY` for large orders with small arguments and
+/// underflows to zero for large arguments, see `ln_bessel_k` for those
+/// cases.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel;
+///
+/// let k = bessel::bessel_k(0.5, 1.0);
+/// let expected = (std::f64::consts::PI / 2.0).sqrt() * (-1.0f64).exp();
+/// assert!((k - expected).abs() < 1e-15);
+/// ```
+pub fn bessel_k(nu: f64, x: f64) -> f64 {
+    checked_bessel_k(nu, x).unwrap()
+}
+
+/// Computes the modified Bessel function of the second kind `K_ν(x)` of
+/// real order `ν` for `x > 0`.
+///
+/// # Errors
+///
+/// if `x <= 0.0`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+pub fn checked_bessel_k(nu: f64, x: f64) -> Result<f64> {
+    let (value, ln_scale) = scaled_bessel_k(nu, x)?;
+    Ok(value * ln_scale.exp())
+}
+
+/// Computes the natural logarithm of the modified Bessel function of the
+/// second kind `ln(K_ν(x))` of real order `ν` for `x > 0`, without
+/// overflowing or underflowing where `K_ν(x)` itself would.
+///
+/// # Panics
+///
+/// if `x <= 0.0`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel;
+///
+/// assert!((bessel::ln_bessel_k(0.0, 800.0) + 803.1166706636599).abs() < 1e-12);
+/// ```
+pub fn ln_bessel_k(nu: f64, x: f64) -> f64 {
+    checked_ln_bessel_k(nu, x).unwrap()
+}
+
+/// Computes the natural logarithm of the modified Bessel function of the
+/// second kind `ln(K_ν(x))` of real order `ν` for `x > 0`, without
+/// overflowing or underflowing where `K_ν(x)` itself would.
+///
+/// # Errors
+///
+/// if `x <= 0.0`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+pub fn checked_ln_bessel_k(nu: f64, x: f64) -> Result<f64> {
+    let (value, ln_scale) = scaled_bessel_k(nu, x)?;
+    Ok(value.ln() + ln_scale)
+}
+
+/// Returns `(k, s)` with `K_ν(x) = k * exp(s)`, following the algorithm of
+/// Temme as presented in "Numerical Recipes", Press et al., 3rd ed.,
+/// section 6.6: `K_μ` and `K_(μ+1)` are computed for `|μ| <= 1 / 2` with
+/// Temme's series for `x < 2` and Steed's continued fraction otherwise, and
+/// raised to order `|ν|` by forward recurrence
+fn scaled_bessel_k(nu: f64, x: f64) -> Result<(f64, f64)> {
+    if nu.is_nan() || x.is_nan() {
+        return Ok((f64::NAN, 0.0));
+    }
+    if x <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("x"));
+    }
+    if x.is_infinite() {
+        return Ok((0.0, 0.0));
+    }
+    // K is even in its order
+    let nu = nu.abs();
+    if nu.is_infinite() {
+        return Ok((f64::INFINITY, 0.0));
+    }
+    let steps = (nu + 0.5).floor();
+    let mu = nu - steps;
+    let (mut k_mu, mut k_mu1, mut ln_scale) = if x < SERIES_MAX_X {
+        let (k_mu, k_mu1) = temme_series(mu, x);
+        (k_mu, k_mu1, 0.0)
+    } else {
+        let (k_mu, k_mu1) = steed_fraction(mu, x);
+        (k_mu, k_mu1, -x)
+    };
+
+    let two_over_x = 2.0 / x;
+    let mut order = mu;
+    for _ in 0..steps as u64 {
+        order += 1.0;
+        let next = order * two_over_x * k_mu1 + k_mu;
+        k_mu = k_mu1;
+        k_mu1 = next;
+        if k_mu1 > RESCALE_THRESHOLD {
+            ln_scale += k_mu1.ln();
+            k_mu /= k_mu1;
+            k_mu1 = 1.0;
+        }
+    }
+    Ok((k_mu, ln_scale))
+}
+
+/// Returns `(K_μ(x), K_(μ+1)(x))` for `|μ| <= 1 / 2` and `0 < x < 2` from
+/// Temme's series
+fn temme_series(mu: f64, x: f64) -> (f64, f64) {
+    let mu2 = mu * mu;
+    let gam1 = -evaluate::polynomial(mu2, GAM1_COEFFS);
+    let gam2 = evaluate::polynomial(mu2, GAM2_COEFFS);
+    // 1 / Γ(1 + μ) and 1 / Γ(1 - μ)
+    let gam_plus = gam2 - mu * gam1;
+    let gam_minus = gam2 + mu * gam1;
+
+    let half_x = 0.5 * x;
+    let pi_mu = f64::consts::PI * mu;
+    let fact = if pi_mu.abs() < f64::EPSILON {
+        1.0
+    } else {
+        pi_mu / pi_mu.sin()
+    };
+    let d = -half_x.ln();
+    let e = mu * d;
+    let fact2 = if e.abs() < f64::EPSILON {
+        1.0
+    } else {
+        e.sinh() / e
+    };
+    let mut ff = fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+    let mut sum = ff;
+    let e = e.exp();
+    let mut p = 0.5 * e / gam_plus;
+    let mut q = 0.5 / (e * gam_minus);
+    let mut c = 1.0;
+    let d = half_x * half_x;
+    let mut sum1 = p;
+    let mut i = 1.0;
+    loop {
+        ff = (i * ff + p + q) / (i * i - mu2);
+        c *= d / i;
+        p /= i - mu;
+        q /= i + mu;
+        let del = c * ff;
+        sum += del;
+        sum1 += c * (p - i * ff);
+        if del.abs() < sum.abs() * f64::EPSILON {
+            break;
+        }
+        i += 1.0;
+    }
+    (sum, sum1 * 2.0 / x)
+}
+
+/// Returns `(e^x K_μ(x), e^x K_(μ+1)(x))` for `|μ| <= 1 / 2` and `x >= 2`
+/// from Steed's continued fraction
+fn steed_fraction(mu: f64, x: f64) -> (f64, f64) {
+    let a1 = 0.25 - mu * mu;
+    let mut b = 2.0 * (1.0 + x);
+    let mut d = 1.0 / b;
+    let mut delh = d;
+    let mut h = d;
+    let mut q1 = 0.0;
+    let mut q2 = 1.0;
+    let mut q = a1;
+    let mut c = a1;
+    let mut a = -a1;
+    let mut s = 1.0 + q * delh;
+    let mut i = 2.0;
+    loop {
+        a -= 2.0 * (i - 1.0);
+        c = -a * c / i;
+        let qnew = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = qnew;
+        q += c * qnew;
+        b += 2.0;
+        d = 1.0 / (b + a * d);
+        delh *= b * d - 1.0;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if (dels / s).abs() < f64::EPSILON {
+            break;
+        }
+        i += 1.0;
+    }
+    let k_mu = (f64::consts::PI / (2.0 * x)).sqrt() / s;
+    let k_mu1 = k_mu * (mu + x + 0.5 - a1 * h) / x;
+    (k_mu, k_mu1)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_bessel_k() {
+        assert_almost_eq!(super::bessel_k(0.0, 0.1), 2.4270690247020165578, 1e-14);
+        assert_almost_eq!(super::bessel_k(0.0, 1.0), 0.42102443824070833334, 1e-15);
+        assert_almost_eq!(super::bessel_k(0.0, 2.0), 0.11389387274953343565, 1e-15);
+        assert_almost_eq!(super::bessel_k(0.0, 10.0), 1.7780062316167651811e-5, 1e-19);
+        assert_almost_eq!(super::bessel_k(0.5, 1.0), 0.46106850444789455844, 1e-15);
+        assert_almost_eq!(super::bessel_k(1.0, 1.0), 0.60190723019723457474, 1e-15);
+        assert_almost_eq!(super::bessel_k(1.0, 0.01), 99.973894118296245561, 1e-12);
+        assert_almost_eq!(super::bessel_k(2.5, 3.0), 0.084060631974117382653, 1e-15);
+        assert_almost_eq!(super::bessel_k(-2.5, 3.0), 0.084060631974117382653, 1e-15);
+        assert_almost_eq!(super::bessel_k(0.3, 1.5), 0.21893795473217301825, 1e-15);
+        assert_almost_eq!(super::bessel_k(0.3, 2.5), 0.063313879296295559452, 1e-15);
+        assert_almost_eq!(super::bessel_k(5.0, 0.1), 38376009.99583591757, 1e-6);
+        assert_almost_eq!(super::bessel_k(10.0, 2.0), 162482.40397955914872, 1e-9);
+        assert_almost_eq!(super::bessel_k(1.7, 50.0), 3.5091573095620960449e-23, 1e-37);
+        assert_almost_eq!(super::bessel_k(0.25, 1e-10), 681.71747463057842062, 1e-11);
+        assert_eq!(super::bessel_k(0.0, f64::INFINITY), 0.0);
+        assert!(super::bessel_k(f64::NAN, 1.0).is_nan());
+        assert!(super::bessel_k(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bessel_k_x_lte_0() {
+        super::bessel_k(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_checked_bessel_k_x_lte_0() {
+        assert!(super::checked_bessel_k(1.0, 0.0).is_err());
+        assert!(super::checked_bessel_k(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_ln_bessel_k() {
+        assert_almost_eq!(super::ln_bessel_k(0.0, 1.0), -0.8650643989067880968, 1e-15);
+        assert_almost_eq!(super::ln_bessel_k(2.5, 3.0), -2.4762169313021237996, 1e-15);
+        assert_almost_eq!(super::ln_bessel_k(0.0, 700.0), -703.04992725894391223, 1e-12);
+        assert_almost_eq!(super::ln_bessel_k(100.0, 1.0), 427.75325102501880829, 1e-12);
+        assert_almost_eq!(super::ln_bessel_k(0.0, 800.0), -803.11667066365989547, 1e-12);
+        assert_almost_eq!(super::ln_bessel_k(300.0, 0.5), 1824.3970195957920047, 1e-11);
+        assert_eq!(super::ln_bessel_k(0.0, f64::INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_bessel_k_x_lte_0() {
+        super::ln_bessel_k(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_checked_ln_bessel_k_x_lte_0() {
+        assert!(super::checked_ln_bessel_k(1.0, 0.0).is_err());
+    }
+}
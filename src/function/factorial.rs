@@ -119,7 +119,7 @@ fn get_fcache() -> [f64; CACHE_SIZE] {
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use std::{f64, u64};
+    use std::f64;
 
     #[test]
     fn test_factorial_and_ln_factorial() {
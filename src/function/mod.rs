@@ -1,6 +1,7 @@
 //! Provides a host of special statistical functions (e.g. the beta function or
 //! the error function)
 
+pub mod bessel;
 pub mod beta;
 pub mod erf;
 pub mod evaluate;
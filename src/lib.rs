@@ -26,6 +26,7 @@
 #![crate_type = "lib"]
 #![crate_name = "statrs"]
 #![allow(clippy::excessive_precision)]
+#![cfg_attr(test, allow(clippy::approx_constant))]
 #![allow(clippy::many_single_char_names)]
 #![allow(dead_code)]
 #![allow(unused_imports)]
@@ -37,10 +38,10 @@ extern crate approx;
 macro_rules! assert_almost_eq {
     ($a:expr, $b:expr, $prec:expr) => {
         if !$crate::prec::almost_eq($a, $b, $prec) {
-            panic!(format!(
+            panic!(
                 "assertion failed: `abs(left - right) < {:e}`, (left: `{}`, right: `{}`)",
                 $prec, $a, $b
-            ));
+            );
         }
     };
 }
@@ -51,7 +52,10 @@ pub mod euclid;
 pub mod function;
 pub mod generate;
 pub mod prec;
+pub mod quadrature;
+pub mod solve;
 pub mod statistics;
+pub mod stats_tests;
 
 mod error;
 
@@ -0,0 +1,704 @@
+//! Provides numerical integration of functions of one variable and
+//! expectations under continuous distributions
+//!
+//! Both integrators accept finite or infinite limits, mapping infinite
+//! intervals onto finite ones by a change of variables. Their `tolerance` is
+//! relative to the integral of `|f|`, so that integrands which cancel to zero
+//! can still be integrated to a meaningful accuracy.
+//!
+//! # Examples
+//!
+//! ```
+//! use statrs::quadrature;
+//!
+//! let result = quadrature::gauss_kronrod(|x| (-x * x).exp(), 0.0, f64::INFINITY, 1e-12, 100)
+//!     .unwrap();
+//! assert!((result.value - std::f64::consts::PI.sqrt() / 2.0).abs() < 1e-12);
+//! ```
+
+use crate::distribution::Continuous;
+use crate::statistics::{Max, Min};
+use crate::{solve, Result, StatsError};
+use std::f64;
+
+/// The value of an integral together with an estimate of its absolute error
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Integral {
+    /// The estimated value of the integral
+    pub value: f64,
+    /// An estimate of the absolute error in `value`
+    pub error: f64,
+}
+
+/// The nodes of the 15-point Kronrod rule on `[-1, 1]`, in decreasing order;
+/// the nodes with odd indices are those of the embedded 7-point Gauss rule
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_639_206_854_697_526_329,
+    0.949_107_912_342_758_524_526_189_684_047_851,
+    0.864_864_423_359_769_072_789_712_788_640_926,
+    0.741_531_185_599_394_439_863_864_773_280_788,
+    0.586_087_235_467_691_130_294_144_845_693_013,
+    0.405_845_151_377_397_166_906_606_412_076_961,
+    0.207_784_955_007_898_467_600_689_403_773_245,
+    0.0,
+];
+
+/// The weights of the 15-point Kronrod rule
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_224_963_732_008_058_970,
+    0.063_092_092_629_978_553_290_700_663_189_204,
+    0.104_790_010_322_250_183_839_876_322_541_518,
+    0.140_653_259_715_525_918_745_189_590_510_238,
+    0.169_004_726_639_267_902_826_583_426_598_550,
+    0.190_350_578_064_785_409_913_256_402_421_014,
+    0.204_432_940_075_298_892_414_161_999_234_649,
+    0.209_482_141_084_727_828_012_999_174_891_714,
+];
+
+/// The weights of the embedded 7-point Gauss rule
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_693_270_611_432_679_082,
+    0.279_705_391_489_276_667_901_467_771_423_780,
+    0.381_830_050_505_118_944_950_369_775_488_975,
+    0.417_959_183_673_469_387_755_102_040_816_327,
+];
+
+/// The relative error below which the G7K15 error estimate is dominated by
+/// rounding, and to which smaller tolerances are raised
+const ROUNDOFF: f64 = 50.0 * f64::EPSILON;
+
+/// The largest abscissa of the tanh-sinh rule, beyond which the nodes are
+/// closer to the ends of the interval than can be represented
+const TANH_SINH_MAX_T: f64 = 6.5;
+
+/// Integrates `f` from `a` to `b` with adaptive Gauss-Kronrod (G7K15)
+/// quadrature, repeatedly bisecting the subinterval with the largest error
+/// estimate until the total estimated error is at most `tolerance` times the
+/// integral of `|f|`. Tolerances below about `1e-14` are limited by
+/// rounding and treated as that.
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`,
+/// or `StatsError::BadParams` if either limit is `NaN`. Returns
+/// `StatsError::ComputationFailedToConverge` if the tolerance is not met
+/// with at most `max_subdivisions` subintervals or `f` is not finite at a
+/// node.
+///
+/// # Remarks
+///
+/// Infinite limits are handled by the substitutions `x = a + t / (1 - t)`
+/// and `x = t / (1 - t^2)`. Integrable singularities at the ends of the
+/// interval are tolerated, since the rule never evaluates `f` there, but
+/// `tanh_sinh` usually handles them with far fewer evaluations.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::quadrature;
+///
+/// let result = quadrature::gauss_kronrod(|x| x.sin(), 0.0, std::f64::consts::PI, 1e-14, 100)
+///     .unwrap();
+/// assert!((result.value - 2.0).abs() < 1e-14);
+/// assert!(result.error < 1e-13);
+/// ```
+pub fn gauss_kronrod<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_subdivisions: usize,
+) -> Result<Integral>
+where
+    F: Fn(f64) -> f64,
+{
+    check_params(a, b, tolerance)?;
+    integrate_mapped(&f, a, b, |g, low, high| {
+        adaptive_gauss_kronrod(g, low, high, tolerance, max_subdivisions)
+    })
+}
+
+/// Integrates `f` from `a` to `b` with the tanh-sinh (double exponential)
+/// rule, halving the step size until successive estimates agree to within
+/// `tolerance` times the integral of `|f|`
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`,
+/// or `StatsError::BadParams` if either limit is `NaN`. Returns
+/// `StatsError::ComputationFailedToConverge` if the tolerance is not met
+/// within `max_levels` halvings of the step size or `f` is not finite at a
+/// node.
+///
+/// # Remarks
+///
+/// The nodes cluster doubly exponentially towards the ends of the interval,
+/// which makes the rule well suited to integrands with singularities there.
+/// Since `f` only sees the nodes themselves, a singularity at a nonzero end
+/// can only be approached to within the spacing of floats there, so it is
+/// best to shift singularities to zero.
+/// Infinite limits are handled by the same substitutions as in
+/// `gauss_kronrod`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::quadrature;
+///
+/// // the integrand is singular at zero
+/// let result = quadrature::tanh_sinh(|x| x.ln() / x.sqrt(), 0.0, 1.0, 1e-12, 10).unwrap();
+/// assert!((result.value + 4.0).abs() < 1e-12);
+/// ```
+pub fn tanh_sinh<F>(f: F, a: f64, b: f64, tolerance: f64, max_levels: usize) -> Result<Integral>
+where
+    F: Fn(f64) -> f64,
+{
+    check_params(a, b, tolerance)?;
+    integrate_mapped(&f, a, b, |g, low, high| {
+        tanh_sinh_finite(g, low, high, tolerance, max_levels)
+    })
+}
+
+/// Computes the expectation `E[g(X)]` of `g` for a random variable `X`
+/// following the continuous distribution `dist`, by integrating `g(x)
+/// pdf(x)` over `[dist.min(), dist.max()]`
+///
+/// # Errors
+///
+/// Returns `StatsError::ComputationFailedToConverge` if the integral cannot
+/// be computed to a relative accuracy of about `1e-10`, for example when
+/// the expectation does not exist
+///
+/// # Remarks
+///
+/// The location and spread of the distribution are estimated from where the
+/// density is largest, and the domain is split around that point so that
+/// narrow or distant peaks are not missed.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::quadrature;
+///
+/// let n = Normal::new(1.0, 2.0).unwrap();
+/// let second_moment = quadrature::expectation(&n, |x| x * x).unwrap();
+/// assert!((second_moment - 5.0).abs() < 1e-10);
+/// ```
+pub fn expectation<D, G>(dist: &D, g: G) -> Result<f64>
+where
+    D: Continuous<f64, f64> + Min<f64> + Max<f64>,
+    G: Fn(f64) -> f64,
+{
+    let (min, max) = (dist.min(), dist.max());
+    let integrand = |x: f64| {
+        let density = dist.pdf(x);
+        if density == 0.0 {
+            0.0
+        } else {
+            g(x) * density
+        }
+    };
+    let (center, scale) = locate_mass(dist, min, max);
+
+    // split into the peak and the two tails on either side
+    let mut cuts = vec![min];
+    for &cut in &[center - 8.0 * scale, center, center + 8.0 * scale] {
+        if cut > *cuts.last().unwrap() && cut < max {
+            cuts.push(cut);
+        }
+    }
+    cuts.push(max);
+    let mut sum = 0.0;
+    for piece in cuts.windows(2) {
+        let (low, high) = (piece[0], piece[1]);
+        let value = if low.is_infinite() {
+            scale
+                * gauss_kronrod(
+                    |y| integrand(high + scale * y),
+                    f64::NEG_INFINITY,
+                    0.0,
+                    1e-10,
+                    1000,
+                )?
+                .value
+        } else if high.is_infinite() {
+            scale
+                * gauss_kronrod(
+                    |y| integrand(low + scale * y),
+                    0.0,
+                    f64::INFINITY,
+                    1e-10,
+                    1000,
+                )?
+                .value
+        } else {
+            gauss_kronrod(integrand, low, high, 1e-10, 1000)?.value
+        };
+        sum += value;
+    }
+    Ok(sum)
+}
+
+/// Finds a point of large density and the corresponding spread `1 / pdf`
+/// by probing `dist` on a grid over its domain, uniform for a finite domain
+/// and geometric otherwise, and refining the best probe towards the mode
+fn locate_mass<D: Continuous<f64, f64>>(dist: &D, min: f64, max: f64) -> (f64, f64) {
+    let mut probes = Vec::new();
+    if min.is_finite() && max.is_finite() {
+        probes.extend((1..256).map(|i| min + (max - min) * f64::from(i) / 256.0));
+    } else {
+        let anchor = if min.is_finite() {
+            min
+        } else if max.is_finite() {
+            max
+        } else {
+            0.0
+        };
+        probes.push(anchor);
+        for k in -30..64 {
+            let step = 2f64.powi(k);
+            probes.push(anchor + step);
+            probes.push(anchor - step);
+        }
+    }
+    probes.retain(|&x| x > min && x < max);
+    probes.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    probes.dedup();
+
+    // compare log densities, since the density of a narrow peak underflows
+    // at all probes but the nearest. A log density that is infinite at an
+    // interior point, or large where the density is zero, is a numerical
+    // failure rather than a mode, and the probe is skipped
+    let neg_ln_pdf = |x: f64| {
+        let ln_density = dist.ln_pdf(x);
+        if ln_density.is_nan()
+            || ln_density == f64::INFINITY
+            || (ln_density > f64::MIN_POSITIVE.ln() && dist.pdf(x) == 0.0)
+        {
+            f64::MAX
+        } else {
+            (-ln_density).min(f64::MAX)
+        }
+    };
+    let best = match (0..probes.len()).min_by(|&i, &j| {
+        neg_ln_pdf(probes[i])
+            .partial_cmp(&neg_ln_pdf(probes[j]))
+            .unwrap()
+    }) {
+        Some(i) if neg_ln_pdf(probes[i]) < f64::MAX => i,
+        _ => return (0.5 * (min.max(-1.0) + max.min(1.0)), 1.0),
+    };
+    let low = if best == 0 { min } else { probes[best - 1] };
+    let high = probes.get(best + 1).copied().unwrap_or(max);
+    let probe = probes[best];
+
+    // refine towards the mode, unless it is at an end of the domain where
+    // the density may be unbounded
+    let mode = solve::brent_min(
+        neg_ln_pdf,
+        low.max(f64::MIN),
+        high.min(f64::MAX),
+        0.0,
+        solve::DEFAULT_MAX_ITERATIONS,
+    )
+    .ok()
+    .filter(|&x| {
+        (x - min).min(max - x) > 1e-6 * (high - low).min(f64::MAX)
+            && dist.pdf(x).is_finite()
+            && dist.pdf(x) > dist.pdf(probe)
+    })
+    .unwrap_or(probe);
+    let density = dist.pdf(mode);
+    if density > 0.0 && density.is_finite() {
+        (mode, 1.0 / density)
+    } else {
+        (mode, 1.0)
+    }
+}
+
+fn check_params(a: f64, b: f64, tolerance: f64) -> Result<()> {
+    if a.is_nan() || b.is_nan() {
+        Err(StatsError::BadParams)
+    } else if tolerance >= 0.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgNotNegative("tolerance"))
+    }
+}
+
+/// Integrates `f` from `a` to `b` by orienting the interval and mapping it
+/// onto a finite one, on which it is integrated by `rule`
+fn integrate_mapped<F, R>(f: &F, a: f64, b: f64, rule: R) -> Result<Integral>
+where
+    F: Fn(f64) -> f64,
+    R: Fn(&dyn Fn(f64) -> f64, f64, f64) -> Result<Integral>,
+{
+    if a == b {
+        return Ok(Integral {
+            value: 0.0,
+            error: 0.0,
+        });
+    }
+    if a > b {
+        let result = integrate_mapped(f, b, a, rule)?;
+        return Ok(Integral {
+            value: -result.value,
+            error: result.error,
+        });
+    }
+    // a node mapped beyond the largest float lies where an integrable f
+    // vanishes, so it contributes nothing
+    let at = |x: f64, jacobian: f64| {
+        if x.is_infinite() || jacobian.is_infinite() {
+            0.0
+        } else {
+            f(x) * jacobian
+        }
+    };
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => rule(f, a, b),
+        (true, false) => rule(
+            &|t| at(a + t / (1.0 - t), 1.0 / ((1.0 - t) * (1.0 - t))),
+            0.0,
+            1.0,
+        ),
+        (false, true) => rule(
+            &|t| at(b - t / (1.0 - t), 1.0 / ((1.0 - t) * (1.0 - t))),
+            0.0,
+            1.0,
+        ),
+        (false, false) => rule(
+            &|t| {
+                let s = 1.0 - t * t;
+                at(t / s, (1.0 + t * t) / (s * s))
+            },
+            -1.0,
+            1.0,
+        ),
+    }
+}
+
+/// A subinterval of an adaptive Gauss-Kronrod integration
+struct Segment {
+    low: f64,
+    high: f64,
+    value: f64,
+    error: f64,
+    abs_value: f64,
+}
+
+fn adaptive_gauss_kronrod(
+    f: &dyn Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_subdivisions: usize,
+) -> Result<Integral> {
+    let mut segments = vec![kronrod_segment(f, a, b)?];
+    loop {
+        let value: f64 = segments.iter().map(|s| s.value).sum();
+        let error: f64 = segments.iter().map(|s| s.error).sum();
+        let abs_value: f64 = segments.iter().map(|s| s.abs_value).sum();
+        // a zero error estimate on the whole interval may only mean that
+        // every node missed the mass of the integrand, so it is split once
+        let unsplit = segments.len() == 1;
+        if (error <= tolerance.max(ROUNDOFF) * abs_value || error == 0.0)
+            && !(unsplit && error == 0.0)
+        {
+            return Ok(Integral { value, error });
+        }
+        if segments.len() >= max_subdivisions {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        let worst = (0..segments.len())
+            .max_by(|&i, &j| segments[i].error.partial_cmp(&segments[j].error).unwrap())
+            .unwrap();
+        let Segment { low, high, .. } = segments.swap_remove(worst);
+        let mid = 0.5 * (low + high);
+        if mid <= low || mid >= high {
+            // the subinterval cannot be split any further
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        segments.push(kronrod_segment(f, low, mid)?);
+        segments.push(kronrod_segment(f, mid, high)?);
+    }
+}
+
+/// Applies the G7K15 rule to `f` on `[low, high]`, estimating the error as
+/// in QUADPACK's `qk15`
+fn kronrod_segment(f: &dyn Fn(f64) -> f64, low: f64, high: f64) -> Result<Segment> {
+    let center = 0.5 * (low + high);
+    let half = 0.5 * (high - low);
+    let mut values = [0.0; 15];
+    values[7] = f(center);
+    for i in 0..7 {
+        values[i] = f(center - half * KRONROD_NODES[i]);
+        values[14 - i] = f(center + half * KRONROD_NODES[i]);
+    }
+    if values.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError::ComputationFailedToConverge);
+    }
+
+    let mut kronrod = KRONROD_WEIGHTS[7] * values[7];
+    let mut gauss = GAUSS_WEIGHTS[3] * values[7];
+    let mut abs_kronrod = KRONROD_WEIGHTS[7] * values[7].abs();
+    for i in 0..7 {
+        let pair = values[i] + values[14 - i];
+        kronrod += KRONROD_WEIGHTS[i] * pair;
+        abs_kronrod += KRONROD_WEIGHTS[i] * (values[i].abs() + values[14 - i].abs());
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * pair;
+        }
+    }
+    let mean = 0.5 * kronrod;
+    let mut deviation = KRONROD_WEIGHTS[7] * (values[7] - mean).abs();
+    for i in 0..7 {
+        deviation +=
+            KRONROD_WEIGHTS[i] * ((values[i] - mean).abs() + (values[14 - i] - mean).abs());
+    }
+
+    let value = kronrod * half;
+    let abs_value = abs_kronrod * half.abs();
+    let deviation = deviation * half.abs();
+    let mut error = ((kronrod - gauss) * half).abs();
+    if deviation != 0.0 && error != 0.0 {
+        error = deviation * (200.0 * error / deviation).powf(1.5).min(1.0);
+    }
+    if abs_value > f64::MIN_POSITIVE / ROUNDOFF {
+        error = error.max(ROUNDOFF * abs_value);
+    }
+    Ok(Segment {
+        low,
+        high,
+        value,
+        error,
+        abs_value,
+    })
+}
+
+fn tanh_sinh_finite(
+    f: &dyn Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_levels: usize,
+) -> Result<Integral> {
+    let center = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+    // adds the contributions of the nodes at ±t, computing their distance
+    // from the nearer end directly to keep full relative precision there
+    let pair = |t: f64| -> Result<(f64, f64)> {
+        let u = f64::consts::FRAC_PI_2 * t.sinh();
+        let e = (-2.0 * u).exp();
+        let distance = half * 2.0 * e / (1.0 + e);
+        let weight = half * f64::consts::FRAC_PI_2 * t.cosh() * 4.0 * e / ((1.0 + e) * (1.0 + e));
+        let mut value = 0.0;
+        let mut abs_value = 0.0;
+        let nodes: &[f64] = if t == 0.0 {
+            &[center]
+        } else {
+            &[a + distance, b - distance]
+        };
+        for &x in nodes {
+            // nodes which round onto an end of the interval are dropped
+            if weight == 0.0 || x <= a || x >= b {
+                continue;
+            }
+            let y = f(x);
+            if !y.is_finite() {
+                return Err(StatsError::ComputationFailedToConverge);
+            }
+            value += weight * y;
+            abs_value += weight * y.abs();
+        }
+        Ok((value, abs_value))
+    };
+
+    // level 0 uses the step size 1 and every later level halves it, adding
+    // only the new nodes in between the existing ones
+    let mut h = 1.0;
+    let (mut sum, mut abs_sum) = pair(0.0)?;
+    let mut t = h;
+    while t <= TANH_SINH_MAX_T {
+        let (value, abs_value) = pair(t)?;
+        sum += value;
+        abs_sum += abs_value;
+        t += h;
+    }
+    let mut estimate = h * sum;
+    for _ in 0..max_levels {
+        h *= 0.5;
+        let mut t = h;
+        while t <= TANH_SINH_MAX_T {
+            let (value, abs_value) = pair(t)?;
+            sum += value;
+            abs_sum += abs_value;
+            t += 2.0 * h;
+        }
+        let previous = estimate;
+        estimate = h * sum;
+        let error = (estimate - previous).abs();
+        if error <= tolerance * h * abs_sum {
+            return Ok(Integral {
+                value: estimate,
+                error,
+            });
+        }
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{
+        Beta, Exp, Gamma, NoncentralChiSquared, NoncentralStudentsT, Normal, StudentsT, Uniform,
+    };
+
+    // a normal density whose log density overflows far from its mode, as
+    // that of the noncentral chi-squared distribution once did
+    struct OverflowingLnPdf(Normal);
+
+    impl Min<f64> for OverflowingLnPdf {
+        fn min(&self) -> f64 {
+            0.0
+        }
+    }
+
+    impl Max<f64> for OverflowingLnPdf {
+        fn max(&self) -> f64 {
+            f64::INFINITY
+        }
+    }
+
+    impl Continuous<f64, f64> for OverflowingLnPdf {
+        fn pdf(&self, x: f64) -> f64 {
+            2.0 * self.0.pdf(x)
+        }
+
+        fn ln_pdf(&self, x: f64) -> f64 {
+            if x > 1e15 {
+                f64::INFINITY
+            } else {
+                f64::consts::LN_2 + self.0.ln_pdf(x)
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_kronrod() {
+        let result = gauss_kronrod(|x| x.exp(), 0.0, 1.0, 1e-14, 100).unwrap();
+        assert_almost_eq!(result.value, f64::consts::E - 1.0, 1e-14);
+        assert!(result.error < 1e-13);
+        // reversed limits change the sign
+        let result = gauss_kronrod(|x| x.exp(), 1.0, 0.0, 1e-14, 100).unwrap();
+        assert_almost_eq!(result.value, 1.0 - f64::consts::E, 1e-14);
+        assert_eq!(gauss_kronrod(|x| x, 2.0, 2.0, 1e-14, 100).unwrap().value, 0.0);
+        // an integrand which needs subdivision
+        let result = gauss_kronrod(|x| (1.0 / x).sin() / (x * x), 0.1, 1.0, 1e-12, 1000).unwrap();
+        assert_almost_eq!(result.value, 1f64.cos() - 10f64.cos(), 1e-11);
+        // an odd integrand cancels to zero
+        let result = gauss_kronrod(|x| x * x * x, -1.0, 1.0, 1e-14, 100).unwrap();
+        assert_almost_eq!(result.value, 0.0, 1e-15);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_infinite() {
+        let sqrt_pi = f64::consts::PI.sqrt();
+        let result = gauss_kronrod(|x| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY, 1e-12, 100).unwrap();
+        assert_almost_eq!(result.value, sqrt_pi, 1e-12);
+        let result = gauss_kronrod(|x| (-x * x).exp(), f64::NEG_INFINITY, 0.0, 1e-12, 100).unwrap();
+        assert_almost_eq!(result.value, sqrt_pi / 2.0, 1e-12);
+        let result = gauss_kronrod(|x| 1.0 / (x * x), 1.0, f64::INFINITY, 1e-12, 100).unwrap();
+        assert_almost_eq!(result.value, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_failure() {
+        assert!(gauss_kronrod(|x| 1.0 / x, 0.0, 1.0, 1e-10, 50).is_err());
+        assert!(gauss_kronrod(|x| (1.0 / x).sin(), 0.0, 1.0, 1e-14, 10).is_err());
+        assert!(gauss_kronrod(|_| f64::NAN, 0.0, 1.0, 1e-10, 50).is_err());
+        assert!(gauss_kronrod(|x| x, 0.0, 1.0, -1.0, 50).is_err());
+        assert!(gauss_kronrod(|x| x, f64::NAN, 1.0, 1e-10, 50).is_err());
+    }
+
+    #[test]
+    fn test_tanh_sinh() {
+        let result = tanh_sinh(|x| x.exp(), 0.0, 1.0, 1e-14, 10).unwrap();
+        assert_almost_eq!(result.value, f64::consts::E - 1.0, 1e-14);
+        let result = tanh_sinh(|x| x.ln(), 0.0, 1.0, 1e-12, 10).unwrap();
+        assert_almost_eq!(result.value, -1.0, 1e-12);
+        let result = tanh_sinh(|x| 1.0 / x.sqrt(), 0.0, 4.0, 1e-12, 10).unwrap();
+        assert_almost_eq!(result.value, 4.0, 1e-12);
+        let result = tanh_sinh(|x| 1.0 / x.sqrt(), 4.0, 0.0, 1e-12, 10).unwrap();
+        assert_almost_eq!(result.value, -4.0, 1e-12);
+    }
+
+    #[test]
+    fn test_tanh_sinh_infinite() {
+        let result = tanh_sinh(|x| (-x).exp(), 0.0, f64::INFINITY, 1e-10, 10).unwrap();
+        assert_almost_eq!(result.value, 1.0, 1e-10);
+        let result = tanh_sinh(|x| 1.0 / (1.0 + x * x), f64::NEG_INFINITY, f64::INFINITY, 1e-10, 10).unwrap();
+        assert_almost_eq!(result.value, f64::consts::PI, 1e-10);
+    }
+
+    #[test]
+    fn test_tanh_sinh_failure() {
+        assert!(tanh_sinh(|x| (1.0 / x).sin(), 0.0, 1.0, 1e-14, 3).is_err());
+        assert!(tanh_sinh(|_| f64::NAN, 0.0, 1.0, 1e-10, 10).is_err());
+        assert!(tanh_sinh(|x| x, 0.0, 1.0, f64::NAN, 10).is_err());
+    }
+
+    #[test]
+    fn test_expectation() {
+        let n = Normal::new(1.0, 2.0).unwrap();
+        assert_almost_eq!(expectation(&n, |_| 1.0).unwrap(), 1.0, 1e-10);
+        assert_almost_eq!(expectation(&n, |x| x).unwrap(), 1.0, 1e-10);
+        assert_almost_eq!(expectation(&n, |x| x * x).unwrap(), 5.0, 1e-9);
+        // a narrow peak far from the origin
+        let n = Normal::new(1000.0, 0.01).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x).unwrap(), 1000.0, 1e-7);
+        let n = Gamma::new(0.5, 2.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x).unwrap(), 0.25, 1e-10);
+        let n = Exp::new(3.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x * x).unwrap(), 2.0 / 9.0, 1e-10);
+        let n = Beta::new(2.0, 3.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x).unwrap(), 0.4, 1e-10);
+        let n = Beta::new(10000.0, 10.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x).unwrap(), 10000.0 / 10010.0, 1e-10);
+        let n = Uniform::new(-1.0, 3.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x * x).unwrap(), 7.0 / 3.0, 1e-10);
+        let n = StudentsT::new(0.0, 1.0, 5.0).unwrap();
+        assert_almost_eq!(expectation(&n, |x| x * x).unwrap(), 5.0 / 3.0, 1e-9);
+    }
+
+    #[test]
+    fn test_expectation_densities_integrate_to_one() {
+        for &(freedom, noncentrality) in &[(3.0, 4.0), (1.0, 1.0), (10.0, 50.0), (0.5, 0.1)] {
+            let n = NoncentralChiSquared::new(freedom, noncentrality).unwrap();
+            assert_almost_eq!(expectation(&n, |_| 1.0).unwrap(), 1.0, 1e-10);
+        }
+        let n = NoncentralStudentsT::new(3.0, 2.0).unwrap();
+        assert_almost_eq!(expectation(&n, |_| 1.0).unwrap(), 1.0, 1e-10);
+        // a log density of +inf where the density is zero is not a mode
+        let n = OverflowingLnPdf(Normal::new(0.0, 1.0).unwrap());
+        assert_almost_eq!(expectation(&n, |_| 1.0).unwrap(), 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_zero_error_on_whole_interval() {
+        // all 15 nodes miss the narrow peak, so the zero error estimate of
+        // the unsplit interval must not be accepted
+        let peak = |x: f64| (-1e6 * (x - 0.75).powi(2)).exp();
+        let result = gauss_kronrod(peak, 0.0, 1.0, 1e-12, 1000).unwrap();
+        assert_almost_eq!(result.value, (f64::consts::PI / 1e6).sqrt(), 1e-12);
+    }
+
+    #[test]
+    fn test_expectation_does_not_exist() {
+        let n = StudentsT::new(0.0, 1.0, 1.5).unwrap();
+        assert!(expectation(&n, |x| x * x).is_err());
+    }
+}
@@ -0,0 +1,533 @@
+//! Provides numerical root finding and one-dimensional minimization
+//!
+//! Every method works on a bracketing interval and takes an absolute
+//! `tolerance` on the location of the solution together with a limit on the
+//! number of iterations. The root finders always also stop once the solution
+//! is known to near machine precision, so a `tolerance` of `0.0` requests
+//! full precision. The minimizers cannot locate a minimum more precisely than
+//! about the square root of the machine epsilon relative to its location.
+//!
+//! # Examples
+//!
+//! ```
+//! use statrs::solve;
+//!
+//! let root = solve::brent(|x| x * x - 2.0, 0.0, 2.0, 0.0, 100).unwrap();
+//! assert!((root - 2f64.sqrt()).abs() < 1e-15);
+//!
+//! let min = solve::brent_min(|x| (x - 1.0).powi(2), -3.0, 3.0, 1e-10, 100).unwrap();
+//! assert!((min - 1.0).abs() < 1e-8);
+//! ```
+
+use crate::{Result, StatsError};
+use std::f64;
+
+/// The golden ratio conjugate `(3 - sqrt(5)) / 2`, the fraction of an
+/// interval at which golden-section search places its interior points
+const GOLDEN_SECTION: f64 = 0.381_966_011_250_105_1;
+
+/// A limit on the number of iterations that suits most problems, used by
+/// the solvers within this crate. The root finders need about 60 bisections
+/// at worst to reduce a bracket to machine precision when the bracket is no
+/// wider than the magnitude of the root, leaving room for the interpolation
+/// steps in between.
+pub const DEFAULT_MAX_ITERATIONS: usize = 200;
+
+/// Finds a root of `f` between `a` and `b`, where `f` changes sign, with
+/// Brent's method of inverse quadratic interpolation safeguarded by bisection
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`.
+/// Returns `StatsError::ComputationFailedToConverge` if `f(a)` and `f(b)`
+/// have the same sign or either is `NaN`, or if the root is not found
+/// within `max_iterations` iterations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::solve;
+///
+/// let root = solve::brent(|x| x.cos() - x, 0.0, 1.0, 1e-12, 100).unwrap();
+/// assert!((root - 0.7390851332151607).abs() < 1e-12);
+/// ```
+pub fn brent<F>(f: F, a: f64, b: f64, tolerance: f64, max_iterations: usize) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    check_tolerance(tolerance)?;
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() || fa.is_nan() || fb.is_nan() {
+        return Err(StatsError::ComputationFailedToConverge);
+    }
+    let (mut c, mut fc) = (a, fa);
+    let (mut d, mut e) = (b - a, b - a);
+    for _ in 0..max_iterations {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = root_tolerance(b, tolerance);
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol || fb == 0.0 {
+            return Ok(b);
+        }
+        if e.abs() < tol || fa.abs() <= fb.abs() {
+            d = m;
+            e = m;
+        } else {
+            // inverse quadratic interpolation, or the secant method when
+            // only two points are distinct
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2.0 * p < (3.0 * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        }
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(m) };
+        fb = f(b);
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// Finds a root of `f` between `a` and `b`, where `f` changes sign, with
+/// Newton's method using the derivative `df`, falling back to bisection
+/// whenever a Newton step would leave the bracket or fails to shrink it
+/// quickly enough
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`.
+/// Returns `StatsError::ComputationFailedToConverge` if `f(a)` and `f(b)`
+/// have the same sign or either is `NaN`, or if the root is not found
+/// within `max_iterations` iterations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::solve;
+///
+/// let root = solve::newton(|x| x * x - 2.0, |x| 2.0 * x, 0.0, 2.0, 0.0, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-15);
+/// ```
+pub fn newton<F, D>(
+    f: F,
+    df: D,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    safeguarded(f, |x, fx| fx / df(x), a, b, tolerance, max_iterations)
+}
+
+/// Finds a root of `f` between `a` and `b`, where `f` changes sign, with
+/// Halley's method using the first and second derivatives `df` and `d2f`,
+/// falling back to bisection whenever a Halley step would leave the bracket
+/// or fails to shrink it quickly enough
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`.
+/// Returns `StatsError::ComputationFailedToConverge` if `f(a)` and `f(b)`
+/// have the same sign or either is `NaN`, or if the root is not found
+/// within `max_iterations` iterations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::solve;
+///
+/// let root = solve::halley(
+///     |x| x.exp() - 2.0,
+///     |x| x.exp(),
+///     |x| x.exp(),
+///     0.0,
+///     1.0,
+///     0.0,
+///     100,
+/// )
+/// .unwrap();
+/// assert!((root - 2f64.ln()).abs() < 1e-15);
+/// ```
+pub fn halley<F, D, D2>(
+    f: F,
+    df: D,
+    d2f: D2,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+    D2: Fn(f64) -> f64,
+{
+    safeguarded(
+        f,
+        |x, fx| {
+            let dfx = df(x);
+            2.0 * fx * dfx / (2.0 * dfx * dfx - fx * d2f(x))
+        },
+        a,
+        b,
+        tolerance,
+        max_iterations,
+    )
+}
+
+/// Finds a minimum of `f` between `a` and `b` by golden-section search,
+/// which converges to a local minimum of any unimodal `f`
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`.
+/// Returns `StatsError::ComputationFailedToConverge` if the minimum is not
+/// located within `max_iterations` iterations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::solve;
+///
+/// let min = solve::golden_section(|x| x.cosh(), -1.0, 2.0, 1e-6, 100).unwrap();
+/// assert!(min.abs() < 1e-6);
+/// ```
+pub fn golden_section<F>(f: F, a: f64, b: f64, tolerance: f64, max_iterations: usize) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    check_tolerance(tolerance)?;
+    let (mut a, mut b) = if a <= b { (a, b) } else { (b, a) };
+    let mut x1 = a + GOLDEN_SECTION * (b - a);
+    let mut x2 = b - GOLDEN_SECTION * (b - a);
+    let (mut f1, mut f2) = (f(x1), f(x2));
+    for _ in 0..max_iterations {
+        let x = if f1 <= f2 { x1 } else { x2 };
+        if b - a <= 2.0 * min_tolerance(x, tolerance) {
+            return Ok(x);
+        }
+        if f1 <= f2 {
+            b = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = a + GOLDEN_SECTION * (b - a);
+            f1 = f(x1);
+        } else {
+            a = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = b - GOLDEN_SECTION * (b - a);
+            f2 = f(x2);
+        }
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// Finds a minimum of `f` between `a` and `b` with Brent's method, which
+/// combines successive parabolic interpolation with golden-section search
+/// and converges superlinearly for smooth `f`
+///
+/// # Errors
+///
+/// Returns `StatsError::ArgNotNegative` if `tolerance` is negative or `NaN`.
+/// Returns `StatsError::ComputationFailedToConverge` if the minimum is not
+/// located within `max_iterations` iterations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::solve;
+///
+/// let min = solve::brent_min(|x| x * x.ln(), 0.0, 1.0, 1e-12, 100).unwrap();
+/// assert!((min - (-1f64).exp()).abs() < 1e-8);
+/// ```
+pub fn brent_min<F>(f: F, a: f64, b: f64, tolerance: f64, max_iterations: usize) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    check_tolerance(tolerance)?;
+    let (mut a, mut b) = if a <= b { (a, b) } else { (b, a) };
+    // x is the best point so far, w the second best and v the previous
+    // value of w
+    let mut x = a + GOLDEN_SECTION * (b - a);
+    let (mut w, mut v) = (x, x);
+    let fx0 = f(x);
+    let (mut fx, mut fw, mut fv) = (fx0, fx0, fx0);
+    let (mut d, mut e): (f64, f64) = (0.0, 0.0);
+    for _ in 0..max_iterations {
+        let m = 0.5 * (a + b);
+        let tol = min_tolerance(x, tolerance);
+        let t2 = 2.0 * tol;
+        if (x - m).abs() <= t2 - 0.5 * (b - a) {
+            return Ok(x);
+        }
+        let mut golden = true;
+        if e.abs() > tol {
+            // fit a parabola through x, w and v
+            let r = (x - w) * (fx - fv);
+            let mut q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            } else {
+                q = -q;
+            }
+            let r = e;
+            e = d;
+            if p.abs() < (0.5 * q * r).abs() && p > q * (a - x) && p < q * (b - x) {
+                d = p / q;
+                let u = x + d;
+                if u - a < t2 || b - u < t2 {
+                    d = tol.copysign(m - x);
+                }
+                golden = false;
+            }
+        }
+        if golden {
+            e = if x < m { b - x } else { a - x };
+            d = GOLDEN_SECTION * e;
+        }
+        let u = if d.abs() >= tol {
+            x + d
+        } else {
+            x + tol.copysign(d)
+        };
+        let fu = f(u);
+        if fu <= fx {
+            if u < x {
+                b = x;
+            } else {
+                a = x;
+            }
+            v = w;
+            fv = fw;
+            w = x;
+            fw = fx;
+            x = u;
+            fx = fu;
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                v = w;
+                fv = fw;
+                w = u;
+                fw = fu;
+            } else if fu <= fv || v == x || v == w {
+                v = u;
+                fv = fu;
+            }
+        }
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// Iterates `x -= step(x, f(x))` on a bracket of a root of `f`, bisecting
+/// instead whenever the step would leave the bracket or the previous step
+/// did not halve the one before it
+fn safeguarded<F, S>(
+    f: F,
+    step: S,
+    a: f64,
+    b: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<f64>
+where
+    F: Fn(f64) -> f64,
+    S: Fn(f64, f64) -> f64,
+{
+    check_tolerance(tolerance)?;
+    let (fa, fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() || fa.is_nan() || fb.is_nan() {
+        return Err(StatsError::ComputationFailedToConverge);
+    }
+    // orient the bracket so that f(low) < 0 < f(high)
+    let (mut low, mut high) = if fa < 0.0 { (a, b) } else { (b, a) };
+    let mut x = 0.5 * (a + b);
+    let mut prev_delta = (b - a).abs();
+    let mut fx = f(x);
+    for _ in 0..max_iterations {
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        if fx < 0.0 {
+            low = x;
+        } else {
+            high = x;
+        }
+        let proposed = x - step(x, fx);
+        let in_bracket = proposed > low.min(high) && proposed < low.max(high);
+        let delta = if in_bracket && 2.0 * (proposed - x).abs() <= prev_delta {
+            proposed - x
+        } else {
+            0.5 * (high + low) - x
+        };
+        prev_delta = delta.abs();
+        x += delta;
+        let tol = root_tolerance(x, tolerance);
+        if delta.abs() <= tol || (high - low).abs() <= 2.0 * tol {
+            return Ok(x);
+        }
+        fx = f(x);
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// The tolerance of the root finders at `x`, at least about twice the
+/// machine precision relative to `x`
+fn root_tolerance(x: f64, tolerance: f64) -> f64 {
+    2.0 * f64::EPSILON * x.abs() + 0.5 * tolerance + f64::MIN_POSITIVE
+}
+
+/// The tolerance of the minimizers at `x`, at least about the square root
+/// of the machine precision relative to `x`
+fn min_tolerance(x: f64, tolerance: f64) -> f64 {
+    f64::EPSILON.sqrt() * x.abs() + tolerance / 3.0 + f64::MIN_POSITIVE
+}
+
+fn check_tolerance(tolerance: f64) -> Result<()> {
+    if tolerance >= 0.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgNotNegative("tolerance"))
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_brent() {
+        let root = brent(|x| x * x - 2.0, 0.0, 2.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, f64::consts::SQRT_2, 1e-15);
+        let root = brent(|x| x.cos() - x, 1.0, 0.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, 0.7390851332151607, 1e-15);
+        assert_eq!(brent(|x| x - 1.0, 1.0, 3.0, 0.0, 100).unwrap(), 1.0);
+        let root = brent(|x| x * x - 2.0, 0.0, 2.0, 1e-3, 100).unwrap();
+        assert!((root - f64::consts::SQRT_2).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_brent_failure() {
+        assert!(brent(|x| x * x + 1.0, -1.0, 1.0, 0.0, 100).is_err());
+        assert!(brent(|_| f64::NAN, -1.0, 1.0, 0.0, 100).is_err());
+        assert!(brent(|x| x.cos() - x, 0.0, 1.0, 0.0, 2).is_err());
+        assert!(brent(|x| x, -1.0, 1.0, -1.0, 100).is_err());
+        assert!(brent(|x| x, -1.0, 1.0, f64::NAN, 100).is_err());
+    }
+
+    #[test]
+    fn test_newton() {
+        let root = newton(|x| x * x - 2.0, |x| 2.0 * x, 0.0, 2.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, f64::consts::SQRT_2, 1e-15);
+        let root = newton(|x| x.cos() - x, |x| -x.sin() - 1.0, 1.0, 0.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, 0.7390851332151607, 1e-15);
+        // the derivative vanishes at the root, so Newton alone converges slowly
+        let cube = |x: f64| (x - 1.0) * (x - 1.0) * (x - 1.0);
+        let root = newton(cube, |x| 3.0 * (x - 1.0) * (x - 1.0), 0.0, 3.0, 0.0, 200).unwrap();
+        assert_almost_eq!(root, 1.0, 1e-15);
+        // the Newton step from the midpoint overshoots the bracket
+        let root = newton(|x| x.atan(), |x| 1.0 / (1.0 + x * x), -3.0, 20.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, 0.0, 1e-15);
+    }
+
+    #[test]
+    fn test_newton_failure() {
+        assert!(newton(|x| x * x + 1.0, |x| 2.0 * x, -1.0, 1.0, 0.0, 100).is_err());
+        assert!(newton(|_| f64::NAN, |_| 1.0, -1.0, 1.0, 0.0, 100).is_err());
+        assert!(newton(|x| x * x - 2.0, |x| 2.0 * x, 0.0, 2.0, 0.0, 1).is_err());
+        assert!(newton(|x| x, |_| 1.0, -1.0, 1.0, -1.0, 100).is_err());
+    }
+
+    #[test]
+    fn test_halley() {
+        let root = halley(|x| x * x - 2.0, |x| 2.0 * x, |_| 2.0, 0.0, 2.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, f64::consts::SQRT_2, 1e-15);
+        let root = halley(|x| x.exp() - 2.0, |x| x.exp(), |x| x.exp(), -5.0, 5.0, 0.0, 100).unwrap();
+        assert_almost_eq!(root, f64::consts::LN_2, 1e-15);
+        assert!(halley(|x| x * x + 1.0, |x| 2.0 * x, |_| 2.0, -1.0, 1.0, 0.0, 100).is_err());
+    }
+
+    #[test]
+    fn test_golden_section() {
+        let min = golden_section(|x| (x - 1.0) * (x - 1.0), -3.0, 3.0, 0.0, 200).unwrap();
+        assert_almost_eq!(min, 1.0, 1e-7);
+        let min = golden_section(|x| x.abs(), 2.0, -1.0, 1e-6, 200).unwrap();
+        assert_almost_eq!(min, 0.0, 1e-6);
+        // the minimum of a monotone function is at the end of the interval
+        let min = golden_section(|x| x, 0.0, 1.0, 1e-6, 200).unwrap();
+        assert_almost_eq!(min, 0.0, 1e-6);
+        assert!(golden_section(|x| x * x, -1.0, 1.0, 0.0, 3).is_err());
+        assert!(golden_section(|x| x * x, -1.0, 1.0, -1.0, 100).is_err());
+    }
+
+    #[test]
+    fn test_brent_min() {
+        let min = brent_min(|x| (x - 1.0) * (x - 1.0), -3.0, 3.0, 0.0, 100).unwrap();
+        assert_almost_eq!(min, 1.0, 1e-7);
+        let min = brent_min(|x| x * x.ln(), 0.0, 1.0, 0.0, 100).unwrap();
+        assert_almost_eq!(min, (-1f64).exp(), 1e-7);
+        let min = brent_min(|x| x.abs(), 2.0, -1.0, 1e-6, 100).unwrap();
+        assert_almost_eq!(min, 0.0, 1e-6);
+        let min = brent_min(|x| -x.sin(), 0.0, 3.0, 1e-10, 100).unwrap();
+        assert_almost_eq!(min, f64::consts::FRAC_PI_2, 1e-7);
+        assert!(brent_min(|x| x * x.ln(), 0.0, 1.0, 0.0, 3).is_err());
+        assert!(brent_min(|x| x * x, -1.0, 1.0, f64::NAN, 100).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::StatsError;
 use crate::statistics::*;
+use crate::Result;
 use std::borrow::Borrow;
 use std::f64;
 
@@ -175,7 +176,7 @@ where
         for x in self {
             let borrow = *x.borrow();
             let borrow2 = match iter.next() {
-                None => panic!(format!("{}", StatsError::ContainersMustBeSameLength)),
+                None => panic!("{}", StatsError::ContainersMustBeSameLength),
                 Some(x) => *x.borrow(),
             };
             let old_mean2 = mean2;
@@ -185,7 +186,7 @@ where
             comoment += (borrow - mean1) * (borrow2 - old_mean2);
         }
         if iter.next().is_some() {
-            panic!(format!("{}", StatsError::ContainersMustBeSameLength));
+            panic!("{}", StatsError::ContainersMustBeSameLength);
         }
 
         if n > 1.0 {
@@ -205,7 +206,7 @@ where
         for x in self {
             let borrow = *x.borrow();
             let borrow2 = match iter.next() {
-                None => panic!(format!("{}", StatsError::ContainersMustBeSameLength)),
+                None => panic!("{}", StatsError::ContainersMustBeSameLength),
                 Some(x) => *x.borrow(),
             };
             let old_mean2 = mean2;
@@ -215,7 +216,7 @@ where
             comoment += (borrow - mean1) * (borrow2 - old_mean2);
         }
         if iter.next().is_some() {
-            panic!(format!("{}", StatsError::ContainersMustBeSameLength));
+            panic!("{}", StatsError::ContainersMustBeSameLength);
         }
         if n > 0.0 {
             comoment / n
@@ -240,6 +241,194 @@ where
     }
 }
 
+impl<T> WeightedStatistics<f64> for T
+where
+    T: IntoIterator,
+    T::Item: Borrow<(f64, f64)>,
+{
+    fn weighted_mean(self) -> Result<f64> {
+        let moments = WeightedMoments::new(self)?;
+        if moments.sum_w > 0.0 {
+            Ok(moments.mean)
+        } else {
+            Ok(f64::NAN)
+        }
+    }
+
+    fn weighted_variance(self, kind: WeightKind) -> Result<f64> {
+        let moments = WeightedMoments::new(self)?;
+        Ok(moments.m2 / weighted_normalizer(moments.sum_w, moments.sum_w2, kind))
+    }
+
+    fn weighted_std_dev(self, kind: WeightKind) -> Result<f64> {
+        self.weighted_variance(kind).map(f64::sqrt)
+    }
+
+    fn weighted_population_variance(self) -> Result<f64> {
+        let moments = WeightedMoments::new(self)?;
+        if moments.sum_w > 0.0 {
+            Ok(moments.m2 / moments.sum_w)
+        } else {
+            Ok(f64::NAN)
+        }
+    }
+
+    fn weighted_quantile(self, tau: f64) -> Result<f64> {
+        let mut pairs = Vec::new();
+        let mut sum_w = 0.0;
+        for pair in self {
+            let (x, w) = *pair.borrow();
+            check_weight(w)?;
+            if w > 0.0 {
+                sum_w += w;
+                pairs.push((x, w));
+            }
+        }
+        if !(0.0..=1.0).contains(&tau) || pairs.is_empty() || pairs.iter().any(|p| p.0.is_nan()) {
+            return Ok(f64::NAN);
+        }
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        // the cumulative weight is compared against the target with a
+        // tolerance so that shares landing exactly on a step are detected
+        // despite rounding
+        let target = tau * sum_w;
+        let fuzz = 4.0 * f64::EPSILON * sum_w;
+        let mut cumulative = 0.0;
+        for (i, &(x, w)) in pairs.iter().enumerate() {
+            cumulative += w;
+            if cumulative >= target - fuzz {
+                if (cumulative - target).abs() <= fuzz && i + 1 < pairs.len() {
+                    return Ok((x + pairs[i + 1].0) / 2.0);
+                }
+                return Ok(x);
+            }
+        }
+        Ok(pairs[pairs.len() - 1].0)
+    }
+
+    fn weighted_median(self) -> Result<f64> {
+        self.weighted_quantile(0.5)
+    }
+}
+
+// Running weighted mean and sum of squared deviations, using West's
+// (1979) weighted generalization of Welford's algorithm
+struct WeightedMoments {
+    sum_w: f64,
+    sum_w2: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl WeightedMoments {
+    fn new<T>(pairs: T) -> Result<WeightedMoments>
+    where
+        T: IntoIterator,
+        T::Item: Borrow<(f64, f64)>,
+    {
+        let mut moments = WeightedMoments {
+            sum_w: 0.0,
+            sum_w2: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+        };
+        for pair in pairs {
+            let (x, w) = *pair.borrow();
+            check_weight(w)?;
+            if w == 0.0 {
+                continue;
+            }
+            let old_mean = moments.mean;
+            moments.sum_w += w;
+            moments.sum_w2 += w * w;
+            moments.mean += w / moments.sum_w * (x - old_mean);
+            moments.m2 += w * (x - old_mean) * (x - moments.mean);
+        }
+        Ok(moments)
+    }
+}
+
+fn check_weight(w: f64) -> Result<()> {
+    if w.is_nan() {
+        Err(StatsError::BadParams)
+    } else if w < 0.0 {
+        Err(StatsError::ArgNotNegative("weights"))
+    } else {
+        Ok(())
+    }
+}
+
+// Returns the divisor of the weighted sum of squared deviations for the
+// unbiased estimators, `f64::NAN` if it is not positive
+fn weighted_normalizer(sum_w: f64, sum_w2: f64, kind: WeightKind) -> f64 {
+    let norm = match kind {
+        WeightKind::Frequency => sum_w - 1.0,
+        WeightKind::Reliability => sum_w - sum_w2 / sum_w,
+    };
+    if norm > 0.0 {
+        norm
+    } else {
+        f64::NAN
+    }
+}
+
+// Evaluates `Statistics::weighted_covariance`
+pub(crate) fn weighted_covariance<T>(data: T, other: T, weights: T, kind: WeightKind) -> Result<f64>
+where
+    T: IntoIterator,
+    T::Item: Borrow<f64>,
+{
+    let mut sum_w = 0.0;
+    let mut sum_w2 = 0.0;
+    let mut mean1 = 0.0;
+    let mut mean2 = 0.0;
+    let mut comoment = 0.0;
+
+    let mut iter = other.into_iter();
+    let mut witer = weights.into_iter();
+    for x in data {
+        let (borrow2, w) = match (iter.next(), witer.next()) {
+            (Some(y), Some(w)) => (*y.borrow(), *w.borrow()),
+            _ => return Err(StatsError::ContainersMustBeSameLength),
+        };
+        let borrow = *x.borrow();
+        check_weight(w)?;
+        if w == 0.0 {
+            continue;
+        }
+        let old_mean2 = mean2;
+        sum_w += w;
+        sum_w2 += w * w;
+        mean1 += w / sum_w * (borrow - mean1);
+        mean2 += w / sum_w * (borrow2 - mean2);
+        comoment += w * (borrow - mean1) * (borrow2 - old_mean2);
+    }
+    if iter.next().is_some() || witer.next().is_some() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    Ok(comoment / weighted_normalizer(sum_w, sum_w2, kind))
+}
+
+pub(crate) fn zip_weights<T>(data: T, weights: T) -> Result<Vec<(f64, f64)>>
+where
+    T: IntoIterator,
+    T::Item: Borrow<f64>,
+{
+    let mut witer = weights.into_iter();
+    let mut pairs = Vec::new();
+    for x in data {
+        match witer.next() {
+            None => return Err(StatsError::ContainersMustBeSameLength),
+            Some(w) => pairs.push((*x.borrow(), *w.borrow())),
+        }
+    }
+    if witer.next().is_some() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    Ok(pairs)
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -248,7 +437,8 @@ mod tests {
     use rand::{SeedableRng};
     use rand::distributions::Distribution;
     use crate::distribution::Normal;
-    use crate::statistics::Statistics;
+    use crate::statistics::{OrderStatistics, QuantileDefinition, Statistics, WeightKind, WeightedStatistics};
+    use crate::StatsError;
     use crate::generate::{InfinitePeriodic, InfiniteSinusoidal};
     use crate::testing;
 
@@ -400,4 +590,103 @@ mod tests {
         let data = InfiniteSinusoidal::default(64.0, 16.0, 2.0).take(128).collect::<Vec<f64>>();
         assert_almost_eq!((&data).quadratic_mean(), 2.0 / consts::SQRT_2, 1e-15);
     }
+
+    #[test]
+    fn test_weighted_unit_weights_consistent_with_unweighted() {
+        for file in &["nist/lottery.txt", "nist/lew.txt", "nist/mavro.txt", "nist/michaelso.txt"] {
+            let data = testing::load_data(file);
+            let ones = vec![1.0; data.len()];
+            assert_almost_eq!((&data).weighted_mean(&ones).unwrap(), (&data).mean(), 1e-10);
+            assert_almost_eq!((&data).weighted_variance(&ones, WeightKind::Frequency).unwrap(), (&data).variance(), 1e-8);
+            assert_almost_eq!((&data).weighted_variance(&ones, WeightKind::Reliability).unwrap(), (&data).variance(), 1e-8);
+            assert_almost_eq!((&data).weighted_population_variance(&ones).unwrap(), (&data).population_variance(), 1e-8);
+            assert_almost_eq!((&data).weighted_covariance(&data, &ones, WeightKind::Frequency).unwrap(), (&data).covariance(&data), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_weighted_frequency_weights_expand_data() {
+        let data = testing::load_data("nist/lew.txt");
+        let weights: Vec<f64> = (0..data.len()).map(|i| (i % 4) as f64).collect();
+        let expanded: Vec<f64> = data.iter().zip(weights.iter())
+            .flat_map(|(&x, &w)| vec![x; w as usize])
+            .collect();
+        assert_almost_eq!((&data).weighted_mean(&weights).unwrap(), (&expanded).mean(), 1e-10);
+        assert_almost_eq!((&data).weighted_variance(&weights, WeightKind::Frequency).unwrap(), (&expanded).variance(), 1e-8);
+        assert_almost_eq!((&data).weighted_population_variance(&weights).unwrap(), (&expanded).population_variance(), 1e-8);
+        let mut sorted = expanded.clone();
+        for &tau in &[0.0, 0.1, 0.25, 0.5, 0.9, 1.0] {
+            assert_eq!((&data).weighted_quantile(&weights, tau).unwrap(), sorted.quantile_custom(tau, QuantileDefinition::R2));
+        }
+    }
+
+    #[test]
+    fn test_weighted_reliability_weights() {
+        // sum w (x - mean)^2 = 1.56, V1 = 1.0, V2 = 0.38
+        let x = [1.0, 2.0, 4.0];
+        let w = [0.2, 0.3, 0.5];
+        assert_almost_eq!((&x).weighted_mean(&w).unwrap(), 2.8, 1e-15);
+        assert_almost_eq!((&x).weighted_variance(&w, WeightKind::Reliability).unwrap(), 1.56 / 0.62, 1e-14);
+        assert!((&x).weighted_variance(&w, WeightKind::Frequency).unwrap().is_nan());
+        assert_almost_eq!((&x).weighted_population_variance(&w).unwrap(), 1.56, 1e-14);
+        let y = [2.0, 1.0, 0.0];
+        assert_almost_eq!((&x).weighted_covariance(&y, &w, WeightKind::Reliability).unwrap(), -0.96 / 0.62, 1e-14);
+    }
+
+    #[test]
+    fn test_weighted_pairs_consistent_with_slices() {
+        let x = [0.0, 3.0, -2.0, 7.5];
+        let w = [2.0, 1.0, 0.5, 3.0];
+        let pairs: Vec<(f64, f64)> = x.iter().cloned().zip(w.iter().cloned()).collect();
+        assert_eq!((&pairs).weighted_mean().unwrap(), (&x).weighted_mean(&w).unwrap());
+        assert_eq!((&pairs).weighted_variance(WeightKind::Frequency).unwrap(), (&x).weighted_variance(&w, WeightKind::Frequency).unwrap());
+        assert_eq!((&pairs).weighted_std_dev(WeightKind::Reliability).unwrap(), (&x).weighted_std_dev(&w, WeightKind::Reliability).unwrap());
+        assert_eq!((&pairs).weighted_population_variance().unwrap(), (&x).weighted_population_variance(&w).unwrap());
+        assert_eq!((&pairs).weighted_median().unwrap(), (&x).weighted_median(&w).unwrap());
+        assert_eq!(pairs.weighted_quantile(0.8).unwrap(), 7.5);
+    }
+
+    #[test]
+    fn test_weighted_invalid_input() {
+        let x = [1.0, 2.0, 3.0];
+        let short = [1.0, 2.0];
+        let long = [1.0, 2.0, 3.0, 4.0];
+        let negative = [1.0, -2.0, 3.0];
+        let nan = [1.0, f64::NAN, 3.0];
+        for w in &[&short[..], &long[..]] {
+            assert!(matches!(x[..].weighted_mean(w), Err(StatsError::ContainersMustBeSameLength)));
+            assert!(matches!(x[..].weighted_variance(w, WeightKind::Frequency), Err(StatsError::ContainersMustBeSameLength)));
+            assert!(matches!(x[..].weighted_covariance(&x[..], w, WeightKind::Frequency), Err(StatsError::ContainersMustBeSameLength)));
+            assert!(matches!(x[..].weighted_covariance(w, &x[..], WeightKind::Frequency), Err(StatsError::ContainersMustBeSameLength)));
+            assert!(matches!(x[..].weighted_quantile(w, 0.5), Err(StatsError::ContainersMustBeSameLength)));
+        }
+        assert!(matches!(x[..].weighted_mean(&negative[..]), Err(StatsError::ArgNotNegative(_))));
+        assert!(matches!(x[..].weighted_covariance(&x[..], &negative[..], WeightKind::Frequency), Err(StatsError::ArgNotNegative(_))));
+        assert!(matches!(x[..].weighted_median(&negative[..]), Err(StatsError::ArgNotNegative(_))));
+        assert!(matches!(x[..].weighted_mean(&nan[..]), Err(StatsError::BadParams)));
+        assert!(matches!(x[..].weighted_covariance(&x[..], &nan[..], WeightKind::Frequency), Err(StatsError::BadParams)));
+        assert!(matches!(x[..].weighted_median(&nan[..]), Err(StatsError::BadParams)));
+        assert!(matches!([(1.0, f64::NAN)].weighted_variance(WeightKind::Reliability), Err(StatsError::BadParams)));
+    }
+
+    #[test]
+    fn test_weighted_degenerate_returns_nan() {
+        let empty = [0.0; 0];
+        assert!(empty.weighted_mean(empty).unwrap().is_nan());
+        assert!(empty.weighted_variance(empty, WeightKind::Frequency).unwrap().is_nan());
+        assert!(empty.weighted_population_variance(empty).unwrap().is_nan());
+        assert!(empty.weighted_median(empty).unwrap().is_nan());
+
+        let x = [1.0, 2.0];
+        let zeros = [0.0, 0.0];
+        assert!((&x).weighted_mean(&zeros).unwrap().is_nan());
+        assert!((&x).weighted_variance(&zeros, WeightKind::Reliability).unwrap().is_nan());
+        assert!((&x).weighted_quantile(&zeros, 0.5).unwrap().is_nan());
+        assert!((&x).weighted_variance(&[0.0, 5.0], WeightKind::Reliability).unwrap().is_nan());
+        assert!((&x).weighted_quantile(&[1.0, 1.0], -0.5).unwrap().is_nan());
+        assert!([1.0, f64::NAN].weighted_mean([1.0, 1.0]).unwrap().is_nan());
+        assert!([1.0, f64::NAN].weighted_median([1.0, 1.0]).unwrap().is_nan());
+        // zero weight entries are ignored entirely
+        assert_eq!([1.0, f64::NAN].weighted_mean([1.0, 0.0]).unwrap(), 1.0);
+    }
 }
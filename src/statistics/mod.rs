@@ -2,12 +2,20 @@
 
 pub use self::iter_statistics::*;
 pub use self::order_statistics::*;
+pub use self::p2_quantile::*;
+pub use self::running_stats::*;
 pub use self::statistics::*;
+pub use self::t_digest::*;
 pub use self::traits::*;
+pub use self::weighted_statistics::*;
 
 mod iter_statistics;
 mod order_statistics;
-// TODO: fix later
-// mod slice_statistics;
+mod p2_quantile;
+mod running_stats;
+mod slice_statistics;
+#[allow(clippy::module_inception)]
 mod statistics;
+mod t_digest;
 mod traits;
+mod weighted_statistics;
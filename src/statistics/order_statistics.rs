@@ -1,4 +1,4 @@
-use super::RankTieBreaker;
+use super::{QuantileDefinition, RankTieBreaker};
 
 /// The `OrderStatistics` trait provides statistical utilities
 /// having to do with ordering. All the algorithms are in-place thus requiring
@@ -18,10 +18,10 @@ pub trait OrderStatistics<T> {
     /// ```
     /// use statrs::statistics::OrderStatistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.order_statistic(1).is_nan());
     ///
-    /// let mut y = [0.0, 3.0, -2.0];
+    /// let mut y: [f64; 3] = [0.0, 3.0, -2.0];
     /// assert!(y.order_statistic(0).is_nan());
     /// assert!(y.order_statistic(4).is_nan());
     /// assert_eq!(y.order_statistic(2), 0.0);
@@ -40,12 +40,13 @@ pub trait OrderStatistics<T> {
     /// ```
     /// use statrs::statistics::OrderStatistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.median().is_nan());
     ///
     /// let mut y = [0.0, 3.0, -2.0];
     /// assert_eq!(y.median(), 0.0);
     /// assert!(y != [0.0, 3.0, -2.0]);
+    /// ```
     fn median(&mut self) -> T;
 
     /// Estimates the tau-th quantile from the data. The tau-th quantile
@@ -56,17 +57,18 @@ pub trait OrderStatistics<T> {
     ///
     /// No sorting is assumed. Tau must be between `0` and `1` inclusive.
     /// Returns `f64::NAN` if data is empty or tau is outside the inclusive
-    /// range.
+    /// range. Uses the approximately median-unbiased estimator,
+    /// `QuantileDefinition::R8`.
     ///
     /// # Examples
     ///
     /// ```
     /// use statrs::statistics::OrderStatistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.quantile(0.5).is_nan());
     ///
-    /// let mut y = [0.0, 3.0, -2.0];
+    /// let mut y: [f64; 3] = [0.0, 3.0, -2.0];
     /// assert!(y.quantile(-1.0).is_nan());
     /// assert!(y.quantile(2.0).is_nan());
     /// assert_eq!(y.quantile(0.5), 0.0);
@@ -74,6 +76,32 @@ pub trait OrderStatistics<T> {
     /// ```
     fn quantile(&mut self, tau: f64) -> T;
 
+    /// Estimates the tau-th quantile from the data using one of the nine
+    /// sample quantile definitions of Hyndman and Fan.
+    ///
+    /// # Remarks
+    ///
+    /// No sorting is assumed. Tau must be between `0` and `1` inclusive.
+    /// Returns `f64::NAN` if data is empty or tau is outside the inclusive
+    /// range. The results match R's `quantile(x, tau, type = k)` for
+    /// `QuantileDefinition::Rk`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{OrderStatistics, QuantileDefinition};
+    ///
+    /// let mut x: [f64; 0] = [];
+    /// assert!(x.quantile_custom(0.5, QuantileDefinition::R7).is_nan());
+    ///
+    /// let mut y: [f64; 10] = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R1), 3.0);
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R6), 2.75);
+    /// assert_eq!(y.quantile_custom(0.25, QuantileDefinition::R7), 3.25);
+    /// assert!(y.quantile_custom(1.5, QuantileDefinition::R7).is_nan());
+    /// ```
+    fn quantile_custom(&mut self, tau: f64, definition: QuantileDefinition) -> T;
+
     /// Estimates the p-Percentile value from the data.
     ///
     /// # Remarks
@@ -88,10 +116,10 @@ pub trait OrderStatistics<T> {
     /// ```
     /// use statrs::statistics::OrderStatistics;
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.percentile(0).is_nan());
     ///
-    /// let mut y = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
+    /// let mut y: [f64; 10] = [1.0, 5.0, 3.0, 4.0, 10.0, 9.0, 6.0, 7.0, 8.0, 2.0];
     /// assert_eq!(y.percentile(0), 1.0);
     /// assert_eq!(y.percentile(50), 5.5);
     /// assert_eq!(y.percentile(100), 10.0);
@@ -115,7 +143,7 @@ pub trait OrderStatistics<T> {
     /// use statrs::statistics::OrderStatistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.lower_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -140,7 +168,7 @@ pub trait OrderStatistics<T> {
     /// use statrs::statistics::OrderStatistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.upper_quartile().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -165,7 +193,7 @@ pub trait OrderStatistics<T> {
     /// use statrs::statistics::OrderStatistics;
     ///
     /// # fn main() {
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert!(x.interquartile_range().is_nan());
     ///
     /// let mut y = [2.0, 1.0, 3.0, 4.0];
@@ -182,7 +210,7 @@ pub trait OrderStatistics<T> {
     /// ```
     /// use statrs::statistics::{OrderStatistics, RankTieBreaker};
     ///
-    /// let mut x = [];
+    /// let mut x: [f64; 0] = [];
     /// assert_eq!(x.ranks(RankTieBreaker::Average).len(), 0);
     ///
     /// let y = [1.0, 3.0, 2.0, 2.0];
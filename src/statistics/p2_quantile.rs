@@ -0,0 +1,296 @@
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use std::borrow::Borrow;
+use std::f64;
+
+/// Streaming estimator of a single quantile using the P² algorithm of
+/// Jain and Chlamtac (1985), requiring constant memory regardless of the
+/// number of values observed.
+///
+/// # Remarks
+///
+/// The estimator tracks five markers: the minimum, the maximum, the target
+/// quantile and the quantiles halfway to either extreme. Marker heights are
+/// adjusted with piecewise-parabolic interpolation as values arrive. Until
+/// five values have been observed the quantile is computed exactly, as with
+/// `OrderStatistics::quantile`. `NAN` values are ignored.
+///
+/// Unlike `TDigest`, the estimator answers only the quantile it was
+/// constructed for and does not implement `OrderStatistics` or
+/// `ContinuousCDF`. The marker ranks are fixed by `tau` when it is
+/// constructed, and the other markers only serve to interpolate the
+/// middle one, so the five heights carry no accuracy guarantee for any
+/// other quantile or for the cdf between them. Track several quantiles
+/// with one estimator each, or use a `TDigest` when arbitrary quantile and
+/// cdf queries are needed.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::statistics::{Max, Min, P2Quantile};
+///
+/// let mut p95 = P2Quantile::new(0.95).unwrap();
+/// for i in 0..10_000 {
+///     p95.push((i % 1000) as f64);
+/// }
+/// assert!((p95.quantile() - 950.0).abs() < 5.0);
+/// assert_eq!(p95.min(), 0.0);
+/// assert_eq!(p95.max(), 999.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2Quantile {
+    tau: f64,
+    count: u64,
+    // marker heights
+    q: [f64; 5],
+    // actual marker positions, one-based
+    n: [f64; 5],
+    // desired marker positions and their increments
+    desired: [f64; 5],
+    increments: [f64; 5],
+}
+
+impl P2Quantile {
+    /// Constructs a new estimator of the `tau`-th quantile
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `tau` is not in `[0, 1]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::P2Quantile;
+    ///
+    /// let mut result = P2Quantile::new(0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = P2Quantile::new(1.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(tau: f64) -> Result<P2Quantile> {
+        if !(0.0..=1.0).contains(&tau) {
+            return Err(StatsError::ArgIntervalIncl("tau", 0.0, 1.0));
+        }
+        Ok(P2Quantile {
+            tau,
+            count: 0,
+            q: [0.0; 5],
+            n: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * tau, 1.0 + 4.0 * tau, 3.0 + 2.0 * tau, 5.0],
+            increments: [0.0, tau / 2.0, tau, (1.0 + tau) / 2.0, 1.0],
+        })
+    }
+
+    /// Returns the quantile being estimated
+    pub fn tau(&self) -> f64 {
+        self.tau
+    }
+
+    /// Returns the number of values observed
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Updates the estimator with a single value
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        if self.count < 5 {
+            self.q[self.count as usize] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.q.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            }
+            return;
+        }
+        self.count += 1;
+
+        // find the cell containing x, widening the extremes if needed
+        let k = if x < self.q[0] {
+            self.q[0] = x;
+            0
+        } else if x >= self.q[4] {
+            self.q[4] = x;
+            3
+        } else {
+            (1..5).find(|&i| x < self.q[i]).unwrap() - 1
+        };
+
+        for i in k + 1..5 {
+            self.n[i] += 1.0;
+        }
+        for i in 0..5 {
+            self.desired[i] += self.increments[i];
+        }
+
+        // adjust the heights of the three middle markers
+        for i in 1..4 {
+            let d = self.desired[i] - self.n[i];
+            if (d >= 1.0 && self.n[i + 1] - self.n[i] > 1.0)
+                || (d <= -1.0 && self.n[i - 1] - self.n[i] < -1.0)
+            {
+                let d = d.signum();
+                let candidate = self.parabolic(i, d);
+                self.q[i] = if self.q[i - 1] < candidate && candidate < self.q[i + 1] {
+                    candidate
+                } else {
+                    self.linear(i, d)
+                };
+                self.n[i] += d;
+            }
+        }
+    }
+
+    /// Returns the current estimate of the `tau`-th quantile
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been observed
+    pub fn quantile(&self) -> f64 {
+        match self.count {
+            0 => f64::NAN,
+            1..=4 => {
+                let mut observed = self.q[..self.count as usize].to_vec();
+                observed.quantile(self.tau)
+            }
+            _ => {
+                if self.tau == 0.0 {
+                    self.q[0]
+                } else if ulps_eq!(self.tau, 1.0) {
+                    self.q[4]
+                } else {
+                    self.q[2]
+                }
+            }
+        }
+    }
+
+    fn parabolic(&self, i: usize, d: f64) -> f64 {
+        let (q, n) = (&self.q, &self.n);
+        q[i] + d / (n[i + 1] - n[i - 1])
+            * ((n[i] - n[i - 1] + d) * (q[i + 1] - q[i]) / (n[i + 1] - n[i])
+                + (n[i + 1] - n[i] - d) * (q[i] - q[i - 1]) / (n[i] - n[i - 1]))
+    }
+
+    fn linear(&self, i: usize, d: f64) -> f64 {
+        let j = if d > 0.0 { i + 1 } else { i - 1 };
+        self.q[i] + d * (self.q[j] - self.q[i]) / (self.n[j] - self.n[i])
+    }
+}
+
+impl Min<f64> for P2Quantile {
+    /// Returns the minimum of the observed values, `f64::NAN` if no values
+    /// have been observed
+    fn min(&self) -> f64 {
+        match self.count {
+            0 => f64::NAN,
+            1..=4 => Statistics::min(&self.q[..self.count as usize]),
+            _ => self.q[0],
+        }
+    }
+}
+
+impl Max<f64> for P2Quantile {
+    /// Returns the maximum of the observed values, `f64::NAN` if no values
+    /// have been observed
+    fn max(&self) -> f64 {
+        match self.count {
+            0 => f64::NAN,
+            1..=4 => Statistics::max(&self.q[..self.count as usize]),
+            _ => self.q[4],
+        }
+    }
+}
+
+impl<T: Borrow<f64>> Extend<T> for P2Quantile {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(*x.borrow());
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::distribution::{ContinuousCDF, Exp, Normal};
+    use crate::statistics::*;
+
+    fn sample<D: Distribution<f64>>(dist: D, n: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        (0..n).map(|_| dist.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert!(P2Quantile::new(-0.1).is_err());
+        assert!(P2Quantile::new(1.1).is_err());
+        assert!(P2Quantile::new(f64::NAN).is_err());
+        assert!(P2Quantile::new(0.0).is_ok());
+        assert!(P2Quantile::new(1.0).is_ok());
+    }
+
+    #[test]
+    fn test_few_values_exact() {
+        let mut p = P2Quantile::new(0.5).unwrap();
+        assert!(p.quantile().is_nan());
+        assert!(p.min().is_nan());
+        p.extend(&[3.0, 1.0, 2.0]);
+        assert_eq!(p.count(), 3);
+        assert_eq!(p.quantile(), 2.0);
+        assert_eq!(p.min(), 1.0);
+        assert_eq!(p.max(), 3.0);
+        p.push(f64::NAN);
+        assert_eq!(p.count(), 3);
+        p.push(4.0);
+        assert_eq!(p.quantile(), [3.0, 1.0, 2.0, 4.0].quantile(0.5));
+    }
+
+    #[test]
+    fn test_jain_chlamtac_example() {
+        // worked example from the original paper, estimating the median
+        let data = [
+            0.02, 0.15, 0.74, 3.39, 0.83, 22.37, 10.15, 15.43, 38.62, 15.92,
+            34.60, 10.28, 1.47, 0.40, 0.05, 11.39, 0.27, 0.42, 0.09, 11.37,
+        ];
+        let mut p = P2Quantile::new(0.5).unwrap();
+        p.extend(&data);
+        assert_almost_eq!(p.quantile(), 4.44, 0.01);
+        assert_eq!(p.min(), 0.02);
+        assert_eq!(p.max(), 38.62);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let exp = Exp::new(1.0).unwrap();
+        let normal_data = sample(normal, 100_000);
+        let exp_data = sample(exp, 100_000);
+        for &tau in &[0.01, 0.1, 0.5, 0.9, 0.99] {
+            let mut p = P2Quantile::new(tau).unwrap();
+            p.extend(&normal_data);
+            assert_almost_eq!(p.quantile(), normal.inverse_cdf(tau), 0.02);
+
+            let mut p = P2Quantile::new(tau).unwrap();
+            p.extend(&exp_data);
+            assert_almost_eq!(p.quantile(), exp.inverse_cdf(tau), 0.02 * exp.inverse_cdf(tau).max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        let data: Vec<f64> = (0..1000).map(|i| ((i * 7919) % 1000) as f64).collect();
+        let mut low = P2Quantile::new(0.0).unwrap();
+        let mut high = P2Quantile::new(1.0).unwrap();
+        low.extend(&data);
+        high.extend(&data);
+        assert_eq!(low.quantile(), 0.0);
+        assert_eq!(high.quantile(), 999.0);
+    }
+}
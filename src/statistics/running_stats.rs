@@ -0,0 +1,462 @@
+use crate::statistics::*;
+use std::borrow::Borrow;
+use std::f64;
+use std::iter::FromIterator;
+
+/// Running statistics accumulator that processes a stream of values one at
+/// a time in constant memory, tracking the count, the first four central
+/// moments, the minimum and the maximum.
+///
+/// # Remarks
+///
+/// Accumulators built over disjoint parts of a data set can be combined
+/// with `merge`, yielding the same result as a single accumulator over the
+/// whole data set up to rounding. Updates use the formulas of Chan et al.
+/// and Pébay (2008).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate statrs;
+///
+/// use statrs::statistics::{Max, Min, RunningStats};
+///
+/// # fn main() {
+/// let mut a: RunningStats = [1.0, 2.0, 3.0].iter().collect();
+/// let mut b = RunningStats::new();
+/// b.push(4.0);
+/// b.push(5.0);
+///
+/// a.merge(&b);
+/// assert_eq!(a.count(), 5);
+/// assert_eq!(a.mean(), 3.0);
+/// assert_almost_eq!(a.variance(), 2.5, 1e-15);
+/// assert_eq!(a.min(), 1.0);
+/// assert_eq!(a.max(), 5.0);
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RunningStats {
+    n: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    min: f64,
+    max: f64,
+}
+
+impl RunningStats {
+    /// Constructs a new empty accumulator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::RunningStats;
+    ///
+    /// let stats = RunningStats::new();
+    /// assert_eq!(stats.count(), 0);
+    /// assert!(stats.mean().is_nan());
+    /// ```
+    pub fn new() -> RunningStats {
+        RunningStats {
+            n: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Updates the accumulator with a single value
+    pub fn push(&mut self, x: f64) {
+        let n1 = self.n as f64;
+        self.n += 1;
+        let n = self.n as f64;
+
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+
+        if x < self.min || x.is_nan() {
+            self.min = x;
+        }
+        if x > self.max || x.is_nan() {
+            self.max = x;
+        }
+    }
+
+    /// Combines the values accumulated by `other` into this accumulator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::RunningStats;
+    ///
+    /// let mut a: RunningStats = [1.0, 2.0].iter().collect();
+    /// let b: RunningStats = [3.0, 4.0].iter().collect();
+    /// a.merge(&b);
+    ///
+    /// let c: RunningStats = [1.0, 2.0, 3.0, 4.0].iter().collect();
+    /// assert_eq!(a.mean(), c.mean());
+    /// assert_eq!(a.variance(), c.variance());
+    /// ```
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.n == 0 {
+            return;
+        }
+        if self.n == 0 {
+            *self = *other;
+            return;
+        }
+
+        let na = self.n as f64;
+        let nb = other.n as f64;
+        let n = na + nb;
+        let n2 = n * n;
+
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+        let delta3 = delta2 * delta;
+        let delta4 = delta2 * delta2;
+
+        let mean = self.mean + delta * nb / n;
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta3 * na * nb * (na - nb) / n2
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta4 * na * nb * (na * na - na * nb + nb * nb) / (n2 * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / n2
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+
+        self.n += other.n;
+        self.mean = mean;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+        if other.min < self.min || other.min.is_nan() {
+            self.min = other.min;
+        }
+        if other.max > self.max || other.max.is_nan() {
+            self.max = other.max;
+        }
+    }
+
+    /// Returns the number of values accumulated
+    pub fn count(&self) -> u64 {
+        self.n
+    }
+
+    /// Evaluates the sample mean, an estimate of the population mean.
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values were accumulated or a value was
+    /// `f64::NAN`
+    pub fn mean(&self) -> f64 {
+        if self.n > 0 {
+            self.mean
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Estimates the unbiased population variance from the accumulated
+    /// samples
+    ///
+    /// # Remarks
+    ///
+    /// On a dataset of size `N`, `N-1` is used as a normalizer (Bessel's
+    /// correction).
+    ///
+    /// Returns `f64::NAN` if fewer than two values were accumulated
+    pub fn variance(&self) -> f64 {
+        if self.n > 1 {
+            self.m2 / (self.n as f64 - 1.0)
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Estimates the unbiased population standard deviation from the
+    /// accumulated samples
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values were accumulated
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Evaluates the population variance from a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values were accumulated
+    pub fn population_variance(&self) -> f64 {
+        if self.n > 0 {
+            self.m2 / self.n as f64
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Evaluates the population standard deviation from a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values were accumulated
+    pub fn population_std_dev(&self) -> f64 {
+        self.population_variance().sqrt()
+    }
+
+    /// Estimates the unbiased population skewness from the accumulated
+    /// samples
+    ///
+    /// # Remarks
+    ///
+    /// Uses the adjusted Fisher-Pearson coefficient `G1`.
+    ///
+    /// Returns `f64::NAN` if fewer than three values were accumulated
+    pub fn skewness(&self) -> f64 {
+        if self.n < 3 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        (n * (n - 1.0)).sqrt() / (n - 2.0) * self.population_skewness()
+    }
+
+    /// Evaluates the population skewness from a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values were accumulated
+    pub fn population_skewness(&self) -> f64 {
+        if self.n < 2 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        n.sqrt() * self.m3 / self.m2.powf(1.5)
+    }
+
+    /// Estimates the unbiased population excess kurtosis from the
+    /// accumulated samples
+    ///
+    /// # Remarks
+    ///
+    /// Uses the adjusted estimator `G2`.
+    ///
+    /// Returns `f64::NAN` if fewer than four values were accumulated
+    pub fn kurtosis(&self) -> f64 {
+        if self.n < 4 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        (n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * self.population_kurtosis() + 6.0)
+    }
+
+    /// Evaluates the population excess kurtosis from a full population
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if fewer than two values were accumulated
+    pub fn population_kurtosis(&self) -> f64 {
+        if self.n < 2 {
+            return f64::NAN;
+        }
+        let n = self.n as f64;
+        n * self.m4 / (self.m2 * self.m2) - 3.0
+    }
+}
+
+impl Default for RunningStats {
+    fn default() -> RunningStats {
+        RunningStats::new()
+    }
+}
+
+impl Min<f64> for RunningStats {
+    /// Returns the minimum of the accumulated values
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values were accumulated or a value was
+    /// `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Min, RunningStats};
+    ///
+    /// let stats: RunningStats = [0.0, 3.0, -2.0].iter().collect();
+    /// assert_eq!(stats.min(), -2.0);
+    /// ```
+    fn min(&self) -> f64 {
+        if self.n > 0 {
+            self.min
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl Max<f64> for RunningStats {
+    /// Returns the maximum of the accumulated values
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values were accumulated or a value was
+    /// `f64::NAN`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::{Max, RunningStats};
+    ///
+    /// let stats: RunningStats = [0.0, 3.0, -2.0].iter().collect();
+    /// assert_eq!(stats.max(), 3.0);
+    /// ```
+    fn max(&self) -> f64 {
+        if self.n > 0 {
+            self.max
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl<T: Borrow<f64>> Extend<T> for RunningStats {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(*x.borrow());
+        }
+    }
+}
+
+impl<T: Borrow<f64>> FromIterator<T> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> RunningStats {
+        let mut stats = RunningStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::prec;
+    use crate::statistics::*;
+    use crate::testing;
+
+    const FILES: [&str; 5] = [
+        "nist/lottery.txt",
+        "nist/lew.txt",
+        "nist/mavro.txt",
+        "nist/michaelso.txt",
+        "nist/numacc3.txt",
+    ];
+
+    // two-pass population skewness and excess kurtosis
+    fn batch_shape(data: &[f64]) -> (f64, f64) {
+        let n = data.len() as f64;
+        let mean = data.mean();
+        let m2 = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let m3 = data.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+        let m4 = data.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n;
+        (m3 / m2.powf(1.5), m4 / (m2 * m2) - 3.0)
+    }
+
+    #[test]
+    fn test_consistent_with_batch() {
+        for file in FILES.iter() {
+            let data = testing::load_data(file);
+            let stats: RunningStats = data.iter().collect();
+            let (skew, kurt) = batch_shape(&data);
+            assert_eq!(stats.count(), data.len() as u64);
+            assert!(prec::almost_eq(stats.mean(), (&data).mean(), 1e-10));
+            assert!(prec::almost_eq(stats.variance(), (&data).variance(), 1e-8));
+            assert!(prec::almost_eq(stats.std_dev(), (&data).std_dev(), 1e-10));
+            assert!(prec::almost_eq(stats.population_variance(), (&data).population_variance(), 1e-8));
+            assert!(prec::almost_eq(stats.population_skewness(), skew, 1e-8));
+            assert!(prec::almost_eq(stats.population_kurtosis(), kurt, 1e-8));
+            assert_eq!(Min::min(&stats), Statistics::min(&data));
+            assert_eq!(Max::max(&stats), Statistics::max(&data));
+        }
+    }
+
+    #[test]
+    fn test_merge_consistent_with_single_pass() {
+        for file in FILES.iter() {
+            let data = testing::load_data(file);
+            let whole: RunningStats = data.iter().collect();
+            for &split in &[1, data.len() / 3, data.len() / 2, data.len() - 1] {
+                let mut a: RunningStats = data[..split].iter().collect();
+                let b: RunningStats = data[split..].iter().collect();
+                a.merge(&b);
+                assert_eq!(a.count(), whole.count());
+                assert!(prec::almost_eq(a.mean(), whole.mean(), 1e-10));
+                assert!(prec::almost_eq(a.variance(), whole.variance(), 1e-8));
+                assert!(prec::almost_eq(a.skewness(), whole.skewness(), 1e-8));
+                assert!(prec::almost_eq(a.kurtosis(), whole.kurtosis(), 1e-8));
+                assert_eq!(Min::min(&a), Min::min(&whole));
+                assert_eq!(Max::max(&a), Max::max(&whole));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let data = [1.0, 5.0, -3.0, 2.5];
+        let full: RunningStats = data.iter().collect();
+        let mut a = full;
+        a.merge(&RunningStats::new());
+        assert_eq!(a, full);
+        let mut b = RunningStats::new();
+        b.merge(&full);
+        assert_eq!(b, full);
+    }
+
+    #[test]
+    fn test_sample_shape() {
+        // reference values from the two-pass G1 and G2 estimators, as in
+        // R's e1071::skewness and e1071::kurtosis with type = 2
+        let stats: RunningStats = [2.0, 8.0, 0.0, 4.0, 1.0, 9.0, 9.0, 0.0].iter().collect();
+        assert!(prec::almost_eq(stats.skewness(), 0.33058218040797466, 1e-14));
+        assert!(prec::almost_eq(stats.kurtosis(), -2.098602258096087, 1e-14));
+    }
+
+    #[test]
+    fn test_insufficient_data_returns_nan() {
+        let empty = RunningStats::new();
+        assert!(empty.mean().is_nan());
+        assert!(empty.variance().is_nan());
+        assert!(empty.population_variance().is_nan());
+        assert!(Min::min(&empty).is_nan());
+        assert!(Max::max(&empty).is_nan());
+
+        let two: RunningStats = [1.0, 2.0].iter().collect();
+        assert_eq!(two.variance(), 0.5);
+        assert!(two.skewness().is_nan());
+        assert!(two.kurtosis().is_nan());
+        assert_eq!(two.population_skewness(), 0.0);
+
+        let nan: RunningStats = [1.0, f64::NAN, 2.0].iter().collect();
+        assert!(nan.mean().is_nan());
+        assert!(Min::min(&nan).is_nan());
+        assert!(Max::max(&nan).is_nan());
+    }
+}
@@ -1,88 +1,165 @@
 use crate::statistics::*;
+use ::num_traits::float::Float;
 use std::f64;
 
-impl OrderStatistics<f64> for [f64] {
-    fn order_statistic(&mut self, order: usize) -> f64 {
+impl<T: Float> OrderStatistics<T> for [T] {
+    fn order_statistic(&mut self, order: usize) -> T {
         let n = self.len();
         match order {
-            1 => self.min(),
-            _ if order == n => self.max(),
-            _ if order < 1 || order > n => f64::NAN,
+            1 => min(self),
+            _ if order == n => max(self),
+            _ if order < 1 || order > n => T::nan(),
             _ => select_inplace(self, order - 1),
         }
     }
 
-    fn median(&mut self) -> f64 {
+    fn median(&mut self) -> T {
         let k = self.len() / 2;
-        if self.len() % 2 != 0 {
+        if self.len() % 2 == 1 {
             select_inplace(self, k)
         } else {
-            (select_inplace(self, k.saturating_sub(1)) + select_inplace(self, k)) / 2.0
+            let two = T::one() + T::one();
+            (select_inplace(self, k.saturating_sub(1)) + select_inplace(self, k)) / two
         }
     }
 
-    fn quantile(&mut self, tau: f64) -> f64 {
-        if tau < 0.0 || tau > 1.0 || self.is_empty() {
-            return f64::NAN;
+    fn quantile(&mut self, tau: f64) -> T {
+        if !(0.0..=1.0).contains(&tau) || self.is_empty() {
+            return T::nan();
         }
 
         let h = (self.len() as f64 + 1.0 / 3.0) * tau + 1.0 / 3.0;
         let hf = h as i64;
 
         if hf <= 0 || tau == 0.0 {
-            return self.min();
+            return min(self);
         }
         if hf >= self.len() as i64 || ulps_eq!(tau, 1.0) {
-            return self.max();
+            return max(self);
         }
 
         let a = select_inplace(self, (hf as usize).saturating_sub(1));
         let b = select_inplace(self, hf as usize);
-        a + (h - hf as f64) * (b - a)
+        a + from_f64::<T>(h - hf as f64) * (b - a)
     }
 
-    fn percentile(&mut self, p: usize) -> f64 {
+    fn quantile_custom(&mut self, tau: f64, definition: QuantileDefinition) -> T {
+        if !(0.0..=1.0).contains(&tau) || self.is_empty() {
+            return T::nan();
+        }
+
+        // follows the formulation of R's `quantile`: `j` is the one-based
+        // order statistic at or below the quantile and `g` the weight given
+        // to its successor, `fuzz` guards against representation error
+        // pushing an exact index just below an integer
+        let n = self.len() as f64;
+        let fuzz = 4.0 * f64::EPSILON;
+        let (j, g) = match definition {
+            QuantileDefinition::R1 | QuantileDefinition::R2 | QuantileDefinition::R3 => {
+                let nppm = if definition == QuantileDefinition::R3 {
+                    n * tau - 0.5
+                } else {
+                    n * tau
+                };
+                let j = (nppm + fuzz).floor();
+                let g = match definition {
+                    QuantileDefinition::R1 => {
+                        if nppm > j {
+                            1.0
+                        } else {
+                            0.0
+                        }
+                    }
+                    QuantileDefinition::R2 => {
+                        if nppm > j {
+                            1.0
+                        } else {
+                            0.5
+                        }
+                    }
+                    _ => {
+                        if nppm != j || j % 2.0 == 1.0 {
+                            1.0
+                        } else {
+                            0.0
+                        }
+                    }
+                };
+                (j, g)
+            }
+            _ => {
+                let (a, b) = match definition {
+                    QuantileDefinition::R4 => (0.0, 1.0),
+                    QuantileDefinition::R5 => (0.5, 0.5),
+                    QuantileDefinition::R6 => (0.0, 0.0),
+                    QuantileDefinition::R7 => (1.0, 1.0),
+                    QuantileDefinition::R8 => (1.0 / 3.0, 1.0 / 3.0),
+                    _ => (3.0 / 8.0, 3.0 / 8.0),
+                };
+                let nppm = a + tau * (n + 1.0 - a - b);
+                let j = (nppm + fuzz).floor();
+                let g = nppm - j;
+                (j, if g.abs() < fuzz { 0.0 } else { g })
+            }
+        };
+
+        // order statistics outside of 1..=N are clamped to the extremes
+        let len = self.len();
+        let lo = (j.max(1.0) as usize).min(len) - 1;
+        let hi = ((j + 1.0).max(1.0) as usize).min(len) - 1;
+        if g == 0.0 {
+            select_inplace(self, lo)
+        } else if g == 1.0 {
+            select_inplace(self, hi)
+        } else {
+            let a = select_inplace(self, lo);
+            let b = select_inplace(self, hi);
+            a + from_f64::<T>(g) * (b - a)
+        }
+    }
+
+    fn percentile(&mut self, p: usize) -> T {
         self.quantile(p as f64 / 100.0)
     }
 
-    fn lower_quartile(&mut self) -> f64 {
+    fn lower_quartile(&mut self) -> T {
         self.quantile(0.25)
     }
 
-    fn upper_quartile(&mut self) -> f64 {
+    fn upper_quartile(&mut self) -> T {
         self.quantile(0.75)
     }
 
-    fn interquartile_range(&mut self) -> f64 {
+    fn interquartile_range(&mut self) -> T {
         self.upper_quartile() - self.lower_quartile()
     }
 
-    fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<f64> {
+    fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<T> {
         let n = self.len();
-        let mut ranks: Vec<f64> = vec![0.0; n];
+        let mut ranks: Vec<T> = vec![T::zero(); n];
         let mut enumerated: Vec<_> = self.iter().enumerate().collect();
         enumerated.sort_by(|(_, el_a), (_, el_b)| el_a.partial_cmp(el_b).unwrap());
         match tie_breaker {
             RankTieBreaker::First => {
                 for (i, idx) in enumerated.into_iter().map(|(idx, _)| idx).enumerate() {
-                    ranks[idx] = (i + 1) as f64
+                    ranks[idx] = from_f64((i + 1) as f64)
                 }
                 ranks
             }
             _ => {
                 let mut prev = 0;
                 let mut prev_idx = 0;
-                let mut prev_elt = 0.0;
+                let mut prev_elt = T::zero();
                 for (i, (idx, elt)) in enumerated.iter().cloned().enumerate() {
                     if i == 0 {
                         prev_idx = idx;
                         prev_elt = *elt;
                     }
-                    if (*elt - prev_elt).abs() <= 0.0 {
+                    if (*elt - prev_elt).abs() <= T::zero() {
                         continue;
                     }
                     if i == prev + 1 {
-                        ranks[prev_idx] = i as f64;
+                        ranks[prev_idx] = from_f64(i as f64);
                     } else {
                         handle_rank_ties(&mut ranks, &enumerated, prev, i, tie_breaker);
                     }
@@ -98,7 +175,45 @@ impl OrderStatistics<f64> for [f64] {
     }
 }
 
-impl Min<f64> for [f64] {
+impl<T: Float> OrderStatistics<T> for Vec<T> {
+    fn order_statistic(&mut self, order: usize) -> T {
+        self.as_mut_slice().order_statistic(order)
+    }
+
+    fn median(&mut self) -> T {
+        OrderStatistics::median(self.as_mut_slice())
+    }
+
+    fn quantile(&mut self, tau: f64) -> T {
+        self.as_mut_slice().quantile(tau)
+    }
+
+    fn quantile_custom(&mut self, tau: f64, definition: QuantileDefinition) -> T {
+        self.as_mut_slice().quantile_custom(tau, definition)
+    }
+
+    fn percentile(&mut self, p: usize) -> T {
+        self.as_mut_slice().percentile(p)
+    }
+
+    fn lower_quartile(&mut self) -> T {
+        self.as_mut_slice().lower_quartile()
+    }
+
+    fn upper_quartile(&mut self) -> T {
+        self.as_mut_slice().upper_quartile()
+    }
+
+    fn interquartile_range(&mut self) -> T {
+        self.as_mut_slice().interquartile_range()
+    }
+
+    fn ranks(&mut self, tie_breaker: RankTieBreaker) -> Vec<T> {
+        self.as_mut_slice().ranks(tie_breaker)
+    }
+}
+
+impl<T: Float> Min<T> for [T] {
     /// Returns the minimum value in the data
     ///
     /// # Remarks
@@ -120,12 +235,12 @@ impl Min<f64> for [f64] {
     /// let z = [0.0, 3.0, -2.0];
     /// assert_eq!(z.min(), -2.0);
     /// ```
-    fn min(&self) -> f64 {
-        Statistics::min(self)
+    fn min(&self) -> T {
+        min(self)
     }
 }
 
-impl Max<f64> for [f64] {
+impl<T: Float> Max<T> for [T] {
     /// Returns the maximum value in the data
     ///
     /// # Remarks
@@ -147,73 +262,36 @@ impl Max<f64> for [f64] {
     /// let z = [0.0, 3.0, -2.0];
     /// assert_eq!(z.max(), 3.0);
     /// ```
-    fn max(&self) -> f64 {
-        Statistics::max(self)
+    fn max(&self) -> T {
+        max(self)
     }
 }
 
-impl Distribution<f64> for [f64] {
-    /// Evaluates the sample mean, an estimate of the population
-    /// mean.
-    ///
-    /// # Remarks
-    ///
-    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// #[macro_use]
-    /// extern crate statrs;
-    ///
-    /// use std::f64;
-    /// use statrs::statistics::Distribution;
-    ///
-    /// # fn main() {
-    /// let x = [];
-    /// assert!(x.mean().unwrap().is_nan());
-    ///
-    /// let y = [0.0, f64::NAN, 3.0, -2.0];
-    /// assert!(y.mean().unwrap().is_nan());
-    ///
-    /// let z = [0.0, 3.0, -2.0];
-    /// assert_almost_eq!(z.mean().unwrap(), 1.0 / 3.0, 1e-15);
-    /// # }
-    /// ```
-    fn mean(&self) -> Option<f64> {
-        Some(Statistics::mean(self))
-    }
-    /// Estimates the unbiased population variance from the provided samples
+impl<T: Float> Median<T> for [T] {
+    /// Returns the median value from the data
     ///
     /// # Remarks
     ///
-    /// On a dataset of size `N`, `N-1` is used as a normalizer (Bessel's
-    /// correction).
-    ///
-    /// Returns `f64::NAN` if data has less than two entries or if any entry is
-    /// `f64::NAN`
+    /// Returns `f64::NAN` if data is empty
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::f64;
-    /// use statrs::statistics::Distribution;
-    ///
-    /// let x = [];
-    /// assert!(x.variance().unwrap().is_nan());
+    /// use statrs::statistics::Median;
     ///
-    /// let y = [0.0, f64::NAN, 3.0, -2.0];
-    /// assert!(y.variance().unwrap().is_nan());
+    /// let x: [f64; 0] = [];
+    /// assert!(x.median().is_nan());
     ///
-    /// let z = [0.0, 3.0, -2.0];
-    /// assert_eq!(z.variance().unwrap(), 19.0 / 3.0);
+    /// let y = [0.0, 3.0, -2.0];
+    /// assert_eq!(y.median(), 0.0);
     /// ```
-    fn variance(&self) -> Option<f64> {
-        Some(Statistics::variance(self))
+    fn median(&self) -> T {
+        let mut copy = self.to_vec();
+        OrderStatistics::median(&mut *copy)
     }
 }
 
-impl Median<f64> for [f64] {
+impl<T: Float> Median<T> for Vec<T> {
     /// Returns the median value from the data
     ///
     /// # Remarks
@@ -225,20 +303,44 @@ impl Median<f64> for [f64] {
     /// ```
     /// use statrs::statistics::Median;
     ///
-    /// let x = [];
+    /// let x: Vec<f64> = vec![];
     /// assert!(x.median().is_nan());
     ///
-    /// let y = [0.0, 3.0, -2.0];
+    /// let y = vec![0.0, 3.0, -2.0];
     /// assert_eq!(y.median(), 0.0);
-    fn median(&self) -> f64 {
-        let mut copy = self.to_vec();
-        OrderStatistics::median(&mut *copy)
+    /// ```
+    fn median(&self) -> T {
+        Median::median(self.as_slice())
+    }
+}
+
+fn from_f64<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+// Mirrors `Statistics::min`, returning `NAN` if the data is empty
+// or contains a `NAN`
+fn min<T: Float>(arr: &[T]) -> T {
+    let mut iter = arr.iter();
+    match iter.next() {
+        None => T::nan(),
+        Some(&init) => iter.fold(init, |acc, &x| if x < acc || x.is_nan() { x } else { acc }),
+    }
+}
+
+// Mirrors `Statistics::max`, returning `NAN` if the data is empty
+// or contains a `NAN`
+fn max<T: Float>(arr: &[T]) -> T {
+    let mut iter = arr.iter();
+    match iter.next() {
+        None => T::nan(),
+        Some(&init) => iter.fold(init, |acc, &x| if x > acc || x.is_nan() { x } else { acc }),
     }
 }
 
-fn handle_rank_ties(
-    ranks: &mut [f64],
-    index: &[(usize, &f64)],
+fn handle_rank_ties<T: Float>(
+    ranks: &mut [T],
+    index: &[(usize, &T)],
     a: usize,
     b: usize,
     tie_breaker: RankTieBreaker,
@@ -251,18 +353,18 @@ fn handle_rank_ties(
         RankTieBreaker::First => unreachable!(),
     };
     for i in &index[a..b] {
-        ranks[i.0] = rank
+        ranks[i.0] = from_f64(rank)
     }
 }
 
 // Selection algorithm from Numerical Recipes
 // See: https://en.wikipedia.org/wiki/Selection_algorithm
-fn select_inplace(arr: &mut [f64], rank: usize) -> f64 {
+fn select_inplace<T: Float>(arr: &mut [T], rank: usize) -> T {
     if rank == 0 {
-        return arr.min();
+        return min(arr);
     }
     if rank > arr.len() - 1 {
-        return arr.max();
+        return max(arr);
     }
 
     let mut low = 0;
@@ -330,7 +432,7 @@ mod tests {
 
     #[test]
     fn test_order_statistic_short() {
-        let mut data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 1.0, 6.0];
+        let mut data: [f64; 9] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 1.0, 6.0];
         assert!(data.order_statistic(0).is_nan());
         assert_eq!(data.order_statistic(1), -3.0);
         assert_eq!(data.order_statistic(2), -1.0);
@@ -355,6 +457,79 @@ mod tests {
         assert_almost_eq!(data.quantile(0.325), -37.0 / 240.0, 1e-15);
     }
 
+    #[test]
+    fn test_quantile_custom_short() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        let check = |def: QuantileDefinition, expected: [f64; 7]| {
+            let taus = [0.0, 0.05, 0.25, 0.5, 0.7, 0.9, 1.0];
+            for (&tau, &x) in taus.iter().zip(expected.iter()) {
+                let mut copy = data;
+                assert_almost_eq!(copy.quantile_custom(tau, def), x, 1e-14);
+            }
+        };
+        // reference values from R's `quantile(data, taus, type = k)`
+        check(QuantileDefinition::R1, [-3.0, -3.0, -0.5, 0.2, 4.0, 6.0, 10.0]);
+        check(QuantileDefinition::R2, [-3.0, -3.0, -0.5, 0.6, 4.5, 8.0, 10.0]);
+        check(QuantileDefinition::R3, [-3.0, -3.0, -1.0, 0.2, 4.0, 6.0, 10.0]);
+        check(QuantileDefinition::R4, [-3.0, -3.0, -0.75, 0.2, 4.0, 6.0, 10.0]);
+        check(QuantileDefinition::R5, [-3.0, -3.0, -0.5, 0.6, 4.5, 8.0, 10.0]);
+        check(QuantileDefinition::R6, [-3.0, -3.0, -0.625, 0.6, 4.7, 9.6, 10.0]);
+        check(QuantileDefinition::R7, [-3.0, -2.1, -0.375, 0.6, 4.3, 6.4, 10.0]);
+        check(QuantileDefinition::R8, [-3.0, -3.0, -13.0 / 24.0, 0.6, 137.0 / 30.0, 128.0 / 15.0, 10.0]);
+        check(QuantileDefinition::R9, [-3.0, -3.0, -17.0 / 32.0, 0.6, 4.55, 8.4, 10.0]);
+    }
+
+    #[test]
+    fn test_quantile_custom_consistent_with_quantile() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        for &tau in &[0.0, 0.01, 0.2, 0.325, 0.5, 0.52, 0.7, 0.99, 1.0] {
+            let (mut a, mut b) = (data, data);
+            assert_almost_eq!(a.quantile_custom(tau, QuantileDefinition::R8), b.quantile(tau), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_quantile_custom_invalid() {
+        let mut empty: [f64; 0] = [];
+        assert!(empty.quantile_custom(0.5, QuantileDefinition::R1).is_nan());
+        let mut data: [f64; 3] = [1.0, 2.0, 3.0];
+        assert!(data.quantile_custom(-0.1, QuantileDefinition::R7).is_nan());
+        assert!(data.quantile_custom(1.1, QuantileDefinition::R7).is_nan());
+        assert!(data.quantile_custom(f64::NAN, QuantileDefinition::R7).is_nan());
+    }
+
+    #[test]
+    fn test_quantile_custom_single_element() {
+        for &def in &[QuantileDefinition::R1, QuantileDefinition::R3, QuantileDefinition::R6, QuantileDefinition::R9] {
+            let mut data = [4.0];
+            assert_eq!(data.quantile_custom(0.0, def), 4.0);
+            assert_eq!(data.quantile_custom(0.3, def), 4.0);
+            assert_eq!(data.quantile_custom(1.0, def), 4.0);
+        }
+    }
+
+    #[test]
+    fn test_order_statistics_f32() {
+        let mut data: [f32; 9] = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 1.0, 6.0];
+        assert_eq!(data.order_statistic(1), -3.0);
+        assert_eq!(data.order_statistic(3), -0.5);
+        assert_eq!(data.order_statistic(9), 10.0);
+        assert_eq!(OrderStatistics::median(&mut data[..]), 1.0);
+        assert_eq!(data.quantile_custom(0.25, QuantileDefinition::R7), -0.5);
+        assert_eq!([1.0f32, 3.0, 2.0, 2.0].ranks(RankTieBreaker::Average), [1.0, 4.0, 2.5, 2.5]);
+        assert!(Min::min(&[0.0f32, f32::NAN][..]).is_nan());
+    }
+
+    #[test]
+    fn test_order_statistics_vec() {
+        let mut data = vec![-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        assert_eq!(data.order_statistic(2), -1.0);
+        assert_almost_eq!(data.quantile(0.2), -4.0 / 5.0, 1e-15);
+        assert_almost_eq!(data.quantile_custom(0.7, QuantileDefinition::R6), 4.7, 1e-14);
+        assert_eq!(data.median(), 0.6);
+        assert_eq!(vec![2.0f32, 1.0, 3.0].ranks(RankTieBreaker::First), vec![2.0, 1.0, 3.0]);
+    }
+
     #[test]
     fn test_ranks() {
         let mut sorted_distinct = [1.0, 2.0, 4.0, 7.0, 8.0, 9.0, 10.0, 12.0];
@@ -1,3 +1,8 @@
+use super::iter_statistics::{weighted_covariance, zip_weights};
+use super::WeightedStatistics;
+use crate::Result;
+use std::borrow::Borrow;
+
 /// Enumeration of possible tie-breaking strategies
 /// when computing ranks
 #[derive(Debug, Copy, Clone)]
@@ -12,6 +17,53 @@ pub enum RankTieBreaker {
     First,
 }
 
+/// Enumeration of the nine sample quantile definitions discussed by
+/// Hyndman and Fan (1996), numbered as in R's `quantile(type = 1..9)`.
+///
+/// `R1` to `R3` are discontinuous and always return an element of the data,
+/// `R4` to `R9` interpolate linearly between adjacent order statistics.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QuantileDefinition {
+    /// Inverse of the empirical distribution function
+    R1,
+    /// Inverse of the empirical distribution function, averaging at
+    /// discontinuities
+    R2,
+    /// Observation closest to `N * tau`, preferring the even order statistic
+    /// on ties (SAS definition)
+    R3,
+    /// Linear interpolation of the empirical distribution function
+    R4,
+    /// Piecewise linear function where the knots are the midpoints of the
+    /// empirical distribution function steps (Hazen)
+    R5,
+    /// Linear interpolation of the expectations of the order statistics of
+    /// the uniform distribution (Weibull, SPSS, Minitab)
+    R6,
+    /// Linear interpolation of the modes of the order statistics of the
+    /// uniform distribution (default in R, numpy and Excel)
+    R7,
+    /// Linear interpolation of the approximate medians of the order
+    /// statistics, median-unbiased regardless of the distribution
+    R8,
+    /// Approximately unbiased for the expected order statistics if the data
+    /// is normally distributed (Blom)
+    R9,
+}
+
+/// Enumeration of the interpretations of observation weights used when
+/// estimating a weighted variance or covariance
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WeightKind {
+    /// Weights are integer repeat counts of each observation, the sum of the
+    /// weights minus one is used as a normalizer
+    Frequency,
+    /// Weights describe the relative importance or precision of each
+    /// observation, normalized by `V1 - V2 / V1` where `V1` and `V2` are the
+    /// sums of the weights and squared weights
+    Reliability,
+}
+
 /// The `Statistics` trait provides a host of statistical utilities for
 /// analyzing
 /// data sets
@@ -74,7 +126,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.abs_min().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -97,7 +149,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.abs_max().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -125,7 +177,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -155,7 +207,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.geometric_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -194,7 +246,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.harmonic_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -229,7 +281,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.variance().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -257,7 +309,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.std_dev().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -283,7 +335,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.population_variance().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -309,7 +361,7 @@ pub trait Statistics<T> {
     /// use std::f64;
     /// use statrs::statistics::Statistics;
     ///
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.population_std_dev().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -345,16 +397,16 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
-    /// assert!(x.covariance(&[]).is_nan());
+    /// let x: [f64; 0] = [];
+    /// assert!(x.covariance([]).is_nan());
     ///
     /// let y1 = [0.0, f64::NAN, 3.0, -2.0];
     /// let y2 = [-5.0, 4.0, 10.0, f64::NAN];
-    /// assert!(y1.covariance(&y2).is_nan());
+    /// assert!((&y1).covariance(&y2).is_nan());
     ///
     /// let z1 = [0.0, 3.0, -2.0];
     /// let z2 = [-5.0, 4.0, 10.0];
-    /// assert_almost_eq!(z1.covariance(&z2), -5.5, 1e-14);
+    /// assert_almost_eq!((&z1).covariance(&z2), -5.5, 1e-14);
     /// # }
     /// ```
     fn covariance(self, other: Self) -> T;
@@ -382,16 +434,16 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
-    /// assert!(x.population_covariance(&[]).is_nan());
+    /// let x: [f64; 0] = [];
+    /// assert!(x.population_covariance([]).is_nan());
     ///
     /// let y1 = [0.0, f64::NAN, 3.0, -2.0];
     /// let y2 = [-5.0, 4.0, 10.0, f64::NAN];
-    /// assert!(y1.population_covariance(&y2).is_nan());
+    /// assert!((&y1).population_covariance(&y2).is_nan());
     ///
     /// let z1 = [0.0, 3.0, -2.0];
     /// let z2 = [-5.0, 4.0, 10.0];
-    /// assert_almost_eq!(z1.population_covariance(&z2), -11.0 / 3.0, 1e-14);
+    /// assert_almost_eq!((&z1).population_covariance(&z2), -11.0 / 3.0, 1e-14);
     /// # }
     /// ```
     fn population_covariance(self, other: Self) -> T;
@@ -412,7 +464,7 @@ pub trait Statistics<T> {
     /// use statrs::statistics::Statistics;
     ///
     /// # fn main() {
-    /// let x = [];
+    /// let x: [f64; 0] = [];
     /// assert!(x.quadratic_mean().is_nan());
     ///
     /// let y = [0.0, f64::NAN, 3.0, -2.0];
@@ -424,4 +476,272 @@ pub trait Statistics<T> {
     /// # }
     /// ```
     fn quadratic_mean(self) -> T;
+
+    /// Evaluates the weighted mean of the data with the weights given
+    /// in `weights`
+    ///
+    /// # Remarks
+    ///
+    /// The `weighted_*` methods are provided for containers of `f64` by
+    /// pairing the data with the weights and forwarding to
+    /// `WeightedStatistics`, so implementors need not define them.
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0` or an
+    /// entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the two containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x = [1.0, 2.0, 4.0];
+    /// let w = [3.0, 1.0, 0.0];
+    /// assert_eq!((&x).weighted_mean(&w).unwrap(), 1.25);
+    /// assert!(x[..].weighted_mean(&w[..2]).is_err());
+    /// assert!((&x).weighted_mean(&[1.0, -2.0, 1.0]).is_err());
+    /// ```
+    fn weighted_mean(self, weights: Self) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?.weighted_mean().map(T::from)
+    }
+
+    /// Estimates the unbiased weighted population variance from the provided
+    /// samples and weights
+    ///
+    /// # Remarks
+    ///
+    /// The normalizer depends on `kind`, see `WeightKind`. With unit
+    /// frequency weights this is equal to `variance`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or an entry is
+    /// `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the two containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::{Statistics, WeightKind};
+    ///
+    /// # fn main() {
+    /// let x = [0.0, 3.0, -2.0];
+    /// let w = [2.0, 1.0, 1.0];
+    /// // equivalent to the unweighted [0.0, 0.0, 3.0, -2.0]
+    /// let freq = (&x).weighted_variance(&w, WeightKind::Frequency).unwrap();
+    /// assert_almost_eq!(freq, 17.0 / 4.0, 1e-15);
+    /// let rel = (&x).weighted_variance(&w, WeightKind::Reliability).unwrap();
+    /// assert_almost_eq!(rel, 51.0 / 10.0, 1e-15);
+    /// # }
+    /// ```
+    fn weighted_variance(self, weights: Self, kind: WeightKind) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?
+            .weighted_variance(kind)
+            .map(T::from)
+    }
+
+    /// Estimates the unbiased weighted population standard deviation from
+    /// the provided samples and weights
+    ///
+    /// # Remarks
+    ///
+    /// The normalizer depends on `kind`, see `WeightKind`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or an entry is
+    /// `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the two containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::{Statistics, WeightKind};
+    ///
+    /// # fn main() {
+    /// let x = [0.0, 3.0, -2.0];
+    /// let w = [2.0, 1.0, 1.0];
+    /// let std_dev = (&x).weighted_std_dev(&w, WeightKind::Frequency).unwrap();
+    /// assert_almost_eq!(std_dev, (17f64 / 4.0).sqrt(), 1e-15);
+    /// # }
+    /// ```
+    fn weighted_std_dev(self, weights: Self, kind: WeightKind) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?
+            .weighted_std_dev(kind)
+            .map(T::from)
+    }
+
+    /// Evaluates the weighted population variance from a full population
+    ///
+    /// # Remarks
+    ///
+    /// The sum of the weights is used as a normalizer and would thus be
+    /// biased if applied to a subset
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0` or an
+    /// entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the two containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [0.0, 3.0, -2.0];
+    /// let w = [2.0, 1.0, 1.0];
+    /// let var = (&x).weighted_population_variance(&w).unwrap();
+    /// assert_almost_eq!(var, 51.0 / 16.0, 1e-15);
+    /// # }
+    /// ```
+    fn weighted_population_variance(self, weights: Self) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?
+            .weighted_population_variance()
+            .map(T::from)
+    }
+
+    /// Estimates the unbiased weighted population covariance between the
+    /// two provided samples sharing the weights given in `weights`
+    ///
+    /// # Remarks
+    ///
+    /// The normalizer depends on `kind`, see `WeightKind`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or an entry is
+    /// `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the three containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::{Statistics, WeightKind};
+    ///
+    /// # fn main() {
+    /// let x = [0.0, 3.0, -2.0];
+    /// let y = [-5.0, 4.0, 10.0];
+    /// let w = [1.0, 1.0, 1.0];
+    /// let cov = (&x).weighted_covariance(&y, &w, WeightKind::Frequency).unwrap();
+    /// assert_almost_eq!(cov, (&x).covariance(&y), 1e-14);
+    /// # }
+    /// ```
+    fn weighted_covariance(self, other: Self, weights: Self, kind: WeightKind) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        weighted_covariance(self, other, weights, kind).map(T::from)
+    }
+
+    /// Estimates the tau-th weighted quantile from the data, the smallest
+    /// value at which the cumulative share of the weights reaches `tau`
+    ///
+    /// # Remarks
+    ///
+    /// Where the cumulative share equals `tau` exactly, the mean of the
+    /// two adjacent values is returned, so equal weights reproduce
+    /// `QuantileDefinition::R2`. Entries with zero weight are ignored.
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0`, tau is
+    /// outside of `[0, 1]` or an entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If the two containers do not contain the same number of elements or
+    /// a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x = [3.0, 1.0, 2.0, 4.0];
+    /// let w = [1.0, 1.0, 5.0, 1.0];
+    /// assert_eq!((&x).weighted_quantile(&w, 0.5).unwrap(), 2.0);
+    /// assert_eq!((&x).weighted_quantile(&w, 0.9).unwrap(), 4.0);
+    /// ```
+    fn weighted_quantile(self, weights: Self, tau: f64) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?
+            .weighted_quantile(tau)
+            .map(T::from)
+    }
+
+    /// Estimates the weighted median of the data, see `weighted_quantile`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Statistics;
+    ///
+    /// let x = [3.0, 1.0, 2.0, 4.0];
+    /// assert_eq!((&x).weighted_median(&[1.0, 1.0, 1.0, 1.0]).unwrap(), 2.5);
+    /// assert_eq!((&x).weighted_median(&[1.0, 1.0, 5.0, 1.0]).unwrap(), 2.0);
+    /// ```
+    fn weighted_median(self, weights: Self) -> Result<T>
+    where
+        Self: Sized + IntoIterator,
+        Self::Item: Borrow<f64>,
+        T: From<f64>,
+    {
+        zip_weights(self, weights)?.weighted_median().map(T::from)
+    }
 }
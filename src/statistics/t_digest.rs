@@ -0,0 +1,509 @@
+use crate::distribution::ContinuousCDF;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use std::borrow::{Borrow, Cow};
+use std::f64;
+use std::f64::consts::PI;
+
+/// Default compression of a `TDigest`
+const DEFAULT_COMPRESSION: f64 = 100.0;
+
+// Number of unmerged values per unit of compression kept before the
+// buffer is folded into the centroids
+const BUFFER_FACTOR: f64 = 5.0;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Mergeable sketch of a distribution using the merging t-digest of
+/// Dunning and Ertl (2019), answering approximate quantile and cdf queries
+/// in memory proportional to the compression.
+///
+/// # Remarks
+///
+/// Values are summarized by weighted centroids, sized with the `k1`
+/// arcsine scale function so that the tails are resolved more finely than
+/// the center. Queries interpolate linearly between the centroid means,
+/// anchored at the exact minimum and maximum; while no values have been
+/// merged together the quantiles agree with `QuantileDefinition::R5`.
+/// `NAN` values are ignored.
+///
+/// The quantile queries mirror those of `OrderStatistics` and the cdf is
+/// exposed through `ContinuousCDF`. Pushed values are buffered and folded
+/// into the centroids in batches; the quantile queries, `merge` and
+/// `extend` do so themselves, while the queries through `ContinuousCDF`
+/// take a shared reference and merge a copy of the buffer instead.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::ContinuousCDF;
+/// use statrs::statistics::TDigest;
+///
+/// let mut a = TDigest::new(100.0).unwrap();
+/// let mut b = TDigest::new(100.0).unwrap();
+/// for i in 0..5000 {
+///     a.push(i as f64);
+///     b.push((i + 5000) as f64);
+/// }
+/// a.merge(&b);
+/// assert_eq!(a.count(), 10_000);
+/// assert!((a.quantile(0.99) - 9900.0).abs() < 10.0);
+/// assert!((a.cdf(2500.0) - 0.25).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    /// Constructs a new empty t-digest with the given compression. Larger
+    /// compressions keep more centroids, trading memory for accuracy; the
+    /// number of centroids is bounded by about `compression / 2`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `compression` is not positive and finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::TDigest;
+    ///
+    /// let mut result = TDigest::new(100.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = TDigest::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(compression: f64) -> Result<TDigest> {
+        if compression <= 0.0 || !compression.is_finite() {
+            return Err(StatsError::ArgMustBePositive("compression"));
+        }
+        Ok(TDigest {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        })
+    }
+
+    /// Returns the compression of the t-digest
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    /// Returns the number of values observed
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+
+    /// Updates the t-digest with a single value, buffering it until the
+    /// buffer fills or the next `flush`
+    pub fn push(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        self.add(Centroid {
+            mean: x,
+            weight: 1.0,
+        });
+    }
+
+    /// Combines the values summarized by `other` into this t-digest
+    pub fn merge(&mut self, other: &TDigest) {
+        for &c in other.centroids.iter().chain(other.buffer.iter()) {
+            self.add(c);
+        }
+        if other.min < self.min {
+            self.min = other.min;
+        }
+        if other.max > self.max {
+            self.max = other.max;
+        }
+        self.flush();
+    }
+
+    /// Folds the buffered values into the centroids, so that queries
+    /// through `ContinuousCDF` no longer need to merge a copy of it
+    pub fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.buffer);
+        all.append(&mut self.centroids);
+        self.centroids = merge_centroids(all, self.count, self.compression);
+    }
+
+    /// Estimates the tau-th quantile from the summarized data.
+    ///
+    /// # Remarks
+    ///
+    /// Tau must be between `0` and `1` inclusive. Returns `f64::NAN` if no
+    /// values have been observed or tau is outside the inclusive range.
+    pub fn quantile(&mut self, tau: f64) -> f64 {
+        self.flush();
+        self.quantile_compressed(tau)
+    }
+
+    /// Estimates the median value from the summarized data
+    pub fn median(&mut self) -> f64 {
+        self.quantile(0.5)
+    }
+
+    /// Estimates the p-Percentile value from the summarized data, `p` must
+    /// be between `0` and `100` inclusive
+    pub fn percentile(&mut self, p: usize) -> f64 {
+        self.quantile(p as f64 / 100.0)
+    }
+
+    /// Estimates the first quartile value from the summarized data
+    pub fn lower_quartile(&mut self) -> f64 {
+        self.quantile(0.25)
+    }
+
+    /// Estimates the third quartile value from the summarized data
+    pub fn upper_quartile(&mut self) -> f64 {
+        self.quantile(0.75)
+    }
+
+    /// Estimates the inter-quartile range from the summarized data
+    pub fn interquartile_range(&mut self) -> f64 {
+        self.upper_quartile() - self.lower_quartile()
+    }
+
+    fn add(&mut self, c: Centroid) {
+        self.count += c.weight;
+        if c.mean < self.min {
+            self.min = c.mean;
+        }
+        if c.mean > self.max {
+            self.max = c.mean;
+        }
+        self.buffer.push(c);
+        if self.buffer.len() as f64 >= BUFFER_FACTOR * self.compression {
+            self.flush();
+        }
+    }
+
+    fn quantile_compressed(&self, tau: f64) -> f64 {
+        if !(0.0..=1.0).contains(&tau) || self.count == 0.0 {
+            return f64::NAN;
+        }
+        let target = tau * self.count;
+        let centroids = self.flushed_centroids();
+        let mut prev = (0.0, self.min);
+        for (cumulative, x) in self.knots(&centroids) {
+            if target <= cumulative {
+                if cumulative == prev.0 {
+                    return x;
+                }
+                return prev.1 + (target - prev.0) / (cumulative - prev.0) * (x - prev.1);
+            }
+            prev = (cumulative, x);
+        }
+        self.max
+    }
+
+    fn cdf_compressed(&self, x: f64) -> f64 {
+        if x < self.min {
+            return 0.0;
+        }
+        if x >= self.max {
+            return 1.0;
+        }
+        let centroids = self.flushed_centroids();
+        let mut prev = (0.0, self.min);
+        for (cumulative, mean) in self.knots(&centroids) {
+            if x < mean {
+                let fraction = (x - prev.1) / (mean - prev.1);
+                return (prev.0 + fraction * (cumulative - prev.0)) / self.count;
+            }
+            prev = (cumulative, mean);
+        }
+        1.0
+    }
+
+    // The centroids as they would be after a `flush`, merging a copy of the
+    // buffer so that queries through shared references see every value
+    fn flushed_centroids(&self) -> Cow<'_, [Centroid]> {
+        if self.buffer.is_empty() {
+            return Cow::Borrowed(&self.centroids);
+        }
+        let mut all = self.buffer.clone();
+        all.extend_from_slice(&self.centroids);
+        Cow::Owned(merge_centroids(all, self.count, self.compression))
+    }
+
+    // Piecewise linear knots of the cumulative weight as a function of the
+    // value, each centroid placing half of its weight below its mean
+    fn knots<'a>(&self, centroids: &'a [Centroid]) -> impl Iterator<Item = (f64, f64)> + 'a {
+        let mut cumulative = 0.0;
+        centroids
+            .iter()
+            .map(move |c| {
+                let knot = (cumulative + c.weight / 2.0, c.mean);
+                cumulative += c.weight;
+                knot
+            })
+            .chain(std::iter::once((self.count, self.max)))
+    }
+}
+
+impl Default for TDigest {
+    fn default() -> TDigest {
+        TDigest::new(DEFAULT_COMPRESSION).unwrap()
+    }
+}
+
+impl Min<f64> for TDigest {
+    /// Returns the minimum of the observed values, `f64::NAN` if no values
+    /// have been observed
+    fn min(&self) -> f64 {
+        if self.count > 0.0 {
+            self.min
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl Max<f64> for TDigest {
+    /// Returns the maximum of the observed values, `f64::NAN` if no values
+    /// have been observed
+    fn max(&self) -> f64 {
+        if self.count > 0.0 {
+            self.max
+        } else {
+            f64::NAN
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for TDigest {
+    /// Estimates the cumulative distribution function of the summarized
+    /// data at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if no values have been observed
+    fn cdf(&self, x: f64) -> f64 {
+        if self.count == 0.0 || x.is_nan() {
+            return f64::NAN;
+        }
+        self.cdf_compressed(x)
+    }
+
+    /// Estimates the quantile function of the summarized data at `p`, see
+    /// `TDigest::quantile`
+    ///
+    /// # Errors
+    ///
+    /// Returns `StatsError::ArgIntervalIncl` if `p` is not in `[0, 1]`
+    fn checked_inverse_cdf(&self, p: f64) -> Result<f64> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+        }
+        Ok(self.quantile_compressed(p))
+    }
+}
+
+impl<T: Borrow<f64>> Extend<T> for TDigest {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(*x.borrow());
+        }
+        self.flush();
+    }
+}
+
+// Merges sorted runs of centroids greedily, growing each new centroid as
+// long as it spans at most one unit of the k1 scale function
+fn merge_centroids(mut all: Vec<Centroid>, count: f64, compression: f64) -> Vec<Centroid> {
+    all.sort_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap());
+    let k = |q: f64| compression / (2.0 * PI) * (2.0 * q - 1.0).asin();
+    let k_inv = |k: f64| {
+        if k >= compression / 4.0 {
+            1.0
+        } else {
+            ((2.0 * PI * k / compression).sin() + 1.0) / 2.0
+        }
+    };
+
+    let mut merged = Vec::with_capacity(all.len().min(compression as usize + 1));
+    let mut iter = all.into_iter();
+    let mut current = match iter.next() {
+        None => return merged,
+        Some(c) => c,
+    };
+    let mut weight_so_far = 0.0;
+    let mut q_limit = k_inv(k(0.0) + 1.0) * count;
+    for c in iter {
+        if weight_so_far + current.weight + c.weight <= q_limit {
+            current.weight += c.weight;
+            current.mean += (c.mean - current.mean) * c.weight / current.weight;
+        } else {
+            weight_so_far += current.weight;
+            q_limit = k_inv(k(weight_so_far / count) + 1.0) * count;
+            merged.push(current);
+            current = c;
+        }
+    }
+    merged.push(current);
+    merged
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use crate::distribution::{ContinuousCDF, Exp, Normal};
+    use crate::statistics::*;
+
+    fn sample<D: Distribution<f64>>(dist: D, n: usize, seed: u64) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n).map(|_| dist.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert!(TDigest::new(0.0).is_err());
+        assert!(TDigest::new(-10.0).is_err());
+        assert!(TDigest::new(f64::NAN).is_err());
+        assert!(TDigest::new(f64::INFINITY).is_err());
+        assert_eq!(TDigest::default().compression(), 100.0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut t = TDigest::default();
+        assert!(t.quantile(0.5).is_nan());
+        assert!(t.cdf(0.0).is_nan());
+        assert!(t.min().is_nan());
+        assert!(t.max().is_nan());
+        t.push(f64::NAN);
+        assert_eq!(t.count(), 0);
+    }
+
+    #[test]
+    fn test_small_data_matches_hazen() {
+        let data = [-1.0, 5.0, 0.0, -3.0, 10.0, -0.5, 4.0, 0.2, 1.0, 6.0];
+        let mut t = TDigest::default();
+        t.extend(&data);
+        for &tau in &[0.0, 0.03, 0.25, 0.5, 0.7, 0.97, 1.0] {
+            let mut copy = data;
+            assert_almost_eq!(t.quantile(tau), copy.quantile_custom(tau, QuantileDefinition::R5), 1e-14);
+        }
+        assert!(t.quantile(1.5).is_nan());
+        assert_eq!(t.cdf(-4.0), 0.0);
+        assert_eq!(t.cdf(10.0), 1.0);
+        assert_almost_eq!(t.cdf(0.2), 0.45, 1e-15);
+        assert_almost_eq!(t.cdf(0.6), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let data = sample(normal, 100_000, 1);
+        let mut t = TDigest::new(100.0).unwrap();
+        t.extend(&data);
+        let mut sorted = data.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for &tau in &[0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let estimate = t.quantile(tau);
+            let rank = sorted.iter().filter(|&&x| x <= estimate).count() as f64 / sorted.len() as f64;
+            let exact = sorted.clone().quantile_custom(tau, QuantileDefinition::R5);
+            // errors are measured in rank
+            assert_almost_eq!(rank, tau, 1e-3);
+            assert_almost_eq!(t.cdf(exact), tau, 1e-3);
+        }
+        assert_eq!(t.min(), Statistics::min(&data));
+        assert_eq!(t.max(), Statistics::max(&data));
+        assert!(t.centroids.len() <= 100);
+    }
+
+    #[test]
+    fn test_merge() {
+        let exp = Exp::new(2.0).unwrap();
+        let data = sample(exp, 50_000, 2);
+        let mut whole = TDigest::default();
+        whole.extend(&data);
+        let mut merged = TDigest::default();
+        for chunk in data.chunks(7_000) {
+            let mut part = TDigest::default();
+            part.extend(chunk);
+            merged.merge(&part);
+        }
+        assert_eq!(merged.count(), 50_000);
+        assert_eq!(merged.min(), whole.min());
+        assert_eq!(merged.max(), whole.max());
+        for &tau in &[0.01, 0.5, 0.95, 0.99] {
+            let expected = exp.inverse_cdf(tau);
+            assert_almost_eq!(merged.quantile(tau), expected, 0.02 * expected.max(0.1));
+            assert_almost_eq!(merged.quantile(tau), whole.quantile(tau), 0.02 * expected.max(0.1));
+        }
+    }
+
+    #[test]
+    fn test_cdf_inverse_cdf_consistent() {
+        let data = sample(Normal::new(5.0, 2.0).unwrap(), 10_000, 3);
+        let mut t = TDigest::default();
+        t.extend(&data);
+        for &p in &[0.05, 0.3, 0.5, 0.8, 0.95] {
+            assert_almost_eq!(t.cdf(t.inverse_cdf(p)), p, 1e-10);
+        }
+        let shared = t.inverse_cdf(0.5);
+        assert_eq!(shared, t.quantile(0.5));
+        assert!(t.checked_inverse_cdf(-0.1).is_err());
+        assert!(t.checked_inverse_cdf(1.5).is_err());
+        assert!(t.checked_inverse_cdf(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_push_then_flush() {
+        let mut t = TDigest::default();
+        for i in 0..10 {
+            t.push(i as f64);
+        }
+        t.flush();
+        assert_eq!(t.cdf(4.5), 0.5);
+        assert_eq!(t.inverse_cdf(0.5), 4.5);
+    }
+
+    #[test]
+    fn test_push_without_flush() {
+        let mut t = TDigest::default();
+        for i in (0..10).rev() {
+            t.push(i as f64);
+        }
+        assert_eq!(t.cdf(4.5), 0.5);
+        assert_eq!(t.inverse_cdf(0.5), 4.5);
+        assert!(t.checked_inverse_cdf(1.5).is_err());
+
+        let data = sample(Normal::new(0.0, 1.0).unwrap(), 10_250, 4);
+        let mut t = TDigest::default();
+        for &x in &data {
+            t.push(x);
+        }
+        assert!(!t.buffer.is_empty());
+        let mut flushed = t.clone();
+        flushed.flush();
+        for &x in &[-2.0, -0.5, 0.0, 1.0, 2.5] {
+            assert_eq!(t.cdf(x), flushed.cdf(x));
+        }
+        assert_eq!(t.inverse_cdf(0.3), flushed.quantile(0.3));
+    }
+}
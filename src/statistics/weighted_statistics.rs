@@ -0,0 +1,168 @@
+use super::WeightKind;
+use crate::Result;
+
+/// The `WeightedStatistics` trait provides weighted statistical utilities
+/// for data sets given as `(value, weight)` pairs
+///
+/// # Remarks
+///
+/// Weights must be non-negative, entries with a weight of `0` do not
+/// contribute to any of the statistics. For data and weights held in
+/// separate containers, see the `weighted_*` methods of `Statistics`.
+pub trait WeightedStatistics<T> {
+    /// Evaluates the weighted mean of the data
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0` or an
+    /// entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [(1.0, 3.0), (2.0, 1.0), (4.0, 0.0)];
+    /// assert_eq!(x.weighted_mean().unwrap(), 1.25);
+    /// assert!([(1.0, -1.0)].weighted_mean().is_err());
+    /// ```
+    fn weighted_mean(self) -> Result<T>;
+
+    /// Estimates the unbiased weighted population variance from the provided
+    /// samples
+    ///
+    /// # Remarks
+    ///
+    /// The normalizer depends on `kind`, see `WeightKind`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or an entry is
+    /// `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::{WeightedStatistics, WeightKind};
+    ///
+    /// # fn main() {
+    /// let x = [(0.0, 2.0), (3.0, 1.0), (-2.0, 1.0)];
+    /// let var = x.weighted_variance(WeightKind::Frequency).unwrap();
+    /// assert_almost_eq!(var, 17.0 / 4.0, 1e-15);
+    /// # }
+    /// ```
+    fn weighted_variance(self, kind: WeightKind) -> Result<T>;
+
+    /// Estimates the unbiased weighted population standard deviation from
+    /// the provided samples
+    ///
+    /// # Remarks
+    ///
+    /// The normalizer depends on `kind`, see `WeightKind`.
+    ///
+    /// Returns `f64::NAN` if the normalizer is not positive or an entry is
+    /// `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::{WeightedStatistics, WeightKind};
+    ///
+    /// # fn main() {
+    /// let x = [(0.0, 2.0), (3.0, 1.0), (-2.0, 1.0)];
+    /// let std_dev = x.weighted_std_dev(WeightKind::Frequency).unwrap();
+    /// assert_almost_eq!(std_dev, (17f64 / 4.0).sqrt(), 1e-15);
+    /// # }
+    /// ```
+    fn weighted_std_dev(self, kind: WeightKind) -> Result<T>;
+
+    /// Evaluates the weighted population variance from a full population
+    ///
+    /// # Remarks
+    ///
+    /// The sum of the weights is used as a normalizer and would thus be
+    /// biased if applied to a subset
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0` or an
+    /// entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// # fn main() {
+    /// let x = [(0.0, 2.0), (3.0, 1.0), (-2.0, 1.0)];
+    /// assert_almost_eq!(x.weighted_population_variance().unwrap(), 51.0 / 16.0, 1e-15);
+    /// # }
+    /// ```
+    fn weighted_population_variance(self) -> Result<T>;
+
+    /// Estimates the tau-th weighted quantile from the data, the smallest
+    /// value at which the cumulative share of the weights reaches `tau`
+    ///
+    /// # Remarks
+    ///
+    /// Where the cumulative share equals `tau` exactly, the mean of the
+    /// two adjacent values is returned, so equal weights reproduce
+    /// `QuantileDefinition::R2`.
+    ///
+    /// Returns `f64::NAN` if data is empty, the weights sum to `0`, tau is
+    /// outside of `[0, 1]` or an entry is `f64::NAN`
+    ///
+    /// # Errors
+    ///
+    /// If a weight is negative. A weight of `f64::NAN` returns
+    /// `StatsError::BadParams`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [(3.0, 1.0), (1.0, 1.0), (2.0, 5.0), (4.0, 1.0)];
+    /// assert_eq!(x.weighted_quantile(0.1).unwrap(), 1.0);
+    /// assert_eq!(x.weighted_quantile(0.9).unwrap(), 4.0);
+    /// assert!(x.weighted_quantile(1.5).unwrap().is_nan());
+    /// ```
+    fn weighted_quantile(self, tau: f64) -> Result<T>;
+
+    /// Estimates the weighted median of the data, see `weighted_quantile`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::WeightedStatistics;
+    ///
+    /// let x = [(3.0, 1.0), (1.0, 1.0), (2.0, 5.0), (4.0, 1.0)];
+    /// assert_eq!(x.weighted_median().unwrap(), 2.0);
+    /// ```
+    fn weighted_median(self) -> Result<T>;
+}
@@ -0,0 +1,565 @@
+use crate::distribution::{ContinuousCDF, FisherSnedecor};
+use crate::statistics::Median;
+use crate::{Result, StatsError};
+use std::f64;
+
+/// One row of an analysis of variance table
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AnovaRow {
+    /// The sum of squares attributed to the source of variation
+    pub sum_sq: f64,
+    /// The degrees of freedom of the source of variation
+    pub freedom: f64,
+    /// The mean square, `sum_sq / freedom`
+    pub mean_sq: f64,
+    /// The F statistic, `f64::NAN` for the residual row
+    pub statistic: f64,
+    /// The p-value of the F-test, `f64::NAN` for the residual row
+    pub p_value: f64,
+    /// The proportion of the total sum of squares attributed to the source,
+    /// `f64::NAN` for the residual row
+    pub eta_squared: f64,
+    /// The less biased estimate of the proportion of variance explained by
+    /// the source, `f64::NAN` for the residual row
+    pub omega_squared: f64,
+}
+
+impl AnovaRow {
+    fn effect(sum_sq: f64, freedom: f64, residuals: &AnovaRow, total_sum_sq: f64) -> AnovaRow {
+        let mean_sq = sum_sq / freedom;
+        let statistic = mean_sq / residuals.mean_sq;
+        AnovaRow {
+            sum_sq,
+            freedom,
+            mean_sq,
+            statistic,
+            p_value: f_p_value(freedom, residuals.freedom, statistic),
+            eta_squared: sum_sq / total_sum_sq,
+            omega_squared: (sum_sq - freedom * residuals.mean_sq)
+                / (total_sum_sq + residuals.mean_sq),
+        }
+    }
+
+    fn residuals(sum_sq: f64, freedom: f64) -> AnovaRow {
+        AnovaRow {
+            sum_sq,
+            freedom,
+            mean_sq: sum_sq / freedom,
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+            eta_squared: f64::NAN,
+            omega_squared: f64::NAN,
+        }
+    }
+}
+
+/// The analysis of variance table of a one-way layout
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OneWayAnova {
+    /// The variation between the group means
+    pub groups: AnovaRow,
+    /// The variation within the groups
+    pub residuals: AnovaRow,
+}
+
+/// The analysis of variance table of a balanced two-way layout with
+/// interaction
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TwoWayAnova {
+    /// The main effect of the first factor
+    pub factor_a: AnovaRow,
+    /// The main effect of the second factor
+    pub factor_b: AnovaRow,
+    /// The interaction between the two factors
+    pub interaction: AnovaRow,
+    /// The variation within the cells
+    pub residuals: AnovaRow,
+}
+
+/// The outcome of a test whose statistic follows an F-distribution under
+/// the null hypothesis
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FTest {
+    /// The F statistic
+    pub statistic: f64,
+    /// The numerator and denominator degrees of freedom of the reference
+    /// F-distribution
+    pub freedom: (f64, f64),
+    /// The p-value of the test
+    pub p_value: f64,
+}
+
+/// Performs a one-way analysis of variance of the null hypothesis that all
+/// `groups` are drawn from populations with the same mean
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, a group is empty or
+/// the total number of observations does not exceed the number of groups
+///
+/// # Remarks
+///
+/// Assumes normally distributed populations with a common variance; see
+/// `welch_anova` when the variances may differ. The effect sizes are
+/// `eta^2 = SS_b / SS_t` and `omega^2 = (SS_b - (k - 1) MS_w) / (SS_t + MS_w)`.
+///
+/// Returns `f64::NAN` for the affected entries if an observation is
+/// `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// SS_b = Σ N_i (x̄_i - x̄)^2, with k - 1 degrees of freedom
+/// SS_w = Σ Σ (x_ij - x̄_i)^2, with N - k degrees of freedom
+/// F = (SS_b / (k - 1)) / (SS_w / (N - k))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::one_way_anova;
+/// use statrs::prec::almost_eq;
+///
+/// let groups = [
+///     vec![4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14],
+///     vec![4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69],
+///     vec![6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26],
+/// ];
+/// let table = one_way_anova(&groups).unwrap();
+/// assert_eq!(table.residuals.freedom, 27.0);
+/// assert!(almost_eq(table.groups.statistic, 4.84608786238014, 1e-12));
+/// assert!(almost_eq(table.groups.p_value, 0.0159099583256228, 1e-12));
+/// ```
+pub fn one_way_anova<R: AsRef<[f64]>>(groups: &[R]) -> Result<OneWayAnova> {
+    check_groups(groups, 1)?;
+    let k = groups.len() as f64;
+    let total = groups.iter().map(|g| g.as_ref().len()).sum::<usize>() as f64;
+    if total <= k {
+        return Err(StatsError::ArgGtArg("sample size", "groups"));
+    }
+
+    let grand_mean = groups.iter().flat_map(|g| g.as_ref()).sum::<f64>() / total;
+    let (between, within) = groups.iter().fold((0.0, 0.0), |(between, within), g| {
+        let g = g.as_ref();
+        let mean = mean(g);
+        (
+            between + g.len() as f64 * (mean - grand_mean) * (mean - grand_mean),
+            within + g.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>(),
+        )
+    });
+    let residuals = AnovaRow::residuals(within, total - k);
+    Ok(OneWayAnova {
+        groups: AnovaRow::effect(between, k - 1.0, &residuals, between + within),
+        residuals,
+    })
+}
+
+/// Performs a two-way analysis of variance with interaction on a balanced
+/// layout, where `cells[i][j]` holds the replicates observed at level `i`
+/// of the first factor and level `j` of the second
+///
+/// # Errors
+///
+/// Returns an error if either factor has fewer than two levels, the layout
+/// is not rectangular or the cells do not all hold the same number of
+/// replicates, or there are fewer than two replicates per cell
+///
+/// # Remarks
+///
+/// Each F statistic is the ratio of the effect's mean square to the
+/// residual mean square. In a balanced layout the sums of squares are
+/// orthogonal, so the order of the factors does not matter. `eta^2` and
+/// `omega^2` are computed relative to the total sum of squares.
+///
+/// Returns `f64::NAN` for the affected entries if an observation is
+/// `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// SS_a = b r Σ (x̄_i.. - x̄)^2, with a - 1 degrees of freedom
+/// SS_b = a r Σ (x̄_.j. - x̄)^2, with b - 1 degrees of freedom
+/// SS_ab = r Σ Σ (x̄_ij. - x̄_i.. - x̄_.j. + x̄)^2, with (a - 1)(b - 1) degrees of freedom
+/// SS_e = Σ Σ Σ (x_ijk - x̄_ij.)^2, with a b (r - 1) degrees of freedom
+/// ```
+///
+/// where `a` and `b` are the numbers of levels and `r` the number of
+/// replicates per cell
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::two_way_anova;
+/// use statrs::prec::almost_eq;
+///
+/// let cells = [
+///     [[4.0, 5.0], [6.0, 6.5], [9.0, 8.0]],
+///     [[5.5, 6.5], [8.0, 9.5], [12.0, 13.0]],
+/// ];
+/// let table = two_way_anova(&cells).unwrap();
+/// assert_eq!(table.interaction.freedom, 2.0);
+/// assert_eq!(table.residuals.freedom, 6.0);
+/// assert!(almost_eq(table.factor_b.statistic, 51.2307692307692, 1e-12));
+/// assert!(table.factor_b.p_value < 0.001);
+/// ```
+pub fn two_way_anova<R, C>(cells: &[R]) -> Result<TwoWayAnova>
+where
+    R: AsRef<[C]>,
+    C: AsRef<[f64]>,
+{
+    if cells.len() < 2 {
+        return Err(StatsError::ArgGte("levels", 2.0));
+    }
+    let b = cells[0].as_ref().len();
+    if cells.iter().any(|row| row.as_ref().len() != b) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if b < 2 {
+        return Err(StatsError::ArgGte("levels", 2.0));
+    }
+    let r = cells[0].as_ref()[0].as_ref().len();
+    if cells
+        .iter()
+        .any(|row| row.as_ref().iter().any(|c| c.as_ref().len() != r))
+    {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if r < 2 {
+        return Err(StatsError::ArgGte("replicates", 2.0));
+    }
+
+    let a = cells.len();
+    let cell_means: Vec<Vec<f64>> = cells
+        .iter()
+        .map(|row| row.as_ref().iter().map(|c| mean(c.as_ref())).collect())
+        .collect();
+    let a_means: Vec<f64> = cell_means.iter().map(|row| mean(row)).collect();
+    let b_means: Vec<f64> = (0..b)
+        .map(|j| cell_means.iter().map(|row| row[j]).sum::<f64>() / a as f64)
+        .collect();
+    let grand_mean = mean(&a_means);
+
+    let (af, bf, rf) = (a as f64, b as f64, r as f64);
+    let ss_a = bf
+        * rf
+        * a_means
+            .iter()
+            .map(|m| (m - grand_mean) * (m - grand_mean))
+            .sum::<f64>();
+    let ss_b = af
+        * rf
+        * b_means
+            .iter()
+            .map(|m| (m - grand_mean) * (m - grand_mean))
+            .sum::<f64>();
+    let mut ss_ab = 0.0;
+    let mut ss_e = 0.0;
+    for (i, row) in cells.iter().enumerate() {
+        for (j, cell) in row.as_ref().iter().enumerate() {
+            let m = cell_means[i][j];
+            let d = m - a_means[i] - b_means[j] + grand_mean;
+            ss_ab += rf * d * d;
+            ss_e += cell.as_ref().iter().map(|x| (x - m) * (x - m)).sum::<f64>();
+        }
+    }
+
+    let total_sum_sq = ss_a + ss_b + ss_ab + ss_e;
+    let residuals = AnovaRow::residuals(ss_e, af * bf * (rf - 1.0));
+    Ok(TwoWayAnova {
+        factor_a: AnovaRow::effect(ss_a, af - 1.0, &residuals, total_sum_sq),
+        factor_b: AnovaRow::effect(ss_b, bf - 1.0, &residuals, total_sum_sq),
+        interaction: AnovaRow::effect(ss_ab, (af - 1.0) * (bf - 1.0), &residuals, total_sum_sq),
+        residuals,
+    })
+}
+
+/// Performs Welch's one-way analysis of variance of the null hypothesis
+/// that all `groups` are drawn from populations with the same mean, without
+/// assuming equal variances
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups or a group has fewer
+/// than two observations
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` for the statistic and p-value if an observation is
+/// `f64::NAN` or a group has zero variance.
+///
+/// # Formula
+///
+/// ```ignore
+/// w_i = N_i / s_i^2, W = Σ w_i, x̃ = Σ w_i x̄_i / W
+/// λ = Σ (1 - w_i / W)^2 / (N_i - 1) / (k^2 - 1)
+/// F = Σ w_i (x̄_i - x̃)^2 / ((k - 1) (1 + 2 (k - 2) λ))
+/// ```
+///
+/// with `k - 1` and `1 / (3 λ)` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::welch_anova;
+/// use statrs::prec::almost_eq;
+///
+/// let groups = [
+///     vec![4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14],
+///     vec![4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69],
+///     vec![6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26],
+/// ];
+/// let result = welch_anova(&groups).unwrap();
+/// assert!(almost_eq(result.freedom.1, 17.1284186166441, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.0173928214901696, 1e-12));
+/// ```
+pub fn welch_anova<R: AsRef<[f64]>>(groups: &[R]) -> Result<FTest> {
+    check_groups(groups, 2)?;
+    let k = groups.len() as f64;
+    let summaries: Vec<(f64, f64, f64)> = groups
+        .iter()
+        .map(|g| {
+            let g = g.as_ref();
+            let n = g.len() as f64;
+            let mean = mean(g);
+            let variance = g.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+            (n, mean, n / variance)
+        })
+        .collect();
+    let weight: f64 = summaries.iter().map(|&(_, _, w)| w).sum();
+    let weighted_mean = summaries.iter().map(|&(_, m, w)| w * m).sum::<f64>() / weight;
+    let lambda = summaries
+        .iter()
+        .map(|&(n, _, w)| (1.0 - w / weight) * (1.0 - w / weight) / (n - 1.0))
+        .sum::<f64>()
+        / (k * k - 1.0);
+    let statistic = summaries
+        .iter()
+        .map(|&(_, m, w)| w * (m - weighted_mean) * (m - weighted_mean))
+        .sum::<f64>()
+        / ((k - 1.0) * (1.0 + 2.0 * (k - 2.0) * lambda));
+    let freedom = (k - 1.0, 1.0 / (3.0 * lambda));
+    Ok(FTest {
+        statistic,
+        freedom,
+        p_value: f_p_value(freedom.0, freedom.1, statistic),
+    })
+}
+
+/// Performs Levene's test of the null hypothesis that all `groups` are
+/// drawn from populations with the same variance
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, a group is empty or
+/// the total number of observations does not exceed the number of groups
+///
+/// # Remarks
+///
+/// The statistic is the one-way ANOVA F statistic computed on the absolute
+/// deviations of each observation from its group mean. See
+/// `brown_forsythe` for the more robust variant using group medians.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::levene;
+/// use statrs::prec::almost_eq;
+///
+/// let groups = [
+///     vec![4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14],
+///     vec![4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69],
+///     vec![6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26],
+/// ];
+/// let result = levene(&groups).unwrap();
+/// assert!(almost_eq(result.statistic, 1.23696295446978, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.306194922991455, 1e-12));
+/// ```
+pub fn levene<R: AsRef<[f64]>>(groups: &[R]) -> Result<FTest> {
+    deviation_anova(groups, mean)
+}
+
+/// Performs the Brown–Forsythe test of the null hypothesis that all
+/// `groups` are drawn from populations with the same variance
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, a group is empty or
+/// the total number of observations does not exceed the number of groups
+///
+/// # Remarks
+///
+/// The statistic is the one-way ANOVA F statistic computed on the absolute
+/// deviations of each observation from its group median, which keeps the
+/// test valid for skewed or heavy-tailed populations. This is the default
+/// form of Levene's test in many statistical packages.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::brown_forsythe;
+/// use statrs::prec::almost_eq;
+///
+/// let groups = [
+///     vec![4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14],
+///     vec![4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69],
+///     vec![6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26],
+/// ];
+/// let result = brown_forsythe(&groups).unwrap();
+/// assert!(almost_eq(result.statistic, 1.11918569487039, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.341226624125482, 1e-12));
+/// ```
+pub fn brown_forsythe<R: AsRef<[f64]>>(groups: &[R]) -> Result<FTest> {
+    deviation_anova(groups, |g| g.median())
+}
+
+fn deviation_anova<R, F>(groups: &[R], center: F) -> Result<FTest>
+where
+    R: AsRef<[f64]>,
+    F: Fn(&[f64]) -> f64,
+{
+    check_groups(groups, 1)?;
+    let deviations: Vec<Vec<f64>> = groups
+        .iter()
+        .map(|g| {
+            let g = g.as_ref();
+            let c = center(g);
+            g.iter().map(|x| (x - c).abs()).collect()
+        })
+        .collect();
+    let table = one_way_anova(&deviations)?;
+    Ok(FTest {
+        statistic: table.groups.statistic,
+        freedom: (table.groups.freedom, table.residuals.freedom),
+        p_value: table.groups.p_value,
+    })
+}
+
+fn check_groups<R: AsRef<[f64]>>(groups: &[R], min_size: usize) -> Result<()> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().len() < min_size) {
+        return Err(StatsError::ArgGte("sample size", min_size as f64));
+    }
+    Ok(())
+}
+
+fn mean(x: &[f64]) -> f64 {
+    x.iter().sum::<f64>() / x.len() as f64
+}
+
+/// Upper tail probability of the F-distribution, `f64::NAN` if the
+/// statistic or degrees of freedom are invalid
+fn f_p_value(freedom_1: f64, freedom_2: f64, statistic: f64) -> f64 {
+    if statistic.is_nan() {
+        return f64::NAN;
+    }
+    match FisherSnedecor::new(freedom_1, freedom_2) {
+        Ok(dist) => 1.0 - dist.cdf(statistic),
+        Err(_) => f64::NAN,
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the PlantGrowth data set: dried weights of plants under a control and
+    // two treatments
+    fn plant_growth() -> Vec<Vec<f64>> {
+        vec![
+            vec![4.17, 5.58, 5.18, 6.11, 4.50, 4.61, 5.17, 4.53, 5.33, 5.14],
+            vec![4.81, 4.17, 4.41, 3.59, 5.87, 3.83, 6.03, 4.89, 4.32, 4.69],
+            vec![6.31, 5.12, 5.54, 5.50, 5.37, 5.29, 4.92, 6.15, 5.80, 5.26],
+        ]
+    }
+
+    #[test]
+    fn test_one_way_anova() {
+        let table = one_way_anova(&plant_growth()).unwrap();
+        assert_almost_eq!(table.groups.sum_sq, 3.7663400000000014, 1e-12);
+        assert_eq!(table.groups.freedom, 2.0);
+        assert_almost_eq!(table.groups.mean_sq, 1.8831700000000007, 1e-12);
+        assert_almost_eq!(table.groups.statistic, 4.846087862380138, 1e-12);
+        assert_almost_eq!(table.groups.p_value, 0.015909958325622784, 1e-12);
+        assert_almost_eq!(table.groups.eta_squared, 0.26414829683211977, 1e-12);
+        assert_almost_eq!(table.groups.omega_squared, 0.20407884598997098, 1e-12);
+        assert_almost_eq!(table.residuals.sum_sq, 10.49209, 1e-12);
+        assert_eq!(table.residuals.freedom, 27.0);
+        assert!(table.residuals.statistic.is_nan());
+    }
+
+    #[test]
+    fn test_two_way_anova() {
+        // the ToothGrowth data set: supplement type by dose
+        let cells = [
+            [
+                [4.2, 11.5, 7.3, 5.8, 6.4, 10.0, 11.2, 11.2, 5.2, 7.0],
+                [16.5, 16.5, 15.2, 17.3, 22.5, 17.3, 13.6, 14.5, 18.8, 15.5],
+                [23.6, 18.5, 33.9, 25.5, 26.4, 32.5, 26.7, 21.5, 23.3, 29.5],
+            ],
+            [
+                [15.2, 21.5, 17.6, 9.7, 14.5, 10.0, 8.2, 9.4, 16.5, 9.7],
+                [19.7, 23.3, 23.6, 26.4, 20.0, 25.2, 25.8, 21.2, 14.5, 27.3],
+                [25.5, 26.4, 22.4, 24.5, 24.8, 30.9, 26.4, 27.3, 29.4, 23.0],
+            ],
+        ];
+        let table = two_way_anova(&cells).unwrap();
+        assert_almost_eq!(table.factor_a.sum_sq, 205.35, 1e-10);
+        assert_almost_eq!(table.factor_a.statistic, 15.571979452497242, 1e-11);
+        assert_almost_eq!(table.factor_a.p_value, 0.00023118280977341995, 1e-12);
+        assert_almost_eq!(table.factor_a.eta_squared, 0.059483646607756895, 1e-12);
+        assert_almost_eq!(table.factor_a.omega_squared, 0.05545190943626194, 1e-12);
+        assert_eq!(table.factor_b.freedom, 2.0);
+        assert_almost_eq!(table.factor_b.statistic, 91.99996489286706, 1e-10);
+        assert!(table.factor_b.p_value < 1e-15);
+        assert_almost_eq!(table.factor_b.eta_squared, 0.7028641947939039, 1e-12);
+        assert_almost_eq!(table.interaction.sum_sq, 108.319, 1e-10);
+        assert_almost_eq!(table.interaction.statistic, 4.106991094022513, 1e-11);
+        assert_almost_eq!(table.interaction.p_value, 0.021860268964790968, 1e-12);
+        assert_almost_eq!(table.interaction.omega_squared, 0.0236465593883998, 1e-12);
+        assert_almost_eq!(table.residuals.sum_sq, 712.106, 1e-10);
+        assert_eq!(table.residuals.freedom, 54.0);
+    }
+
+    #[test]
+    fn test_welch_anova() {
+        let result = welch_anova(&plant_growth()).unwrap();
+        assert_almost_eq!(result.statistic, 5.180972408113188, 1e-12);
+        assert_eq!(result.freedom.0, 2.0);
+        assert_almost_eq!(result.freedom.1, 17.12841861664413, 1e-11);
+        assert_almost_eq!(result.p_value, 0.017392821490169626, 1e-12);
+    }
+
+    #[test]
+    fn test_homogeneity_of_variance() {
+        let result = levene(&plant_growth()).unwrap();
+        assert_almost_eq!(result.statistic, 1.2369629544697838, 1e-12);
+        assert_eq!(result.freedom, (2.0, 27.0));
+        assert_almost_eq!(result.p_value, 0.306194922991455, 1e-12);
+
+        let result = brown_forsythe(&plant_growth()).unwrap();
+        assert_almost_eq!(result.statistic, 1.1191856948703907, 1e-12);
+        assert_almost_eq!(result.p_value, 0.34122662412548244, 1e-12);
+    }
+
+    #[test]
+    fn test_nan() {
+        let groups = [vec![1.0, f64::NAN], vec![2.0, 3.0]];
+        assert!(one_way_anova(&groups).unwrap().groups.p_value.is_nan());
+        assert!(welch_anova(&groups).unwrap().p_value.is_nan());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(one_way_anova(&[[1.0, 2.0]]).is_err());
+        assert!(one_way_anova(&[vec![1.0], vec![]]).is_err());
+        assert!(one_way_anova(&[[1.0], [2.0]]).is_err());
+        assert!(welch_anova(&[vec![1.0, 2.0], vec![3.0]]).is_err());
+        assert!(two_way_anova(&[[[1.0, 2.0], [3.0, 4.0]]]).is_err());
+        assert!(two_way_anova(&[[[1.0], [3.0]], [[1.0], [3.0]]]).is_err());
+        assert!(two_way_anova(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 2.0], vec![3.0]]]).is_err());
+        assert!(two_way_anova(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 2.0]]]).is_err());
+    }
+}
@@ -0,0 +1,428 @@
+use crate::distribution::{Categorical, Discrete};
+use crate::function::gamma;
+use crate::statistics::Max;
+use crate::{Result, StatsError};
+use std::f64;
+
+/// The outcome of a chi-squared or G-test on a table of counts
+///
+/// The chi-squared approximation to the distribution of the statistic
+/// becomes unreliable when expected counts are small. A common rule of
+/// thumb (Cochran) asks for every expected count to be at least one and
+/// for no more than a fifth of them to fall below five; `min_expected` and
+/// `small_expected` report how a test fares against it.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChiSquaredTest {
+    /// The test statistic
+    pub statistic: f64,
+    /// The degrees of freedom of the reference chi-squared distribution
+    pub freedom: f64,
+    /// The p-value, the upper tail probability of the statistic
+    pub p_value: f64,
+    /// The smallest expected count of any cell
+    pub min_expected: f64,
+    /// The number of cells with an expected count below five
+    pub small_expected: usize,
+}
+
+/// Performs Pearson's chi-squared goodness-of-fit test of the `observed`
+/// counts against the `expected` frequencies
+///
+/// # Errors
+///
+/// Returns an error if `observed` and `expected` differ in length, an
+/// observed count is negative or all are zero, an expected frequency is not
+/// positive, or if fewer than one degree of freedom remains
+///
+/// # Remarks
+///
+/// `expected` is rescaled to the observed total, so it may be given as
+/// counts, probabilities or unnormalized weights. `ddof` is the number of
+/// parameters estimated from the data to obtain `expected`; the test has
+/// `k - 1 - ddof` degrees of freedom for `k` categories.
+///
+/// # Formula
+///
+/// ```ignore
+/// Σ (O_i - E_i)^2 / E_i
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_gof;
+/// use statrs::prec::almost_eq;
+///
+/// let observed = [16.0, 18.0, 16.0, 14.0, 12.0, 12.0];
+/// let result = chi_squared_gof(&observed, &[1.0; 6], 0).unwrap();
+/// assert_eq!(result.statistic, 2.0);
+/// assert!(almost_eq(result.p_value, 0.849145036084610, 1e-12));
+/// ```
+pub fn chi_squared_gof(observed: &[f64], expected: &[f64], ddof: usize) -> Result<ChiSquaredTest> {
+    gof(observed, expected, ddof, Statistic::Pearson)
+}
+
+/// Performs Pearson's chi-squared goodness-of-fit test of the `observed`
+/// counts of each category of `dist`
+///
+/// # Errors
+///
+/// Returns an error if the length of `observed` differs from the number of
+/// categories of `dist`, or under the same conditions as `chi_squared_gof`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Categorical;
+/// use statrs::stats_tests::chi_squared_gof_categorical;
+///
+/// let dist = Categorical::new(&[0.1, 0.2, 0.3, 0.4]).unwrap();
+/// let result = chi_squared_gof_categorical(&[8.0, 22.0, 25.0, 45.0], &dist, 0).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn chi_squared_gof_categorical(
+    observed: &[f64],
+    dist: &Categorical,
+    ddof: usize,
+) -> Result<ChiSquaredTest> {
+    if observed.len() as u64 != dist.max() + 1 {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let expected: Vec<f64> = (0..observed.len() as u64).map(|i| dist.pmf(i)).collect();
+    gof(observed, &expected, ddof, Statistic::Pearson)
+}
+
+/// Performs the G-test (likelihood-ratio) goodness-of-fit test of the
+/// `observed` counts against the `expected` frequencies
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as `chi_squared_gof`
+///
+/// # Remarks
+///
+/// `expected` and `ddof` are interpreted as in `chi_squared_gof`. Categories
+/// with an observed count of zero contribute nothing to the statistic.
+///
+/// # Formula
+///
+/// ```ignore
+/// 2 Σ O_i ln(O_i / E_i)
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::g_test_gof;
+///
+/// let result = g_test_gof(&[8.0, 22.0, 25.0, 45.0], &[1.0, 2.0, 3.0, 4.0], 0).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn g_test_gof(observed: &[f64], expected: &[f64], ddof: usize) -> Result<ChiSquaredTest> {
+    gof(observed, expected, ddof, Statistic::LikelihoodRatio)
+}
+
+/// Performs Pearson's chi-squared test of independence of the rows and
+/// columns of an r×c contingency `table` of counts
+///
+/// # Errors
+///
+/// Returns an error if the rows of `table` differ in length, the table has
+/// fewer than two rows or columns, a count is negative, or a row or column
+/// total is zero
+///
+/// # Remarks
+///
+/// When `yates` is true and the table is 2×2, Yates' continuity correction
+/// is applied: each `|O - E|` is reduced by `0.5`, or by the smallest
+/// `|O - E|` if that is less. The expected counts are the products of the
+/// row and column totals divided by the grand total, and the test has
+/// `(r - 1)(c - 1)` degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_independence;
+/// use statrs::prec::almost_eq;
+///
+/// let table = [[10.0, 10.0, 20.0], [20.0, 20.0, 20.0]];
+/// let result = chi_squared_independence(&table, false).unwrap();
+/// assert_eq!(result.freedom, 2.0);
+/// assert!(almost_eq(result.statistic, 2.777777777777778, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.249352208777296, 1e-12));
+/// ```
+pub fn chi_squared_independence<R: AsRef<[f64]>>(
+    table: &[R],
+    yates: bool,
+) -> Result<ChiSquaredTest> {
+    independence(table, yates, Statistic::Pearson)
+}
+
+/// Performs the G-test (likelihood-ratio) of independence of the rows and
+/// columns of an r×c contingency `table` of counts
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as `chi_squared_independence`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::g_test_independence;
+/// use statrs::prec::almost_eq;
+///
+/// let table = vec![vec![10.0, 10.0, 20.0], vec![20.0, 20.0, 20.0]];
+/// let result = g_test_independence(&table).unwrap();
+/// assert!(almost_eq(result.statistic, 2.768858761678132, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.250466680109542, 1e-12));
+/// ```
+pub fn g_test_independence<R: AsRef<[f64]>>(table: &[R]) -> Result<ChiSquaredTest> {
+    independence(table, false, Statistic::LikelihoodRatio)
+}
+
+/// Upper tail probability of the chi-squared distribution with `freedom`
+/// degrees of freedom at `x`
+pub(crate) fn chi_squared_sf(freedom: f64, x: f64) -> f64 {
+    if x.is_nan() || freedom.is_nan() {
+        f64::NAN
+    } else if x <= 0.0 {
+        1.0
+    } else if x.is_infinite() {
+        0.0
+    } else {
+        gamma::gamma_ur(freedom / 2.0, x / 2.0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Statistic {
+    Pearson,
+    LikelihoodRatio,
+}
+
+impl Statistic {
+    /// Contribution of a single cell, with `|O - E|` reduced by `correction`
+    fn cell(self, observed: f64, expected: f64, correction: f64) -> f64 {
+        match self {
+            Statistic::Pearson => {
+                let d = (observed - expected).abs() - correction;
+                d * d / expected
+            }
+            Statistic::LikelihoodRatio => {
+                if observed > 0.0 {
+                    2.0 * observed * (observed / expected).ln()
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+fn check_counts(counts: &[f64]) -> Result<()> {
+    if counts.iter().all(|&c| c >= 0.0 && c.is_finite()) {
+        Ok(())
+    } else {
+        Err(StatsError::ArgNotNegative("observed"))
+    }
+}
+
+fn gof(
+    observed: &[f64],
+    expected: &[f64],
+    ddof: usize,
+    statistic: Statistic,
+) -> Result<ChiSquaredTest> {
+    if observed.len() != expected.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    check_counts(observed)?;
+    if !expected.iter().all(|&e| e > 0.0 && e.is_finite()) {
+        return Err(StatsError::ArgMustBePositive("expected"));
+    }
+    let k = observed.len();
+    if k < ddof + 2 {
+        return Err(StatsError::ArgLt("ddof", k as f64 - 1.0));
+    }
+    let total: f64 = observed.iter().sum();
+    if total <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("observed total"));
+    }
+
+    let scale = total / expected.iter().sum::<f64>();
+    let cells = observed.iter().zip(expected).map(|(&o, &e)| (o, e * scale));
+    Ok(finish(cells, (k - 1 - ddof) as f64, 0.0, statistic))
+}
+
+fn independence<R: AsRef<[f64]>>(
+    table: &[R],
+    yates: bool,
+    statistic: Statistic,
+) -> Result<ChiSquaredTest> {
+    let rows = table.len();
+    if rows < 2 {
+        return Err(StatsError::ArgGte("rows", 2.0));
+    }
+    let cols = table[0].as_ref().len();
+    if table.iter().any(|row| row.as_ref().len() != cols) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if cols < 2 {
+        return Err(StatsError::ArgGte("columns", 2.0));
+    }
+    for row in table {
+        check_counts(row.as_ref())?;
+    }
+
+    let row_totals: Vec<f64> = table.iter().map(|row| row.as_ref().iter().sum()).collect();
+    let col_totals: Vec<f64> = (0..cols)
+        .map(|j| table.iter().map(|row| row.as_ref()[j]).sum())
+        .collect();
+    if row_totals.iter().chain(&col_totals).any(|&t| t <= 0.0) {
+        return Err(StatsError::ArgMustBePositive("row and column totals"));
+    }
+    let total: f64 = row_totals.iter().sum();
+
+    let cells: Vec<(f64, f64)> = table
+        .iter()
+        .zip(&row_totals)
+        .flat_map(|(row, &row_total)| {
+            row.as_ref()
+                .iter()
+                .zip(&col_totals)
+                .map(move |(&o, &col_total)| (o, row_total * col_total / total))
+        })
+        .collect();
+    let freedom = ((rows - 1) * (cols - 1)) as f64;
+    let correction = if yates && rows == 2 && cols == 2 {
+        cells
+            .iter()
+            .fold(0.5, |acc: f64, &(o, e)| acc.min((o - e).abs()))
+    } else {
+        0.0
+    };
+    Ok(finish(cells.into_iter(), freedom, correction, statistic))
+}
+
+fn finish<I>(cells: I, freedom: f64, correction: f64, statistic: Statistic) -> ChiSquaredTest
+where
+    I: Iterator<Item = (f64, f64)>,
+{
+    let mut stat = 0.0;
+    let mut min_expected = f64::INFINITY;
+    let mut small_expected = 0;
+    for (o, e) in cells {
+        stat += statistic.cell(o, e, correction);
+        min_expected = min_expected.min(e);
+        if e < 5.0 {
+            small_expected += 1;
+        }
+    }
+    ChiSquaredTest {
+        statistic: stat,
+        freedom,
+        p_value: chi_squared_sf(freedom, stat),
+        min_expected,
+        small_expected,
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gof() {
+        let observed = [16.0, 18.0, 16.0, 14.0, 12.0, 12.0];
+        let result = chi_squared_gof(&observed, &[1.0; 6], 0).unwrap();
+        assert_eq!(result.statistic, 2.0);
+        assert_eq!(result.freedom, 5.0);
+        assert_almost_eq!(result.p_value, 0.8491450360846096, 1e-13);
+        assert_eq!(result.min_expected, 88.0 / 6.0);
+        assert_eq!(result.small_expected, 0);
+
+        let result = chi_squared_gof(&observed, &[1.0; 6], 1).unwrap();
+        assert_eq!(result.freedom, 4.0);
+        assert_almost_eq!(result.p_value, 0.7357588823428847, 1e-13);
+
+        // probabilities and counts give the same answer
+        let counts = chi_squared_gof(&observed, &[16.0, 16.0, 16.0, 16.0, 16.0, 8.0], 0).unwrap();
+        let probs = chi_squared_gof(&observed, &[0.2, 0.2, 0.2, 0.2, 0.2, 0.1], 0).unwrap();
+        assert_almost_eq!(counts.statistic, 3.5, 1e-14);
+        assert_almost_eq!(probs.statistic, 3.5, 1e-14);
+    }
+
+    #[test]
+    fn test_gof_categorical() {
+        let observed = [8.0, 22.0, 25.0, 45.0];
+        let dist = Categorical::new(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+        let result = chi_squared_gof_categorical(&observed, &dist, 0).unwrap();
+        assert_almost_eq!(result.statistic, 2.0583333333333336, 1e-14);
+        assert_eq!(result.freedom, 3.0);
+        assert_almost_eq!(result.p_value, 0.5603880940389339, 1e-12);
+        assert!(chi_squared_gof_categorical(&observed[..3], &dist, 0).is_err());
+
+        let result = g_test_gof(&observed, &[1.0, 2.0, 3.0, 4.0], 0).unwrap();
+        assert_almost_eq!(result.statistic, 2.107746459739724, 1e-14);
+        assert_almost_eq!(result.p_value, 0.5503471922151044, 1e-12);
+    }
+
+    #[test]
+    fn test_independence() {
+        let table = [[10.0, 10.0, 20.0], [20.0, 20.0, 20.0]];
+        let result = chi_squared_independence(&table, true).unwrap();
+        assert_almost_eq!(result.statistic, 2.7777777777777777, 1e-14);
+        assert_eq!(result.freedom, 2.0);
+        assert_almost_eq!(result.p_value, 0.24935220877729622, 1e-12);
+        assert_eq!(result.min_expected, 12.0);
+
+        let result = g_test_independence(&table).unwrap();
+        assert_almost_eq!(result.statistic, 2.768858761678132, 1e-14);
+        assert_almost_eq!(result.p_value, 0.2504666801095417, 1e-12);
+    }
+
+    #[test]
+    fn test_independence_yates() {
+        let table = [[12.0, 5.0], [3.0, 9.0]];
+        let result = chi_squared_independence(&table, true).unwrap();
+        assert_almost_eq!(result.statistic, 4.171457749766574, 1e-13);
+        assert_eq!(result.freedom, 1.0);
+        assert_almost_eq!(result.p_value, 0.04111041419430707, 1e-12);
+        assert_eq!(result.small_expected, 0);
+
+        let result = chi_squared_independence(&table, false).unwrap();
+        assert_almost_eq!(result.statistic, 5.85483193277311, 1e-13);
+        assert_almost_eq!(result.p_value, 0.015534341414683482, 1e-12);
+    }
+
+    #[test]
+    fn test_small_expected() {
+        let table = [[3.0, 1.0], [1.0, 3.0]];
+        let result = chi_squared_independence(&table, false).unwrap();
+        assert_eq!(result.min_expected, 2.0);
+        assert_eq!(result.small_expected, 4);
+    }
+
+    #[test]
+    fn test_perfect_fit() {
+        let result = chi_squared_gof(&[5.0, 5.0], &[1.0, 1.0], 0).unwrap();
+        assert_eq!(result.statistic, 0.0);
+        assert_eq!(result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(chi_squared_gof(&[1.0, 2.0], &[1.0], 0).is_err());
+        assert!(chi_squared_gof(&[1.0, -2.0], &[1.0, 1.0], 0).is_err());
+        assert!(chi_squared_gof(&[1.0, f64::NAN], &[1.0, 1.0], 0).is_err());
+        assert!(chi_squared_gof(&[1.0, 2.0], &[1.0, 0.0], 0).is_err());
+        assert!(chi_squared_gof(&[0.0, 0.0], &[1.0, 1.0], 0).is_err());
+        assert!(chi_squared_gof(&[1.0, 2.0], &[1.0, 1.0], 1).is_err());
+        assert!(chi_squared_gof(&[1.0], &[1.0], 0).is_err());
+        assert!(chi_squared_independence(&[[1.0, 2.0]], false).is_err());
+        assert!(chi_squared_independence(&[[1.0], [2.0]], false).is_err());
+        assert!(chi_squared_independence(&[vec![1.0, 2.0], vec![1.0]], false).is_err());
+        assert!(chi_squared_independence(&[[1.0, 0.0], [2.0, 0.0]], false).is_err());
+    }
+}
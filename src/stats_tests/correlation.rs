@@ -0,0 +1,438 @@
+use crate::distribution::{ContinuousCDF, StudentsT};
+use crate::function::{erf, factorial};
+use crate::statistics::{OrderStatistics, RankTieBreaker};
+use crate::{Result, StatsError};
+use std::f64;
+
+/// The outcome of a test for association between two paired samples
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Correlation {
+    /// The sample correlation coefficient, between `-1` and `1`
+    pub coefficient: f64,
+    /// The two-sided p-value for the null hypothesis that the samples are
+    /// not associated
+    pub p_value: f64,
+}
+
+impl Correlation {
+    fn nan() -> Correlation {
+        Correlation {
+            coefficient: f64::NAN,
+            p_value: f64::NAN,
+        }
+    }
+}
+
+/// Computes the Pearson product-moment correlation coefficient of the paired
+/// samples `x` and `y` and the two-sided p-value for the null hypothesis
+/// that the true correlation is zero
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` differ in length or contain fewer than
+/// three observations
+///
+/// # Remarks
+///
+/// The p-value is computed from Student's t-distribution with `N - 2`
+/// degrees of freedom and assumes the samples are drawn from a bivariate
+/// normal distribution. Returns `f64::NAN` for both the coefficient and the
+/// p-value if either sample is constant or an entry is `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// r = Σ(x_i - x̄)(y_i - ȳ) / sqrt(Σ(x_i - x̄)^2 Σ(y_i - ȳ)^2)
+/// t = r * sqrt((N - 2) / (1 - r^2))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::pearson;
+/// use statrs::prec::almost_eq;
+///
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [10.0, 9.0, 2.5, 6.0, 4.0];
+/// let result = pearson(&x, &y).unwrap();
+/// assert!(almost_eq(result.coefficient, -0.742610657232506, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.150555808853446, 1e-12));
+/// ```
+pub fn pearson(x: &[f64], y: &[f64]) -> Result<Correlation> {
+    check_samples(x, y, 3)?;
+    let n = x.len() as f64;
+    let mean_x = x.iter().sum::<f64>() / n;
+    let mean_y = y.iter().sum::<f64>() / n;
+    let mut sxx = 0.0;
+    let mut syy = 0.0;
+    let mut sxy = 0.0;
+    for (&a, &b) in x.iter().zip(y) {
+        let dx = a - mean_x;
+        let dy = b - mean_y;
+        sxx += dx * dx;
+        syy += dy * dy;
+        sxy += dx * dy;
+    }
+    let r = (sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0);
+    Ok(Correlation {
+        coefficient: r,
+        p_value: correlation_p_value(r, n - 2.0),
+    })
+}
+
+/// Computes Spearman's rank correlation coefficient of the paired samples
+/// `x` and `y` and the two-sided p-value for the null hypothesis that the
+/// samples are not monotonically associated
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` differ in length or contain fewer than
+/// three observations
+///
+/// # Remarks
+///
+/// Tied values receive their average rank (`RankTieBreaker::Average`) and
+/// the coefficient is the Pearson correlation of the ranks. The p-value uses
+/// the same Student's t approximation as `pearson`, which is reasonable for
+/// `N` of about ten or more. Returns `f64::NAN` for both the coefficient and
+/// the p-value if either sample is constant or an entry is `f64::NAN`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::spearman;
+/// use statrs::prec::almost_eq;
+///
+/// let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let y = [5.0, 6.0, 7.0, 8.0, 7.0];
+/// let result = spearman(&x, &y).unwrap();
+/// assert!(almost_eq(result.coefficient, 0.820782681668123, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.088587005313544, 1e-12));
+/// ```
+pub fn spearman(x: &[f64], y: &[f64]) -> Result<Correlation> {
+    check_samples(x, y, 3)?;
+    if has_nan(x, y) {
+        return Ok(Correlation::nan());
+    }
+    let rank_x = x.to_vec().ranks(RankTieBreaker::Average);
+    let rank_y = y.to_vec().ranks(RankTieBreaker::Average);
+    pearson(&rank_x, &rank_y)
+}
+
+/// Computes Kendall's tau-b rank correlation coefficient of the paired
+/// samples `x` and `y` and the two-sided p-value for the null hypothesis
+/// that the samples are not associated
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` differ in length or contain fewer than
+/// two observations
+///
+/// # Remarks
+///
+/// The coefficient is corrected for ties in either sample and is computed in
+/// `O(N log N)` time using Knight's algorithm. When there are no ties and
+/// either `N <= 33` or the samples are at most one swap away from perfect
+/// agreement, the p-value is exact. Otherwise it comes from the normal
+/// approximation with the tie-corrected variance of Kendall (1970).
+/// Returns `f64::NAN` for both the coefficient and the p-value if either
+/// sample is constant or an entry is `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// τ_b = (n_c - n_d) / sqrt((n_0 - n_1) * (n_0 - n_2))
+/// ```
+///
+/// where `n_c` and `n_d` are the number of concordant and discordant pairs,
+/// `n_0 = N(N - 1) / 2`, and `n_1` and `n_2` are the number of pairs tied
+/// in `x` and `y` respectively
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::kendall;
+/// use statrs::prec::almost_eq;
+///
+/// let x = [12.0, 2.0, 1.0, 12.0, 2.0];
+/// let y = [1.0, 4.0, 7.0, 1.0, 0.0];
+/// let result = kendall(&x, &y).unwrap();
+/// assert!(almost_eq(result.coefficient, -0.471404520791032, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.282745459932775, 1e-10));
+/// ```
+pub fn kendall(x: &[f64], y: &[f64]) -> Result<Correlation> {
+    check_samples(x, y, 2)?;
+    if has_nan(x, y) {
+        return Ok(Correlation::nan());
+    }
+
+    let n = x.len() as u64;
+    let mut pairs: Vec<(f64, f64)> = x.iter().cloned().zip(y.iter().cloned()).collect();
+    pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let x_ties = tie_groups(&pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+    let joint_ties = tie_groups(&pairs);
+
+    // with the pairs ordered by x (and by y within ties in x), every
+    // inversion left in the y sequence is a discordant pair
+    let mut ys: Vec<f64> = pairs.iter().map(|p| p.1).collect();
+    let mut buf = ys.clone();
+    let discordant = sort_counting_inversions(&mut ys, &mut buf);
+    let y_ties = tie_groups(&ys);
+
+    let n0 = n * (n - 1) / 2;
+    let n1 = tied_pairs(&x_ties);
+    let n2 = tied_pairs(&y_ties);
+    let n3 = tied_pairs(&joint_ties);
+    let s = n0 as f64 - n1 as f64 - n2 as f64 + n3 as f64 - 2.0 * discordant as f64;
+    let tau = (s / ((n0 - n1) as f64 * (n0 - n2) as f64).sqrt()).clamp(-1.0, 1.0);
+    if tau.is_nan() {
+        return Ok(Correlation::nan());
+    }
+
+    let min_swaps = discordant.min(n0 - discordant);
+    let p_value = if n1 == 0 && n2 == 0 && (n <= 33 || min_swaps <= 1) {
+        kendall_exact_p_value(n, min_swaps)
+    } else {
+        let nf = n as f64;
+        let m = nf * (nf - 1.0);
+        let (x0, x1) = tie_variance_terms(&x_ties);
+        let (y0, y1) = tie_variance_terms(&y_ties);
+        let mut variance =
+            (m * (2.0 * nf + 5.0) - x1 - y1) / 18.0 + 2.0 * n1 as f64 * n2 as f64 / m;
+        if n > 2 {
+            variance += x0 * y0 / (9.0 * m * (nf - 2.0));
+        }
+        let z = s / variance.sqrt();
+        erf::erfc(z.abs() / f64::consts::SQRT_2)
+    };
+    Ok(Correlation {
+        coefficient: tau,
+        p_value,
+    })
+}
+
+fn check_samples(x: &[f64], y: &[f64], min_len: usize) -> Result<()> {
+    if x.len() != y.len() {
+        Err(StatsError::ContainersMustBeSameLength)
+    } else if x.len() < min_len {
+        Err(StatsError::ArgGte("sample size", min_len as f64))
+    } else {
+        Ok(())
+    }
+}
+
+fn has_nan(x: &[f64], y: &[f64]) -> bool {
+    x.iter().chain(y).any(|v| v.is_nan())
+}
+
+/// Two-sided p-value of a correlation coefficient `r` under the t
+/// approximation with `freedom` degrees of freedom
+fn correlation_p_value(r: f64, freedom: f64) -> f64 {
+    if r.is_nan() {
+        return f64::NAN;
+    }
+    let t = r * (freedom / ((1.0 - r) * (1.0 + r))).sqrt();
+    let dist = StudentsT::new(0.0, 1.0, freedom).unwrap();
+    (2.0 * dist.cdf(-t.abs())).min(1.0)
+}
+
+/// Returns the sizes of the groups of equal values in sorted data, ignoring
+/// values that occur only once
+fn tie_groups<T: PartialEq>(sorted: &[T]) -> Vec<u64> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for i in 1..=sorted.len() {
+        if i == sorted.len() || sorted[i] != sorted[start] {
+            if i - start > 1 {
+                groups.push((i - start) as u64);
+            }
+            start = i;
+        }
+    }
+    groups
+}
+
+fn tied_pairs(groups: &[u64]) -> u64 {
+    groups.iter().map(|&t| t * (t - 1) / 2).sum()
+}
+
+fn tie_variance_terms(groups: &[u64]) -> (f64, f64) {
+    groups.iter().fold((0.0, 0.0), |(v0, v1), &t| {
+        let t = t as f64;
+        (
+            v0 + t * (t - 1.0) * (t - 2.0),
+            v1 + t * (t - 1.0) * (2.0 * t + 5.0),
+        )
+    })
+}
+
+/// Sorts `v` with a merge sort, using `buf` (of the same length) as scratch
+/// space, and returns the number of strict inversions in the original order
+fn sort_counting_inversions(v: &mut [f64], buf: &mut [f64]) -> u64 {
+    let n = v.len();
+    if n < 2 {
+        return 0;
+    }
+    let mid = n / 2;
+    let mut swaps = sort_counting_inversions(&mut v[..mid], &mut buf[..mid])
+        + sort_counting_inversions(&mut v[mid..], &mut buf[mid..]);
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < mid && j < n {
+        if v[j] < v[i] {
+            buf[k] = v[j];
+            j += 1;
+            swaps += (mid - i) as u64;
+        } else {
+            buf[k] = v[i];
+            i += 1;
+        }
+        k += 1;
+    }
+    buf[k..k + mid - i].copy_from_slice(&v[i..mid]);
+    k += mid - i;
+    buf[k..].copy_from_slice(&v[j..]);
+    v.copy_from_slice(buf);
+    swaps
+}
+
+/// Exact two-sided p-value of Kendall's tau for `n` untied observations
+/// whose orderings differ by `c` swaps, with `c` at most half the number
+/// of pairs
+fn kendall_exact_p_value(n: u64, c: u64) -> f64 {
+    if n <= 2 || 2 * c == n * (n - 1) / 2 {
+        return 1.0;
+    }
+    if c == 0 {
+        return (2.0 / factorial::factorial(n)).min(1.0);
+    }
+    if c == 1 {
+        return (2.0 / factorial::factorial(n - 1)).min(1.0);
+    }
+
+    // counts[k] is the number of permutations of j elements with exactly k
+    // inversions (the Mahonian numbers), built up one element at a time and
+    // truncated at c
+    let c = c as usize;
+    let mut counts = vec![0.0; c + 1];
+    counts[0] = 1.0;
+    counts[1] = 1.0;
+    for j in 3..=n as usize {
+        for k in 1..=c {
+            counts[k] += counts[k - 1];
+        }
+        for k in (j..=c).rev() {
+            counts[k] -= counts[k - j];
+        }
+    }
+    (2.0 * counts.iter().sum::<f64>() / factorial::factorial(n)).min(1.0)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pearson() {
+        let result = pearson(&[1.0, 2.0, 3.0, 4.0, 5.0], &[10.0, 9.0, 2.5, 6.0, 4.0]).unwrap();
+        assert_almost_eq!(result.coefficient, -0.7426106572325057, 1e-14);
+        assert_almost_eq!(result.p_value, 0.1505558088534455, 1e-12);
+
+        let perfect = pearson(&[1.0, 2.0, 3.0], &[-2.0, -4.0, -6.0]).unwrap();
+        assert_eq!(perfect.coefficient, -1.0);
+        assert_eq!(perfect.p_value, 0.0);
+
+        let constant = pearson(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).unwrap();
+        assert!(constant.coefficient.is_nan());
+        assert!(constant.p_value.is_nan());
+
+        let nan = pearson(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0]).unwrap();
+        assert!(nan.coefficient.is_nan());
+        assert!(nan.p_value.is_nan());
+    }
+
+    #[test]
+    fn test_pearson_symmetric() {
+        let x: [f64; 7] = [0.3, -1.2, 4.5, 2.2, 0.0, 1.1, -0.7];
+        let y = [1.0, 0.2, 3.1, 2.9, 0.4, 0.2, -2.0];
+        assert_eq!(pearson(&x, &y).unwrap(), pearson(&y, &x).unwrap());
+    }
+
+    #[test]
+    fn test_spearman() {
+        let result = spearman(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5.0, 6.0, 7.0, 8.0, 7.0]).unwrap();
+        assert_almost_eq!(result.coefficient, 0.8207826816681233, 1e-14);
+        assert_almost_eq!(result.p_value, 0.0885870053135438, 1e-12);
+
+        // invariant under monotone transformations
+        let x: [f64; 7] = [0.3, -1.2, 4.5, 2.2, 0.0, 1.1, -0.7];
+        let y = [1.0, 0.2, 3.1, 2.9, 0.4, 0.2, -2.0];
+        let exp_x: Vec<f64> = x.iter().map(|v| v.exp()).collect();
+        assert_eq!(spearman(&x, &y).unwrap(), spearman(&exp_x, &y).unwrap());
+
+        let nan = spearman(&[1.0, f64::NAN, 3.0], &[1.0, 2.0, 3.0]).unwrap();
+        assert!(nan.coefficient.is_nan());
+        assert!(nan.p_value.is_nan());
+    }
+
+    #[test]
+    fn test_kendall_ties() {
+        let result = kendall(&[12.0, 2.0, 1.0, 12.0, 2.0], &[1.0, 4.0, 7.0, 1.0, 0.0]).unwrap();
+        assert_almost_eq!(result.coefficient, -0.47140452079103173, 1e-14);
+        assert_almost_eq!(result.p_value, 0.28274545993277467, 1e-10);
+    }
+
+    #[test]
+    fn test_kendall_exact() {
+        // p-values from enumerating all 8! permutations
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let result = kendall(&x, &[2.0, 1.0, 4.0, 3.0, 6.0, 5.0, 8.0, 7.0]).unwrap();
+        assert_almost_eq!(result.coefficient, 0.7142857142857143, 1e-15);
+        assert_almost_eq!(result.p_value, 0.014136904761904762, 1e-15);
+
+        let result = kendall(&x, &[3.0, 1.0, 2.0, 5.0, 4.0, 7.0, 8.0, 6.0]).unwrap();
+        assert_almost_eq!(result.coefficient, 0.6428571428571429, 1e-15);
+        assert_almost_eq!(result.p_value, 0.03115079365079365, 1e-15);
+
+        let reversed: Vec<f64> = x.iter().rev().cloned().collect();
+        let result = kendall(&x, &reversed).unwrap();
+        assert_eq!(result.coefficient, -1.0);
+        assert_almost_eq!(result.p_value, 2.0 / 40320.0, 1e-18);
+
+        let result = kendall(&[1.0, 2.0], &[2.0, 1.0]).unwrap();
+        assert_eq!(result.coefficient, -1.0);
+        assert_eq!(result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_kendall_matches_brute_force() {
+        let x = [0.3, -1.2, 4.5, 2.2, 0.0, 1.1, -0.7, 2.2, 0.3, 5.0, -3.0, 0.3];
+        let y = [1.0, 0.2, 3.1, 2.9, 0.4, 0.2, -2.0, 2.0, 1.0, 0.2, 0.5, 1.5];
+        let mut s = 0.0;
+        let mut tx = 0.0;
+        let mut ty = 0.0;
+        let mut pairs: f64 = 0.0;
+        for i in 0..x.len() {
+            for j in i + 1..x.len() {
+                let dx: f64 = x[i] - x[j];
+                let dy: f64 = y[i] - y[j];
+                pairs += 1.0;
+                if dx == 0.0 { tx += 1.0; }
+                if dy == 0.0 { ty += 1.0; }
+                if dx * dy > 0.0 { s += 1.0; }
+                if dx * dy < 0.0 { s -= 1.0; }
+            }
+        }
+        let expected = s / ((pairs - tx) * (pairs - ty)).sqrt();
+        assert_almost_eq!(kendall(&x, &y).unwrap().coefficient, expected, 1e-15);
+    }
+
+    #[test]
+    fn test_bad_samples() {
+        assert!(pearson(&[1.0, 2.0, 3.0], &[1.0, 2.0]).is_err());
+        assert!(pearson(&[1.0, 2.0], &[1.0, 2.0]).is_err());
+        assert!(spearman(&[1.0, 2.0], &[1.0, 2.0]).is_err());
+        assert!(kendall(&[1.0], &[1.0]).is_err());
+        assert!(kendall(&[1.0, 2.0], &[1.0]).is_err());
+    }
+}
@@ -0,0 +1,398 @@
+use super::Alternative;
+use crate::distribution::{Binomial, Discrete, DiscreteCDF, Hypergeometric, Poisson};
+use crate::function::{beta, gamma};
+use crate::{Result, StatsError};
+use std::f64;
+
+/// Relative tolerance used when comparing probabilities for the
+/// minimum-likelihood two-sided p-value, so that outcomes as likely as the
+/// observed one are not lost to rounding
+const RELATIVE_ERROR: f64 = 1.0 + 1e-7;
+
+/// The outcome of an exact test on count data
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExactTest {
+    /// The estimate of the tested parameter
+    pub estimate: f64,
+    /// The p-value for the chosen alternative hypothesis
+    pub p_value: f64,
+}
+
+/// Performs Fisher's exact test of the null hypothesis of independence of
+/// rows and columns in the 2×2 contingency table `table`
+///
+/// # Remarks
+///
+/// Conditional on the margins, the top-left count follows a hypergeometric
+/// distribution under the null hypothesis. `Alternative::Greater` tests
+/// whether the odds ratio exceeds one. The two-sided p-value is the total
+/// probability of all tables that are no more likely than the observed one.
+///
+/// The estimate is the sample odds ratio `(a d) / (b c)`, which is
+/// `f64::INFINITY` if `b c = 0` and `f64::NAN` if additionally `a d = 0`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{fisher_exact, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// // Fisher's lady tasting tea
+/// let result = fisher_exact([[3, 1], [1, 3]], Alternative::Greater);
+/// assert_eq!(result.estimate, 9.0);
+/// assert!(almost_eq(result.p_value, 0.242857142857143, 1e-14));
+/// ```
+pub fn fisher_exact(table: [[u64; 2]; 2], alternative: Alternative) -> ExactTest {
+    let [[a, b], [c, d]] = table;
+    let estimate = (a as f64 * d as f64) / (b as f64 * c as f64);
+
+    // X = a given the first column total m = a + c, the second column total
+    // n = b + d and the first row total k = a + b
+    let (m, n, k) = (a + c, b + d, a + b);
+    let dist = Hypergeometric::new(m + n, m, k).unwrap();
+    let support = k.saturating_sub(n)..=k.min(m);
+    let pmf = |x: u64| dist.ln_pmf(x).exp();
+    let p_value: f64 = match alternative {
+        Alternative::Less => support.filter(|&x| x <= a).map(pmf).sum(),
+        Alternative::Greater => support.filter(|&x| x >= a).map(pmf).sum(),
+        Alternative::TwoSided => {
+            let observed = pmf(a) * RELATIVE_ERROR;
+            support.map(pmf).filter(|&p| p <= observed).sum()
+        }
+    };
+    ExactTest {
+        estimate,
+        p_value: p_value.min(1.0),
+    }
+}
+
+/// Performs an exact binomial test of the null hypothesis that the
+/// probability of success in `trials` Bernoulli trials is `p`, having
+/// observed `successes`
+///
+/// # Errors
+///
+/// Returns an error if `trials` is zero, `successes > trials`, or `p` is
+/// not in `[0, 1]`
+///
+/// # Remarks
+///
+/// The estimate is the observed proportion `successes / trials`. The
+/// two-sided p-value is the total probability of all outcomes that are no
+/// more likely than the observed one.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{binomial_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let result = binomial_test(682, 925, 0.75, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(result.estimate, 0.737297297297297, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.382491559574965, 1e-12));
+/// ```
+pub fn binomial_test(
+    successes: u64,
+    trials: u64,
+    p: f64,
+    alternative: Alternative,
+) -> Result<ExactTest> {
+    if trials == 0 {
+        return Err(StatsError::ArgGte("trials", 1.0));
+    }
+    if successes > trials {
+        return Err(StatsError::ArgLteArg("successes", "trials"));
+    }
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+    }
+    Ok(ExactTest {
+        estimate: successes as f64 / trials as f64,
+        p_value: binomial_p_value(successes, trials, p, alternative),
+    })
+}
+
+/// Performs an exact test of the null hypothesis that `count` events
+/// observed over `exposure` units of time (or area, population, ...) come
+/// from a Poisson process with the given `rate`
+///
+/// # Errors
+///
+/// Returns an error if `exposure` or `rate` is not positive
+///
+/// # Remarks
+///
+/// The estimate is the observed rate `count / exposure`. The two-sided
+/// p-value is the total probability of all counts that are no more likely
+/// than the observed one.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{poisson_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let result = poisson_test(10, 2.0, 3.0, Alternative::TwoSided).unwrap();
+/// assert_eq!(result.estimate, 5.0);
+/// assert!(almost_eq(result.p_value, 0.10127528223154, 1e-12));
+/// ```
+pub fn poisson_test(
+    count: u64,
+    exposure: f64,
+    rate: f64,
+    alternative: Alternative,
+) -> Result<ExactTest> {
+    if exposure.is_nan() || exposure <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("exposure"));
+    }
+    if rate.is_nan() || rate <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("rate"));
+    }
+    let mean = rate * exposure;
+    let dist = Poisson::new(mean).unwrap();
+    let cdf = |x: u64| dist.cdf(x);
+    let sf = |x: u64| {
+        if x == 0 {
+            1.0
+        } else {
+            gamma::gamma_lr(x as f64, mean)
+        }
+    };
+    let p_value = match alternative {
+        Alternative::Less => cdf(count),
+        Alternative::Greater => sf(count),
+        Alternative::TwoSided => {
+            // there is no finite support, so when the count lies below the
+            // mean search for a count above it that is less likely
+            let mut upper = (2.0 * mean - count as f64).ceil().max(1.0) as u64;
+            if (count as f64) < mean {
+                let observed = dist.pmf(count);
+                while dist.pmf(upper) > observed {
+                    upper *= 2;
+                }
+            }
+            min_likelihood_p_value(count, mean, upper, |x| dist.pmf(x), cdf, sf)
+        }
+    };
+    Ok(ExactTest {
+        estimate: count as f64 / exposure,
+        p_value,
+    })
+}
+
+/// Performs an exact test of the null hypothesis that the ratio of the
+/// rates of two Poisson processes equals `ratio`, having observed `count_1`
+/// events over `exposure_1` units in the first and `count_2` events over
+/// `exposure_2` units in the second
+///
+/// # Errors
+///
+/// Returns an error if an exposure or `ratio` is not positive
+///
+/// # Remarks
+///
+/// Conditional on the total count, `count_1` follows a binomial
+/// distribution with success probability
+/// `ratio exposure_1 / (ratio exposure_1 + exposure_2)`, which is tested
+/// with `binomial_test`. `Alternative::Greater` tests whether the first
+/// rate is more than `ratio` times the second.
+///
+/// The estimate is the observed rate ratio
+/// `(count_1 / exposure_1) / (count_2 / exposure_2)`. If no events were
+/// observed it is `f64::NAN` and the p-value is one.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{poisson_rate_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let result = poisson_rate_test(11, 800.0, 21, 3011.0, 1.0, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(result.estimate, 1.97148809523810, 1e-13));
+/// assert!(almost_eq(result.p_value, 0.0796686330333269, 1e-12));
+/// ```
+pub fn poisson_rate_test(
+    count_1: u64,
+    exposure_1: f64,
+    count_2: u64,
+    exposure_2: f64,
+    ratio: f64,
+    alternative: Alternative,
+) -> Result<ExactTest> {
+    if exposure_1.is_nan() || exposure_1 <= 0.0 || exposure_2.is_nan() || exposure_2 <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("exposure"));
+    }
+    if ratio.is_nan() || ratio <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("ratio"));
+    }
+    let estimate = (count_1 as f64 / exposure_1) / (count_2 as f64 / exposure_2);
+    let total = count_1 + count_2;
+    if total == 0 {
+        return Ok(ExactTest {
+            estimate,
+            p_value: 1.0,
+        });
+    }
+    let p = ratio * exposure_1 / (ratio * exposure_1 + exposure_2);
+    Ok(ExactTest {
+        estimate,
+        p_value: binomial_p_value(count_1, total, p, alternative),
+    })
+}
+
+fn binomial_p_value(successes: u64, trials: u64, p: f64, alternative: Alternative) -> f64 {
+    let dist = Binomial::new(p, trials).unwrap();
+    let cdf = |x: u64| dist.cdf(x);
+    let sf = |x: u64| {
+        if x == 0 {
+            1.0
+        } else if x > trials {
+            0.0
+        } else {
+            beta::beta_reg(x as f64, (trials - x) as f64 + 1.0, p)
+        }
+    };
+    match alternative {
+        Alternative::Less => cdf(successes),
+        Alternative::Greater => sf(successes),
+        Alternative::TwoSided => min_likelihood_p_value(
+            successes,
+            trials as f64 * p,
+            trials,
+            |x| dist.pmf(x),
+            cdf,
+            sf,
+        ),
+    }
+}
+
+/// Two-sided p-value of the observed count `x` for a unimodal distribution
+/// with the given `mean`: the probability of `x` or anything further from
+/// the mean, plus that of the outcomes on the other side of the mean that
+/// are no more likely than `x`. Only outcomes up to `upper` are considered
+/// beyond the mean; `cdf(k)` is `P(X <= k)` and `sf(k)` is `P(X >= k)`.
+fn min_likelihood_p_value<P, C, S>(x: u64, mean: f64, upper: u64, pmf: P, cdf: C, sf: S) -> f64
+where
+    P: Fn(u64) -> f64,
+    C: Fn(u64) -> f64,
+    S: Fn(u64) -> f64,
+{
+    let xf = x as f64;
+    if xf == mean {
+        return 1.0;
+    }
+    let observed = pmf(x) * RELATIVE_ERROR;
+    let p = if xf < mean {
+        let y = (mean.ceil() as u64..=upper)
+            .filter(|&i| pmf(i) <= observed)
+            .count() as u64;
+        cdf(x) + sf(upper - y + 1)
+    } else {
+        let y = (0..=mean.floor() as u64)
+            .filter(|&i| pmf(i) <= observed)
+            .count() as u64;
+        let lower = if y == 0 { 0.0 } else { cdf(y - 1) };
+        lower + sf(x)
+    };
+    p.min(1.0)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fisher_exact() {
+        let result = fisher_exact([[3, 1], [1, 3]], Alternative::TwoSided);
+        assert_almost_eq!(result.p_value, 0.48571428571428493, 1e-14);
+
+        let result = fisher_exact([[1, 9], [11, 3]], Alternative::TwoSided);
+        assert_almost_eq!(result.estimate, 1.0 / 33.0, 1e-15);
+        assert_almost_eq!(result.p_value, 0.002759456185220082, 1e-14);
+        let result = fisher_exact([[1, 9], [11, 3]], Alternative::Less);
+        assert_almost_eq!(result.p_value, 0.001379728092610041, 1e-14);
+        let result = fisher_exact([[1, 9], [11, 3]], Alternative::Greater);
+        assert_almost_eq!(result.p_value, 0.9999663480952992, 1e-14);
+
+        let result = fisher_exact([[10, 2], [3, 15]], Alternative::TwoSided);
+        assert_eq!(result.estimate, 25.0);
+        assert_almost_eq!(result.p_value, 0.0005367241191434327, 1e-15);
+    }
+
+    #[test]
+    fn test_fisher_exact_degenerate() {
+        let result = fisher_exact([[0, 0], [0, 0]], Alternative::TwoSided);
+        assert!(result.estimate.is_nan());
+        assert_eq!(result.p_value, 1.0);
+
+        let result = fisher_exact([[5, 0], [0, 5]], Alternative::TwoSided);
+        assert_eq!(result.estimate, f64::INFINITY);
+        assert_almost_eq!(result.p_value, 2.0 / 252.0, 1e-14);
+    }
+
+    #[test]
+    fn test_binomial_test() {
+        let result = binomial_test(682, 925, 0.75, Alternative::Greater).unwrap();
+        assert_almost_eq!(result.p_value, 0.8240891223523941, 1e-12);
+
+        let result = binomial_test(2, 10, 0.5, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 0.109375, 1e-13);
+
+        // the observed count lies above the mean
+        let result = binomial_test(3, 8, 0.1, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 0.03809179, 1e-13);
+
+        let result = binomial_test(0, 5, 0.3, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 0.33115, 1e-13);
+
+        let result = binomial_test(5, 10, 0.5, Alternative::TwoSided).unwrap();
+        assert_eq!(result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_binomial_test_boundary() {
+        assert_eq!(binomial_test(0, 5, 0.0, Alternative::TwoSided).unwrap().p_value, 1.0);
+        assert_eq!(binomial_test(1, 5, 0.0, Alternative::TwoSided).unwrap().p_value, 0.0);
+        assert_eq!(binomial_test(4, 5, 1.0, Alternative::TwoSided).unwrap().p_value, 0.0);
+        assert_eq!(binomial_test(5, 5, 1.0, Alternative::Less).unwrap().p_value, 1.0);
+    }
+
+    #[test]
+    fn test_poisson_test() {
+        let result = poisson_test(137, 24.19893, 1.0, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 2.8452272641146256e-56, 1e-66);
+
+        let result = poisson_test(2, 1.5, 3.0, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 0.3425274921846244, 1e-12);
+
+        let result = poisson_test(3, 1.0, 1.0, Alternative::Greater).unwrap();
+        assert_almost_eq!(result.p_value, 0.0803013970713942, 1e-13);
+
+        let result = poisson_test(0, 2.0, 1.0, Alternative::Less).unwrap();
+        assert_eq!(result.estimate, 0.0);
+        assert_almost_eq!(result.p_value, (-2.0f64).exp(), 1e-15);
+    }
+
+    #[test]
+    fn test_poisson_rate_test() {
+        let result = poisson_rate_test(11, 800.0, 21, 3011.0, 1.0, Alternative::Greater).unwrap();
+        assert_almost_eq!(result.p_value, 0.05600865978769749, 1e-12);
+
+        let result = poisson_rate_test(0, 1.0, 0, 2.0, 1.0, Alternative::TwoSided).unwrap();
+        assert!(result.estimate.is_nan());
+        assert_eq!(result.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(binomial_test(0, 0, 0.5, Alternative::TwoSided).is_err());
+        assert!(binomial_test(6, 5, 0.5, Alternative::TwoSided).is_err());
+        assert!(binomial_test(1, 5, 1.5, Alternative::TwoSided).is_err());
+        assert!(binomial_test(1, 5, f64::NAN, Alternative::TwoSided).is_err());
+        assert!(poisson_test(1, 0.0, 1.0, Alternative::TwoSided).is_err());
+        assert!(poisson_test(1, 1.0, -1.0, Alternative::TwoSided).is_err());
+        assert!(poisson_rate_test(1, 1.0, 1, f64::NAN, 1.0, Alternative::TwoSided).is_err());
+        assert!(poisson_rate_test(1, 1.0, 1, 1.0, 0.0, Alternative::TwoSided).is_err());
+    }
+}
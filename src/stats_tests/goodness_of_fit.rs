@@ -0,0 +1,659 @@
+use crate::distribution::{ContinuousCDF, Exp, Normal};
+use crate::function::gamma;
+use crate::statistics::Statistics;
+use crate::{Result, StatsError};
+use std::f64;
+
+/// Smallest sample for which the estimated-parameter tests are offered; the
+/// published approximations to their null distributions are not reliable
+/// below it
+const ESTIMATED_MIN_N: usize = 8;
+
+/// Significance levels of Stephens' critical-value tables for the
+/// exponential distribution
+const EXP_ALPHA: [f64; 5] = [0.15, 0.10, 0.05, 0.025, 0.01];
+const EXP_AD_CRITICAL: [f64; 5] = [0.922, 1.078, 1.341, 1.606, 1.957];
+const EXP_CVM_CRITICAL: [f64; 5] = [0.149, 0.177, 0.224, 0.273, 0.337];
+
+/// Significance levels of Stephens' critical-value tables for the extreme
+/// value (Gumbel and Weibull) distributions
+const EV_ALPHA: [f64; 5] = [0.25, 0.10, 0.05, 0.025, 0.01];
+const EV_AD_CRITICAL: [f64; 5] = [0.474, 0.637, 0.757, 0.877, 1.038];
+const EV_CVM_CRITICAL: [f64; 5] = [0.073, 0.102, 0.124, 0.146, 0.175];
+
+/// The outcome of a goodness-of-fit test comparing a sample with a reference
+/// distribution, a family of distributions, or another sample
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GoodnessOfFit {
+    /// The test statistic
+    pub statistic: f64,
+    /// The p-value for the null hypothesis that the sample is drawn from the
+    /// reference distribution
+    pub p_value: f64,
+}
+
+impl GoodnessOfFit {
+    pub(crate) fn nan() -> GoodnessOfFit {
+        GoodnessOfFit {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+        }
+    }
+}
+
+/// Distribution families whose parameters `anderson_darling_estimated` and
+/// `cramer_von_mises_estimated` estimate from the sample before testing
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EstimatedFamily {
+    /// Normal distribution with mean and standard deviation estimated by the
+    /// sample mean and sample standard deviation
+    Normal,
+    /// Exponential distribution with the rate estimated by the reciprocal of
+    /// the sample mean
+    Exp,
+    /// Weibull distribution with shape and scale estimated by maximum
+    /// likelihood
+    Weibull,
+    /// Gumbel (maximum extreme value) distribution with location and scale
+    /// estimated by maximum likelihood
+    Gumbel,
+}
+
+/// Performs the Anderson–Darling test of the null hypothesis that `x` is
+/// drawn from the continuous distribution `dist`
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty
+///
+/// # Remarks
+///
+/// The statistic weights deviations in the tails more heavily than the
+/// Kolmogorov–Smirnov statistic. The p-value uses the approximation of
+/// Marsaglia and Marsaglia (2004) to the finite-sample distribution, which
+/// is accurate to about six decimal places. The parameters of `dist` must
+/// not have been estimated from `x`; use `anderson_darling_estimated` in
+/// that case.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// A^2 = -N - (1 / N) Σ (2i - 1) (ln F(x_(i)) + ln(1 - F(x_(N + 1 - i))))
+/// ```
+///
+/// where `x_(i)` is the `i`th smallest entry of `x`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::stats_tests::anderson_darling;
+///
+/// let x = [0.61, -1.3, 0.2, 1.9, -0.45, 0.05, 0.88, -0.12, 2.4, 0.7];
+/// let normal = Normal::new(0.0, 1.0).unwrap();
+/// let result = anderson_darling(&x, &normal).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn anderson_darling<D>(x: &[f64], dist: &D) -> Result<GoodnessOfFit>
+where
+    D: ContinuousCDF<f64, f64>,
+{
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    let u = match sorted_probabilities(x, |v| dist.cdf(v)) {
+        Some(u) => u,
+        None => return Ok(GoodnessOfFit::nan()),
+    };
+    let statistic = ad_statistic(&u);
+    Ok(GoodnessOfFit {
+        statistic,
+        p_value: (1.0 - anderson_darling_cdf(u.len(), statistic)).clamp(0.0, 1.0),
+    })
+}
+
+/// Performs the Cramér–von Mises test of the null hypothesis that `x` is
+/// drawn from the continuous distribution `dist`
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty
+///
+/// # Remarks
+///
+/// The p-value applies Stephens' (1970) finite-sample modification
+/// `(W^2 - 0.4 / N + 0.6 / N^2)(1 + 1 / N)` to the statistic and evaluates
+/// the limiting distribution of Anderson and Darling (1952). The parameters
+/// of `dist` must not have been estimated from `x`; use
+/// `cramer_von_mises_estimated` in that case.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// W^2 = 1 / (12N) + Σ (F(x_(i)) - (2i - 1) / (2N))^2
+/// ```
+///
+/// where `x_(i)` is the `i`th smallest entry of `x`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::stats_tests::cramer_von_mises;
+///
+/// let x = [0.61, -1.3, 0.2, 1.9, -0.45, 0.05, 0.88, -0.12, 2.4, 0.7];
+/// let normal = Normal::new(0.0, 1.0).unwrap();
+/// let result = cramer_von_mises(&x, &normal).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn cramer_von_mises<D>(x: &[f64], dist: &D) -> Result<GoodnessOfFit>
+where
+    D: ContinuousCDF<f64, f64>,
+{
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    let u = match sorted_probabilities(x, |v| dist.cdf(v)) {
+        Some(u) => u,
+        None => return Ok(GoodnessOfFit::nan()),
+    };
+    let n = u.len() as f64;
+    let statistic = cvm_statistic(&u);
+    let modified = (statistic - 0.4 / n + 0.6 / (n * n)) * (1.0 + 1.0 / n);
+    Ok(GoodnessOfFit {
+        statistic,
+        p_value: (1.0 - cramer_von_mises_cdf(modified)).clamp(0.0, 1.0),
+    })
+}
+
+/// Performs the Anderson–Darling test of the null hypothesis that `x` is
+/// drawn from some member of `family`, with the parameters estimated from `x`
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than eight entries, or if an entry is
+/// negative for `EstimatedFamily::Exp` or not positive for
+/// `EstimatedFamily::Weibull`
+///
+/// # Remarks
+///
+/// Estimating the parameters makes the fit closer than under the fully
+/// specified test, so the statistic is referred to case-specific null
+/// distributions. For `EstimatedFamily::Normal` the p-value comes from the
+/// approximation of D'Agostino and Stephens (1986) to the distribution of
+/// `A^2 (1 + 0.75 / N + 2.25 / N^2)`. For the other families it is
+/// interpolated (linearly in `ln p`) in Stephens' tables of critical values
+/// for `A^2 (1 + 0.6 / N)` (`Exp`) or `A^2 (1 + 0.2 / sqrt(N))` (`Weibull`
+/// and `Gumbel`), and is clamped to the tabulated range: `[0.01, 0.15]` for
+/// `Exp` and `[0.01, 0.25]` for `Weibull` and `Gumbel`.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or the sample is constant.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{anderson_darling_estimated, EstimatedFamily};
+///
+/// let x = [2.1, 3.4, 1.9, 5.6, 4.4, 3.0, 2.8, 6.1, 3.3, 4.0];
+/// let result = anderson_darling_estimated(&x, EstimatedFamily::Normal).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn anderson_darling_estimated(x: &[f64], family: EstimatedFamily) -> Result<GoodnessOfFit> {
+    let u = match fitted_probabilities(x, family)? {
+        Some(u) => u,
+        None => return Ok(GoodnessOfFit::nan()),
+    };
+    let n = u.len() as f64;
+    let statistic = ad_statistic(&u);
+    let p_value = match family {
+        EstimatedFamily::Normal => {
+            let a = statistic * (1.0 + 0.75 / n + 2.25 / (n * n));
+            if a < 0.2 {
+                1.0 - (-13.436 + 101.14 * a - 223.73 * a * a).exp()
+            } else if a < 0.34 {
+                1.0 - (-8.318 + 42.796 * a - 59.938 * a * a).exp()
+            } else if a < 0.6 {
+                (0.9177 - 4.279 * a - 1.38 * a * a).exp()
+            } else if a < 10.0 {
+                (1.2937 - 5.709 * a + 0.0186 * a * a).exp()
+            } else {
+                3.7e-24
+            }
+        }
+        EstimatedFamily::Exp => {
+            interpolate_p_value(statistic * (1.0 + 0.6 / n), &EXP_AD_CRITICAL, &EXP_ALPHA)
+        }
+        EstimatedFamily::Weibull | EstimatedFamily::Gumbel => interpolate_p_value(
+            statistic * (1.0 + 0.2 / n.sqrt()),
+            &EV_AD_CRITICAL,
+            &EV_ALPHA,
+        ),
+    };
+    Ok(GoodnessOfFit {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// Performs the Cramér–von Mises test of the null hypothesis that `x` is
+/// drawn from some member of `family`, with the parameters estimated from `x`
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as
+/// `anderson_darling_estimated`
+///
+/// # Remarks
+///
+/// For `EstimatedFamily::Normal` the p-value comes from the approximation of
+/// D'Agostino and Stephens (1986) to the distribution of
+/// `W^2 (1 + 0.5 / N)`. For the other families it is interpolated in
+/// Stephens' tables of critical values for `W^2 (1 + 0.16 / N)` (`Exp`) or
+/// `W^2 (1 + 0.2 / sqrt(N))` (`Weibull` and `Gumbel`), and is clamped to the
+/// tabulated range as in `anderson_darling_estimated`.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or the sample is constant.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{cramer_von_mises_estimated, EstimatedFamily};
+///
+/// let x = [2.1, 3.4, 1.9, 5.6, 4.4, 3.0, 2.8, 6.1, 3.3, 4.0];
+/// let result = cramer_von_mises_estimated(&x, EstimatedFamily::Normal).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn cramer_von_mises_estimated(x: &[f64], family: EstimatedFamily) -> Result<GoodnessOfFit> {
+    let u = match fitted_probabilities(x, family)? {
+        Some(u) => u,
+        None => return Ok(GoodnessOfFit::nan()),
+    };
+    let n = u.len() as f64;
+    let statistic = cvm_statistic(&u);
+    let p_value = match family {
+        EstimatedFamily::Normal => {
+            let w = statistic * (1.0 + 0.5 / n);
+            if w < 0.0275 {
+                1.0 - (-13.953 + 775.5 * w - 12542.61 * w * w).exp()
+            } else if w < 0.051 {
+                1.0 - (-5.903 + 179.546 * w - 1515.29 * w * w).exp()
+            } else if w < 0.092 {
+                (0.886 - 31.62 * w + 10.897 * w * w).exp()
+            } else if w < 1.1 {
+                (1.111 - 34.242 * w + 12.832 * w * w).exp()
+            } else {
+                7.37e-10
+            }
+        }
+        EstimatedFamily::Exp => {
+            interpolate_p_value(statistic * (1.0 + 0.16 / n), &EXP_CVM_CRITICAL, &EXP_ALPHA)
+        }
+        EstimatedFamily::Weibull | EstimatedFamily::Gumbel => interpolate_p_value(
+            statistic * (1.0 + 0.2 / n.sqrt()),
+            &EV_CVM_CRITICAL,
+            &EV_ALPHA,
+        ),
+    };
+    Ok(GoodnessOfFit {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// Returns `F(x_i)` in increasing order, or `None` if an entry of `x` is
+/// `f64::NAN`
+fn sorted_probabilities<F: Fn(f64) -> f64>(x: &[f64], cdf: F) -> Option<Vec<f64>> {
+    if x.iter().any(|v| v.is_nan()) {
+        return None;
+    }
+    let mut u: Vec<f64> = x.iter().map(|&v| cdf(v)).collect();
+    if u.iter().any(|p| p.is_nan()) {
+        return None;
+    }
+    u.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Some(u)
+}
+
+fn ad_statistic(u: &[f64]) -> f64 {
+    let n = u.len();
+    let sum: f64 = (0..n)
+        .map(|i| (2 * i + 1) as f64 * (u[i].ln() + (-u[n - 1 - i]).ln_1p()))
+        .sum();
+    -(n as f64) - sum / n as f64
+}
+
+fn cvm_statistic(u: &[f64]) -> f64 {
+    let n = u.len() as f64;
+    let sum: f64 = u
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let d = p - (2 * i + 1) as f64 / (2.0 * n);
+            d * d
+        })
+        .sum();
+    1.0 / (12.0 * n) + sum
+}
+
+/// Fits `family` to `x` and returns the fitted `F(x_i)` in increasing order,
+/// or `None` if the fit is degenerate
+fn fitted_probabilities(x: &[f64], family: EstimatedFamily) -> Result<Option<Vec<f64>>> {
+    if x.len() < ESTIMATED_MIN_N {
+        return Err(StatsError::ArgGte("sample size", ESTIMATED_MIN_N as f64));
+    }
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(None);
+    }
+    let u = match family {
+        EstimatedFamily::Normal => match Normal::new(x.mean(), x.std_dev()) {
+            Ok(dist) => sorted_probabilities(x, |v| dist.cdf(v)),
+            Err(_) => None,
+        },
+        EstimatedFamily::Exp => {
+            if x.iter().any(|&v| v < 0.0) {
+                return Err(StatsError::ArgNotNegative("x"));
+            }
+            match Exp::new(1.0 / x.mean()) {
+                Ok(dist) => sorted_probabilities(x, |v| dist.cdf(v)),
+                Err(_) => None,
+            }
+        }
+        EstimatedFamily::Gumbel => fit_gumbel(x)
+            .and_then(|(mu, beta)| sorted_probabilities(x, |v| gumbel_cdf(v, mu, beta))),
+        EstimatedFamily::Weibull => {
+            if x.iter().any(|&v| v <= 0.0) {
+                return Err(StatsError::ArgMustBePositive("x"));
+            }
+            // if X is Weibull then -ln X is Gumbel, and both the maximum
+            // likelihood fit and the statistics carry over unchanged
+            let y: Vec<f64> = x.iter().map(|v| -v.ln()).collect();
+            fit_gumbel(&y)
+                .and_then(|(mu, beta)| sorted_probabilities(&y, |v| gumbel_cdf(v, mu, beta)))
+        }
+    };
+    Ok(u)
+}
+
+fn gumbel_cdf(x: f64, mu: f64, beta: f64) -> f64 {
+    (-(-(x - mu) / beta).exp()).exp()
+}
+
+/// Maximum likelihood estimates `(μ, β)` of the Gumbel distribution
+/// `F(x) = exp(-exp(-(x - μ) / β))`, or `None` if `x` is constant
+fn fit_gumbel(x: &[f64]) -> Option<(f64, f64)> {
+    let n = x.len() as f64;
+    let mean = x.mean();
+    let min = x.iter().cloned().fold(f64::INFINITY, f64::min);
+    let sd = x.population_std_dev();
+    if sd.is_nan() || sd <= 0.0 {
+        return None;
+    }
+
+    // the scale solves β = x̄ - Σ x_i w_i / Σ w_i with w_i = exp(-x_i / β);
+    // the left side minus the right is increasing in β, so bisect. Shifting
+    // by the minimum keeps the weights in (0, 1].
+    let weighted_mean = |beta: f64| {
+        let (sw, swx) = x.iter().fold((0.0, 0.0), |(sw, swx), &v| {
+            let w = (-(v - min) / beta).exp();
+            (sw + w, swx + w * v)
+        });
+        swx / sw
+    };
+    let mut lo = 1e-8 * sd;
+    let mut hi = 10.0 * sd;
+    while hi - lo > f64::EPSILON * hi {
+        let mid = 0.5 * (lo + hi);
+        if mean - mid - weighted_mean(mid) > 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let beta = 0.5 * (lo + hi);
+    let sw: f64 = x.iter().map(|&v| (-(v - min) / beta).exp()).sum();
+    Some((min - beta * (sw / n).ln(), beta))
+}
+
+/// Interpolates linearly in `ln p` between the tabulated `critical` values
+/// (increasing) and their significance levels `alpha` (decreasing),
+/// clamping to the ends of the table
+fn interpolate_p_value(statistic: f64, critical: &[f64], alpha: &[f64]) -> f64 {
+    if statistic <= critical[0] {
+        return alpha[0];
+    }
+    for i in 1..critical.len() {
+        if statistic < critical[i] {
+            let t = (statistic - critical[i - 1]) / (critical[i] - critical[i - 1]);
+            return (alpha[i - 1].ln() + t * (alpha[i].ln() - alpha[i - 1].ln())).exp();
+        }
+    }
+    alpha[alpha.len() - 1]
+}
+
+/// `P(A_n^2 < z)` for the fully specified Anderson–Darling statistic,
+/// following Marsaglia and Marsaglia (2004)
+fn anderson_darling_cdf(n: usize, z: f64) -> f64 {
+    if z <= 0.0 {
+        return 0.0;
+    }
+    let x = anderson_darling_limit_cdf(z);
+    let n = n as f64;
+    let fix = if x > 0.8 {
+        (-130.2137
+            + (745.2337 - (1705.091 - (1950.646 - (1116.360 - 255.7844 * x) * x) * x) * x) * x)
+            / n
+    } else {
+        let c = 0.01265 + 0.1757 / n;
+        if x < c {
+            let t = x / c;
+            let t = t.sqrt() * (1.0 - t) * (49.0 * t - 102.0);
+            t * (0.0037 / (n * n) + 0.00078 / n + 0.00006) / n
+        } else {
+            let t = (x - c) / (0.8 - c);
+            let t = -0.00022633
+                + (6.54034 - (14.6538 - (14.458 - (8.259 - 1.91864 * t) * t) * t) * t) * t;
+            t * (0.04213 / n + 0.01365 / (n * n))
+        }
+    };
+    x + fix
+}
+
+/// Limiting distribution function of the Anderson–Darling statistic
+fn anderson_darling_limit_cdf(z: f64) -> f64 {
+    if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    }
+}
+
+/// Limiting distribution function of the Cramér–von Mises statistic,
+/// `P(W^2 < x)`, as the series of Anderson and Darling (1952)
+fn cramer_von_mises_cdf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let pi_1_5 = f64::consts::PI * f64::consts::PI.sqrt();
+    let mut total = 0.0;
+    for k in 0..1000 {
+        let k = k as f64;
+        let y = 4.0 * k + 1.0;
+        let q = y * y / (16.0 * x);
+        let u = (gamma::ln_gamma(k + 0.5) - gamma::ln_gamma(k + 1.0)).exp() / (pi_1_5 * x.sqrt());
+        let term = u * y.sqrt() * exp_scaled_bessel_k(0.25, q);
+        total += term;
+        if term <= f64::EPSILON * total {
+            break;
+        }
+    }
+    total
+}
+
+/// Computes `exp(-z) K_ν(z)` for `z > 0` by the trapezoidal rule applied to
+/// `∫_0^∞ exp(-z (1 + cosh t)) cosh(ν t) dt`, which converges geometrically
+/// for this doubly exponentially decaying integrand
+fn exp_scaled_bessel_k(nu: f64, z: f64) -> f64 {
+    let h = 0.02;
+    let mut sum = 0.5 * (-2.0 * z).exp();
+    let mut j = 1.0;
+    loop {
+        let t: f64 = j * h;
+        let a = z * (1.0 + t.cosh());
+        if a > 745.0 {
+            break;
+        }
+        sum += (-a).exp() * (nu * t).cosh();
+        j += 1.0;
+    }
+    sum * h
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [f64; 10] = [0.61, -1.3, 0.2, 1.9, -0.45, 0.05, 0.88, -0.12, 2.4, 0.7];
+    const DATA: [f64; 20] = [
+        2.1, 3.4, 1.9, 5.6, 4.4, 3.0, 2.8, 6.1, 3.3, 4.0,
+        2.5, 3.9, 7.2, 2.2, 3.6, 4.8, 3.1, 5.0, 2.9, 4.1,
+    ];
+
+    #[test]
+    fn test_limit_distributions() {
+        // tabulated 5% and 1% critical values
+        assert_almost_eq!(1.0 - anderson_darling_limit_cdf(2.492), 0.05, 1e-4);
+        assert_almost_eq!(1.0 - anderson_darling_limit_cdf(3.857), 0.01, 1e-3);
+        assert_almost_eq!(1.0 - cramer_von_mises_cdf(0.461), 0.05, 2e-4);
+        assert_almost_eq!(1.0 - cramer_von_mises_cdf(0.743), 0.01, 1e-4);
+        assert_almost_eq!(1.0 - cramer_von_mises_cdf(0.347), 0.10, 2e-4);
+        assert_eq!(cramer_von_mises_cdf(0.0), 0.0);
+        assert_almost_eq!(cramer_von_mises_cdf(5.0), 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_exp_scaled_bessel_k() {
+        for &z in &[0.01, 0.7, 3.0, 40.0] {
+            let half = (f64::consts::PI / (2.0 * z)).sqrt() * (-2.0 * z).exp();
+            assert_almost_eq!(exp_scaled_bessel_k(0.5, z), half, 1e-14 * half);
+        }
+    }
+
+    #[test]
+    fn test_anderson_darling() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let result = anderson_darling(&SAMPLE, &normal).unwrap();
+        assert_almost_eq!(result.statistic, 1.2235053582748865, 1e-9);
+        assert_almost_eq!(result.p_value, 0.2578502568065857, 1e-9);
+
+        let far = Normal::new(3.0, 1.0).unwrap();
+        assert!(anderson_darling(&SAMPLE, &far).unwrap().p_value < 1e-4);
+    }
+
+    #[test]
+    fn test_cramer_von_mises() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let result = cramer_von_mises(&SAMPLE, &normal).unwrap();
+        assert_almost_eq!(result.statistic, 0.19197718612036455, 1e-9);
+        assert_almost_eq!(result.p_value, 0.32460826874792614, 1e-8);
+    }
+
+    #[test]
+    fn test_estimated_normal() {
+        let result = anderson_darling_estimated(&DATA, EstimatedFamily::Normal).unwrap();
+        assert_almost_eq!(result.statistic, 0.35301839095712495, 1e-9);
+        assert_almost_eq!(result.p_value, 0.42949715996695953, 1e-9);
+
+        let result = cramer_von_mises_estimated(&DATA, EstimatedFamily::Normal).unwrap();
+        assert_almost_eq!(result.statistic, 0.05353615979065323, 1e-9);
+        assert_almost_eq!(result.p_value, 0.4420552224378766, 1e-8);
+
+        // location-scale invariant
+        let shifted: Vec<f64> = DATA.iter().map(|v| 10.0 * v - 7.0).collect();
+        let shifted = anderson_darling_estimated(&shifted, EstimatedFamily::Normal).unwrap();
+        assert_almost_eq!(shifted.statistic, 0.35301839095712495, 1e-9);
+    }
+
+    #[test]
+    fn test_estimated_exp() {
+        let result = anderson_darling_estimated(&DATA, EstimatedFamily::Exp).unwrap();
+        assert_almost_eq!(result.statistic, 3.9637991283741805, 1e-12);
+        assert_eq!(result.p_value, 0.01);
+
+        let result = cramer_von_mises_estimated(&DATA, EstimatedFamily::Exp).unwrap();
+        assert_almost_eq!(result.statistic, 0.8008997955848517, 1e-12);
+        assert_eq!(result.p_value, 0.01);
+    }
+
+    #[test]
+    fn test_estimated_gumbel() {
+        let (mu, beta) = fit_gumbel(&DATA).unwrap();
+        assert_almost_eq!(mu, 3.1690568027291635, 1e-12);
+        assert_almost_eq!(beta, 1.059016241372119, 1e-12);
+
+        let result = anderson_darling_estimated(&DATA, EstimatedFamily::Gumbel).unwrap();
+        assert_almost_eq!(result.statistic, 0.1115691298975392, 1e-12);
+        assert_eq!(result.p_value, 0.25);
+
+        let result = cramer_von_mises_estimated(&DATA, EstimatedFamily::Gumbel).unwrap();
+        assert_almost_eq!(result.statistic, 0.01278398809450352, 1e-12);
+        assert_eq!(result.p_value, 0.25);
+    }
+
+    #[test]
+    fn test_estimated_weibull() {
+        let result = anderson_darling_estimated(&DATA, EstimatedFamily::Weibull).unwrap();
+        assert_almost_eq!(result.statistic, 0.3022170446351353, 1e-12);
+        assert_eq!(result.p_value, 0.25);
+
+        let result = cramer_von_mises_estimated(&DATA, EstimatedFamily::Weibull).unwrap();
+        assert_almost_eq!(result.statistic, 0.043630792839949924, 1e-12);
+
+        // the Weibull fit satisfies the likelihood equation for the shape
+        let y: Vec<f64> = DATA.iter().map(|v| -v.ln()).collect();
+        let (_, beta) = fit_gumbel(&y).unwrap();
+        let k = 1.0 / beta;
+        let swk: f64 = DATA.iter().map(|v| v.powf(k)).sum();
+        let swkl: f64 = DATA.iter().map(|v| v.powf(k) * v.ln()).sum();
+        let mean_ln = DATA.iter().map(|v| v.ln()).sum::<f64>() / 20.0;
+        assert_almost_eq!(1.0 / k + mean_ln - swkl / swk, 0.0, 1e-12);
+    }
+
+    #[test]
+    fn test_interpolate_p_value() {
+        assert_eq!(interpolate_p_value(0.1, &EV_AD_CRITICAL, &EV_ALPHA), 0.25);
+        assert_eq!(interpolate_p_value(2.0, &EV_AD_CRITICAL, &EV_ALPHA), 0.01);
+        assert_almost_eq!(interpolate_p_value(0.757, &EV_AD_CRITICAL, &EV_ALPHA), 0.05, 1e-15);
+        let mid = interpolate_p_value(0.697, &EV_AD_CRITICAL, &EV_ALPHA);
+        assert_almost_eq!(mid, (0.1f64 * 0.05).sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert!(anderson_darling(&[], &normal).is_err());
+        assert!(cramer_von_mises(&[], &normal).is_err());
+        assert!(anderson_darling(&[f64::NAN], &normal).unwrap().p_value.is_nan());
+        assert!(anderson_darling_estimated(&SAMPLE[..7], EstimatedFamily::Normal).is_err());
+        assert!(anderson_darling_estimated(&SAMPLE, EstimatedFamily::Exp).is_err());
+        assert!(cramer_von_mises_estimated(&SAMPLE, EstimatedFamily::Weibull).is_err());
+        assert!(anderson_darling_estimated(&SAMPLE, EstimatedFamily::Gumbel).is_ok());
+        let constant = [1.0; 10];
+        assert!(anderson_darling_estimated(&constant, EstimatedFamily::Normal).unwrap().p_value.is_nan());
+        assert!(anderson_darling_estimated(&constant, EstimatedFamily::Gumbel).unwrap().p_value.is_nan());
+    }
+}
@@ -0,0 +1,433 @@
+use super::{Alternative, GoodnessOfFit};
+use crate::distribution::ContinuousCDF;
+use crate::function::factorial;
+use crate::{Result, StatsError};
+use nalgebra::DMatrix;
+use std::f64;
+
+/// Largest one-sample size for which `ks_test` computes exact p-values
+const KS_EXACT_MAX_N: usize = 100;
+
+/// Largest product of the two sample sizes for which `ks_test_two_sample`
+/// computes exact p-values
+const KS_EXACT_MAX_NM: usize = 10000;
+
+/// Performs the one-sample Kolmogorov–Smirnov test of the null hypothesis
+/// that `x` is drawn from the continuous distribution `dist`
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty
+///
+/// # Remarks
+///
+/// With `Alternative::TwoSided` the statistic is `D = sup |F_N(t) - F(t)|`,
+/// where `F_N` is the empirical distribution function of `x`.
+/// `Alternative::Greater` uses `D+ = sup (F_N(t) - F(t))`, testing whether
+/// the distribution function of the population lies above `F`, and
+/// `Alternative::Less` uses `D- = sup (F(t) - F_N(t))`.
+///
+/// For samples of up to 100 values the p-value is exact, computed with the
+/// method of Marsaglia, Tsang and Wang (2003) for the two-sided test and the
+/// Birnbaum–Tingey formula for the one-sided tests. Larger samples use the
+/// limiting Kolmogorov distribution of `sqrt(N) D`, or `exp(-2 N D+^2)` when
+/// one-sided. The parameters of `dist` must not have been estimated from `x`,
+/// otherwise the p-value is too large.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::stats_tests::{ks_test, Alternative};
+///
+/// let x = [0.61, -1.3, 0.2, 1.9, -0.45, 0.05, 0.88, -0.12, 2.4, 0.7];
+/// let normal = Normal::new(0.0, 1.0).unwrap();
+/// let result = ks_test(&x, &normal, Alternative::TwoSided).unwrap();
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn ks_test<D>(x: &[f64], dist: &D, alternative: Alternative) -> Result<GoodnessOfFit>
+where
+    D: ContinuousCDF<f64, f64>,
+{
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(GoodnessOfFit::nan());
+    }
+
+    let mut sorted = x.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len();
+    let nf = n as f64;
+    let (d_plus, d_minus) =
+        sorted
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(d_plus, d_minus): (f64, f64), (i, &v)| {
+                let cdf = dist.cdf(v);
+                (
+                    d_plus.max((i + 1) as f64 / nf - cdf),
+                    d_minus.max(cdf - i as f64 / nf),
+                )
+            });
+
+    let exact = n <= KS_EXACT_MAX_N;
+    let (statistic, p_value) = match alternative {
+        Alternative::TwoSided => {
+            let d = d_plus.max(d_minus);
+            let p = if exact {
+                1.0 - kolmogorov_exact_cdf(n, d)
+            } else {
+                kolmogorov_sf(nf.sqrt() * d)
+            };
+            (d, p)
+        }
+        Alternative::Greater | Alternative::Less => {
+            let d = if alternative == Alternative::Greater {
+                d_plus
+            } else {
+                d_minus
+            };
+            let p = if exact {
+                smirnov_sf(n, d)
+            } else {
+                (-2.0 * nf * d * d).exp()
+            };
+            (d, p)
+        }
+    };
+    Ok(GoodnessOfFit {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// Performs the two-sample Kolmogorov–Smirnov test of the null hypothesis
+/// that `x` and `y` are drawn from the same continuous distribution
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty
+///
+/// # Remarks
+///
+/// The statistic is `D = sup |F_x(t) - F_y(t)|`, the largest distance
+/// between the two empirical distribution functions. The p-value is exact
+/// when the product of the sample sizes is below 10000 and there are no
+/// ties between or within the samples; otherwise it comes from the limiting
+/// Kolmogorov distribution of `sqrt(N_x N_y / (N_x + N_y)) D`.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::ks_test_two_sample;
+/// use statrs::prec::almost_eq;
+///
+/// let x = [1.2, 3.4, 0.5, 2.2, 5.1];
+/// let y = [0.9, 6.0, 4.4, 7.1, 3.9, 8.2];
+/// let result = ks_test_two_sample(&x, &y).unwrap();
+/// assert!(almost_eq(result.statistic, 0.633333333333333, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.177489177489177, 1e-12));
+/// ```
+pub fn ks_test_two_sample(x: &[f64], y: &[f64]) -> Result<GoodnessOfFit> {
+    if x.is_empty() || y.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    if x.iter().chain(y).any(|v| v.is_nan()) {
+        return Ok(GoodnessOfFit::nan());
+    }
+
+    let mut xs = x.to_vec();
+    let mut ys = y.to_vec();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ys.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let (n, m) = (xs.len(), ys.len());
+    let (nf, mf) = (n as f64, m as f64);
+
+    let mut d: f64 = 0.0;
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        let v = xs[i].min(ys[j]);
+        while i < n && xs[i] <= v {
+            i += 1;
+        }
+        while j < m && ys[j] <= v {
+            j += 1;
+        }
+        d = d.max((i as f64 / nf - j as f64 / mf).abs());
+    }
+
+    let mut pooled = xs;
+    pooled.extend_from_slice(&ys);
+    pooled.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let ties = pooled.windows(2).any(|w| w[0] == w[1]);
+
+    let p_value = if n * m < KS_EXACT_MAX_NM && !ties {
+        1.0 - smirnov_two_sample_exact_cdf(n, m, d)
+    } else {
+        kolmogorov_sf((nf * mf / (nf + mf)).sqrt() * d)
+    };
+    Ok(GoodnessOfFit {
+        statistic: d,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// Survival function of the limiting Kolmogorov distribution,
+/// `P(K > x) = 2 Σ (-1)^(k - 1) exp(-2 k^2 x^2)`
+fn kolmogorov_sf(x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    if x < 1.0 {
+        // the alternating series converges slowly for small x, so use the
+        // Jacobi theta transformation of the CDF instead
+        let c = -f64::consts::PI * f64::consts::PI / (8.0 * x * x);
+        let mut sum = 0.0;
+        for k in 1..=20 {
+            let odd = (2 * k - 1) as f64;
+            let term = (c * odd * odd).exp();
+            sum += term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+        }
+        1.0 - (2.0 * f64::consts::PI).sqrt() / x * sum
+    } else {
+        let mut sum = 0.0;
+        let mut sign = 1.0;
+        for k in 1..=100 {
+            let k = k as f64;
+            let term = (-2.0 * k * k * x * x).exp();
+            sum += sign * term;
+            if term <= f64::EPSILON * sum {
+                break;
+            }
+            sign = -sign;
+        }
+        2.0 * sum
+    }
+}
+
+/// `P(D_n < d)` for the two-sided one-sample statistic, following
+/// Marsaglia, Tsang and Wang (2003). `10^e` scaling factors are tracked
+/// separately to avoid overflow in the matrix power.
+fn kolmogorov_exact_cdf(n: usize, d: f64) -> f64 {
+    if d <= 0.0 {
+        return 0.0;
+    }
+    if d >= 1.0 {
+        return 1.0;
+    }
+    let nd = n as f64 * d;
+    let k = nd.floor() as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nd;
+
+    let mut hm = DMatrix::from_fn(m, m, |i, j| if i + 1 >= j { 1.0 } else { 0.0 });
+    for i in 0..m {
+        hm[(i, 0)] -= h.powi(i as i32 + 1);
+        hm[(m - 1, i)] -= h.powi((m - i) as i32);
+    }
+    if 2.0 * h - 1.0 > 0.0 {
+        hm[(m - 1, 0)] += (2.0 * h - 1.0).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..=i {
+            for g in 1..=(i + 1 - j) {
+                hm[(i, j)] /= g as f64;
+            }
+        }
+    }
+
+    let (q, mut e) = matrix_power(&hm, n);
+    let mut s = q[(k - 1, k - 1)];
+    for i in 1..=n {
+        s = s * i as f64 / n as f64;
+        if s < 1e-140 {
+            s *= 1e140;
+            e -= 140;
+        }
+    }
+    s * 10f64.powi(e)
+}
+
+/// Computes `a^n` as `(v, e)` with `a^n = v * 10^e`
+fn matrix_power(a: &DMatrix<f64>, n: usize) -> (DMatrix<f64>, i32) {
+    if n == 1 {
+        return (a.clone(), 0);
+    }
+    let (v, e) = matrix_power(a, n / 2);
+    let squared = &v * &v;
+    let (mut v, mut e) = if n & 1 == 0 {
+        (squared, 2 * e)
+    } else {
+        (a * squared, 2 * e)
+    };
+    let c = v.nrows() / 2;
+    if v[(c, c)] > 1e140 {
+        v *= 1e-140;
+        e += 140;
+    }
+    (v, e)
+}
+
+/// `P(D+_n >= d)` for the one-sided one-sample statistic, using the exact
+/// formula of Birnbaum and Tingey (1951)
+fn smirnov_sf(n: usize, d: f64) -> f64 {
+    if d <= 0.0 {
+        return 1.0;
+    }
+    if d >= 1.0 {
+        return 0.0;
+    }
+    let nf = n as f64;
+    let j_max = (nf * (1.0 - d)).floor() as usize;
+    let mut sum = 0.0;
+    for j in 0..=j_max {
+        let jf = j as f64;
+        let a = 1.0 - d - jf / nf;
+        if a <= 0.0 {
+            continue;
+        }
+        let b = d + jf / nf;
+        sum +=
+            (factorial::ln_binomial(n as u64, j as u64) + (nf - jf) * a.ln() + (jf - 1.0) * b.ln())
+                .exp();
+    }
+    d * sum
+}
+
+/// `P(D < d)` for the two-sided two-sample statistic with sample sizes `n`
+/// and `m` and no ties, by counting the lattice paths that stay within the
+/// band `|i / n - j / m| < d`
+fn smirnov_two_sample_exact_cdf(n: usize, m: usize, d: f64) -> f64 {
+    let (m, n) = if m > n { (n, m) } else { (m, n) };
+    let (mf, nf) = (m as f64, n as f64);
+    // the statistic is a multiple of 1 / (mn); step back half a unit so the
+    // comparisons below are not at the mercy of rounding
+    let q = (0.5 + (d * mf * nf - 1e-7).floor()) / (mf * nf);
+    let mut u: Vec<f64> = (0..=n)
+        .map(|j| if j as f64 / nf > q { 0.0 } else { 1.0 })
+        .collect();
+    for i in 1..=m {
+        let w = i as f64 / (i + n) as f64;
+        u[0] = if i as f64 / mf > q { 0.0 } else { w * u[0] };
+        for j in 1..=n {
+            u[j] = if (i as f64 / mf - j as f64 / nf).abs() > q {
+                0.0
+            } else {
+                w * u[j] + u[j - 1]
+            };
+        }
+    }
+    u[n]
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Normal, Uniform};
+
+    const SAMPLE: [f64; 10] = [0.61, -1.3, 0.2, 1.9, -0.45, 0.05, 0.88, -0.12, 2.4, 0.7];
+
+    fn golden_sequence(n: usize, step: f64, power: f64) -> Vec<f64> {
+        (1..=n).map(|i| ((i as f64 * step) % 1.0).powf(power)).collect()
+    }
+
+    #[test]
+    fn test_kolmogorov_exact_cdf() {
+        // the worked example of Marsaglia, Tsang and Wang
+        assert_almost_eq!(kolmogorov_exact_cdf(10, 0.274), 0.6284796154565043, 1e-14);
+        assert_eq!(kolmogorov_exact_cdf(10, 0.0), 0.0);
+        assert_eq!(kolmogorov_exact_cdf(10, 1.0), 1.0);
+    }
+
+    #[test]
+    fn test_kolmogorov_sf() {
+        assert_eq!(kolmogorov_sf(0.0), 1.0);
+        assert_almost_eq!(kolmogorov_sf(1.3580986393225507), 0.05, 1e-9);
+        // both series agree where they meet
+        assert_almost_eq!(kolmogorov_sf(1.0 - 1e-12), kolmogorov_sf(1.0), 1e-11);
+    }
+
+    #[test]
+    fn test_one_sample_exact() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let result = ks_test(&SAMPLE, &normal, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.statistic, 0.25224157397941616, 1e-9);
+        assert_almost_eq!(result.p_value, 0.4729018120423639, 1e-8);
+
+        let result = ks_test(&SAMPLE, &normal, Alternative::Greater).unwrap();
+        assert_almost_eq!(result.statistic, 0.008197535924596155, 1e-9);
+        assert_almost_eq!(result.p_value, 0.9911774523300867, 1e-8);
+
+        let result = ks_test(&SAMPLE, &normal, Alternative::Less).unwrap();
+        assert_almost_eq!(result.statistic, 0.25224157397941616, 1e-9);
+        assert_almost_eq!(result.p_value, 0.23901720238554833, 1e-8);
+    }
+
+    #[test]
+    fn test_one_sample_asymptotic() {
+        let x = golden_sequence(150, 0.6180339887498949, 1.1);
+        let uniform = Uniform::new(0.0, 1.0).unwrap();
+        let result = ks_test(&x, &uniform, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.statistic, 0.03837121718398945, 1e-14);
+        assert_almost_eq!(result.p_value, 0.9799998197041617, 1e-12);
+
+        let result = ks_test(&x, &uniform, Alternative::Greater).unwrap();
+        assert_almost_eq!(result.p_value, 0.6429392151259083, 1e-12);
+    }
+
+    #[test]
+    fn test_one_sample_rejects() {
+        let x: Vec<f64> = (0..50).map(|i| i as f64 / 10.0).collect();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let result = ks_test(&x, &normal, Alternative::TwoSided).unwrap();
+        assert!(result.p_value < 1e-10);
+    }
+
+    #[test]
+    fn test_two_sample_exact() {
+        // p-value from enumerating all 462 splits of the pooled sample
+        let x = [1.2, 3.4, 0.5, 2.2, 5.1];
+        let y = [0.9, 6.0, 4.4, 7.1, 3.9, 8.2];
+        let result = ks_test_two_sample(&x, &y).unwrap();
+        assert_almost_eq!(result.statistic, 0.6333333333333334, 1e-15);
+        assert_almost_eq!(result.p_value, 0.1774891774891775, 1e-13);
+        assert_eq!(result, ks_test_two_sample(&y, &x).unwrap());
+    }
+
+    #[test]
+    fn test_two_sample_asymptotic() {
+        let x = golden_sequence(120, 0.6180339887498949, 1.0);
+        let y = golden_sequence(100, 0.7548776662466927, 0.8);
+        let result = ks_test_two_sample(&x, &y).unwrap();
+        assert_almost_eq!(result.statistic, 0.10333333333333333, 1e-14);
+        assert_almost_eq!(result.p_value, 0.605051415869615, 1e-12);
+    }
+
+    #[test]
+    fn test_two_sample_ties() {
+        let result = ks_test_two_sample(&[1.0, 2.0, 2.0, 3.0], &[2.0, 3.0, 4.0]).unwrap();
+        assert_almost_eq!(result.statistic, 5.0 / 12.0, 1e-15);
+        assert!(result.p_value > 0.0 && result.p_value <= 1.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert!(ks_test(&[], &normal, Alternative::TwoSided).is_err());
+        assert!(ks_test_two_sample(&[], &[1.0]).is_err());
+        assert!(ks_test(&[f64::NAN], &normal, Alternative::TwoSided).unwrap().p_value.is_nan());
+        assert!(ks_test_two_sample(&[1.0], &[f64::NAN]).unwrap().p_value.is_nan());
+    }
+}
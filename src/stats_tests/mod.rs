@@ -0,0 +1,38 @@
+//! Provides statistical hypothesis tests and related inference procedures
+
+pub use self::anova::*;
+pub use self::chi_squared::*;
+pub use self::correlation::*;
+pub use self::exact_tests::*;
+pub use self::goodness_of_fit::*;
+pub use self::ks_test::*;
+pub use self::p_adjust::*;
+pub use self::power::*;
+pub use self::proportion::*;
+pub use self::rank_tests::*;
+pub use self::shapiro_wilk::*;
+pub use self::t_test::*;
+
+mod anova;
+mod chi_squared;
+mod correlation;
+mod exact_tests;
+mod goodness_of_fit;
+mod ks_test;
+mod p_adjust;
+mod power;
+mod proportion;
+mod rank_tests;
+mod shapiro_wilk;
+mod t_test;
+
+/// The alternative hypothesis of a statistical test
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alternative {
+    /// The parameter differs from its hypothesized value in either direction
+    TwoSided,
+    /// The parameter is less than its hypothesized value
+    Less,
+    /// The parameter is greater than its hypothesized value
+    Greater,
+}
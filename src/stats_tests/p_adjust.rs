@@ -0,0 +1,221 @@
+use crate::{Result, StatsError};
+use std::f64;
+
+/// A method of adjusting p-values for multiple comparisons
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AdjustMethod {
+    /// Bonferroni's correction, controlling the family-wise error rate
+    Bonferroni,
+    /// Holm's step-down method, controlling the family-wise error rate and
+    /// uniformly more powerful than Bonferroni
+    Holm,
+    /// Hochberg's step-up method, controlling the family-wise error rate
+    /// for independent or positively dependent tests
+    Hochberg,
+    /// Hommel's method, controlling the family-wise error rate for
+    /// independent or positively dependent tests and more powerful than
+    /// Hochberg's
+    Hommel,
+    /// The Benjamini–Hochberg method, controlling the false discovery rate
+    /// for independent or positively dependent tests
+    BenjaminiHochberg,
+    /// The Benjamini–Yekutieli method, controlling the false discovery rate
+    /// under arbitrary dependence
+    BenjaminiYekutieli,
+}
+
+/// Adjusts `p_values` for multiple comparisons, returning the adjusted
+/// p-values in the same order as the input
+///
+/// # Errors
+///
+/// Returns an error if a p-value is outside `[0, 1]`
+///
+/// # Remarks
+///
+/// Follows R's `p.adjust` operation for operation, so the results agree
+/// exactly. `f64::NAN` entries are passed through unchanged and do not count
+/// towards the number of comparisons. The adjusted p-values are capped at 1.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{adjust_p_values, AdjustMethod};
+///
+/// let p = [0.01, 0.02, 0.03, 0.04, 0.05];
+/// let adjusted = adjust_p_values(&p, AdjustMethod::Holm).unwrap();
+/// assert_eq!(adjusted, [0.05, 0.08, 0.09, 0.09, 0.09]);
+/// let adjusted = adjust_p_values(&p, AdjustMethod::BenjaminiHochberg).unwrap();
+/// assert_eq!(adjusted, [0.05; 5]);
+/// ```
+pub fn adjust_p_values(p_values: &[f64], method: AdjustMethod) -> Result<Vec<f64>> {
+    if p_values
+        .iter()
+        .any(|p| !p.is_nan() && !(0.0..=1.0).contains(p))
+    {
+        return Err(StatsError::ArgIntervalIncl("p-value", 0.0, 1.0));
+    }
+    let present: Vec<usize> = (0..p_values.len())
+        .filter(|&i| !p_values[i].is_nan())
+        .collect();
+    let p: Vec<f64> = present.iter().map(|&i| p_values[i]).collect();
+    let mut result = p_values.to_vec();
+    for (&i, q) in present.iter().zip(adjust(&p, method)) {
+        result[i] = q;
+    }
+    Ok(result)
+}
+
+fn adjust(p: &[f64], method: AdjustMethod) -> Vec<f64> {
+    let n = p.len();
+    if n <= 1 {
+        return p.to_vec();
+    }
+    if n == 2 && method == AdjustMethod::Hommel {
+        return adjust(p, AdjustMethod::Hochberg);
+    }
+    let nf = n as f64;
+    let mut adjusted = vec![0.0; n];
+    match method {
+        AdjustMethod::Bonferroni => {
+            for (a, &x) in adjusted.iter_mut().zip(p) {
+                *a = (nf * x).min(1.0);
+            }
+        }
+        AdjustMethod::Holm => {
+            let mut running: f64 = 0.0;
+            for (k, &idx) in ascending_order(p).iter().enumerate() {
+                running = running.max((n - k) as f64 * p[idx]);
+                adjusted[idx] = running.min(1.0);
+            }
+        }
+        AdjustMethod::Hochberg
+        | AdjustMethod::BenjaminiHochberg
+        | AdjustMethod::BenjaminiYekutieli => {
+            let harmonic: f64 = (1..=n).map(|j| 1.0 / j as f64).sum();
+            let mut order = ascending_order(p);
+            order.reverse();
+            let mut running = f64::INFINITY;
+            for (k, &idx) in order.iter().enumerate() {
+                let i = (n - k) as f64;
+                let scaled = match method {
+                    AdjustMethod::Hochberg => (nf + 1.0 - i) * p[idx],
+                    AdjustMethod::BenjaminiHochberg => nf / i * p[idx],
+                    _ => harmonic * nf / i * p[idx],
+                };
+                running = running.min(scaled);
+                adjusted[idx] = running.min(1.0);
+            }
+        }
+        AdjustMethod::Hommel => {
+            let order = ascending_order(p);
+            let sorted: Vec<f64> = order.iter().map(|&i| p[i]).collect();
+            let initial = sorted
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| nf * x / (i + 1) as f64)
+                .fold(f64::INFINITY, f64::min);
+            let mut q = vec![initial; n];
+            let mut pa = vec![initial; n];
+            for m in (2..n).rev() {
+                let mf = m as f64;
+                let split = n - m + 1;
+                let q1 = sorted[split..]
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &x)| mf * x / (j + 2) as f64)
+                    .fold(f64::INFINITY, f64::min);
+                for i in 0..split {
+                    q[i] = (mf * sorted[i]).min(q1);
+                }
+                for i in split..n {
+                    q[i] = q[split - 1];
+                }
+                for (a, &b) in pa.iter_mut().zip(&q) {
+                    *a = a.max(b);
+                }
+            }
+            for (k, &idx) in order.iter().enumerate() {
+                adjusted[idx] = pa[k].max(sorted[k]);
+            }
+        }
+    }
+    adjusted
+}
+
+/// Indices of `p` sorted by increasing value, keeping ties in input order
+fn ascending_order(p: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..p.len()).collect();
+    order.sort_by(|&a, &b| p[a].partial_cmp(&p[b]).unwrap());
+    order
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // adjusted values from R's p.adjust, which are reproduced bit for bit
+    const P: [f64; 10] = [0.0021, 0.044, 0.0005, 0.31, 0.019, 0.044, 0.82, 0.0089, 0.2, 0.037];
+
+    fn check(method: AdjustMethod, expected: [f64; 10]) {
+        let adjusted = adjust_p_values(&P, method).unwrap();
+        assert_eq!(adjusted, expected);
+    }
+
+    #[test]
+    fn test_bonferroni() {
+        check(AdjustMethod::Bonferroni, [0.020999999999999998, 0.43999999999999995, 0.005, 1.0, 0.19, 0.43999999999999995, 1.0, 0.089, 1.0, 0.37]);
+    }
+
+    #[test]
+    fn test_holm() {
+        check(AdjustMethod::Holm, [0.0189, 0.22199999999999998, 0.005, 0.62, 0.133, 0.22199999999999998, 0.82, 0.0712, 0.6000000000000001, 0.22199999999999998]);
+    }
+
+    #[test]
+    fn test_hochberg() {
+        check(AdjustMethod::Hochberg, [0.0189, 0.176, 0.005, 0.62, 0.133, 0.176, 0.82, 0.0712, 0.6000000000000001, 0.176]);
+    }
+
+    #[test]
+    fn test_hommel() {
+        check(AdjustMethod::Hommel, [0.0189, 0.176, 0.005, 0.62, 0.095, 0.176, 0.82, 0.07039999999999999, 0.46499999999999997, 0.148]);
+        // two p-values fall back to Hochberg
+        let adjusted = adjust_p_values(&[0.03, 0.02], AdjustMethod::Hommel).unwrap();
+        assert_eq!(adjusted, [0.03, 0.03]);
+    }
+
+    #[test]
+    fn test_benjamini_hochberg() {
+        check(AdjustMethod::BenjaminiHochberg, [
+            0.010499999999999999, 0.06285714285714286, 0.005, 0.34444444444444444, 0.0475,
+            0.06285714285714286, 0.82, 0.029666666666666668, 0.25, 0.06285714285714286,
+        ]);
+    }
+
+    #[test]
+    fn test_benjamini_yekutieli() {
+        check(AdjustMethod::BenjaminiYekutieli, [
+            0.030754166666666662, 0.1841065759637188, 0.01464484126984127, 1.0, 0.13912599206349205,
+            0.1841065759637188, 1.0, 0.08689272486772485, 0.7322420634920634, 0.1841065759637188,
+        ]);
+    }
+
+    #[test]
+    fn test_nan_and_trivial() {
+        let adjusted = adjust_p_values(&[0.01, f64::NAN, 0.04], AdjustMethod::Bonferroni).unwrap();
+        assert_eq!(adjusted[0], 0.02);
+        assert!(adjusted[1].is_nan());
+        assert_eq!(adjusted[2], 0.08);
+
+        assert_eq!(adjust_p_values(&[0.2], AdjustMethod::Holm).unwrap(), [0.2]);
+        assert!(adjust_p_values(&[], AdjustMethod::Hommel).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(adjust_p_values(&[0.5, 1.5], AdjustMethod::Holm).is_err());
+        assert!(adjust_p_values(&[-0.1], AdjustMethod::Holm).is_err());
+    }
+}
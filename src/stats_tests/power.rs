@@ -0,0 +1,726 @@
+use super::Alternative;
+use crate::distribution::{ContinuousCDF, NoncentralChiSquared, NoncentralStudentsT, StudentsT};
+use crate::function::{beta, erf, gamma};
+use crate::{solve, Result, StatsError};
+use std::f64;
+
+/// The design of a t-test, for the purpose of power analysis
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TTestDesign {
+    /// A one-sample t-test on `n` observations, or equivalently a paired
+    /// t-test on `n` pairs
+    OneSample,
+    /// A two-sample t-test with `n` observations in each group and equal
+    /// variances
+    TwoSample,
+}
+
+/// Computes the power of a t-test at significance level `alpha`, when the
+/// standardized effect size is `effect_size` and there are `sample_size`
+/// observations (in each group, for a two-sample test)
+///
+/// # Errors
+///
+/// Returns an error if `effect_size` is not finite, `sample_size` is less
+/// than 2 or not finite, or `alpha` is not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// The effect size is Cohen's `d`, the difference between the true and the
+/// hypothesized mean (or difference in means) divided by the standard
+/// deviation. It is signed: `Alternative::Greater` has power when it is
+/// positive and `Alternative::Less` when it is negative. The power of the
+/// two-sided test counts rejections in both tails. `sample_size` need not be
+/// an integer.
+///
+/// # Formula
+///
+/// ```ignore
+/// One sample: ν = n - 1,  δ = d sqrt(n)
+/// Two sample: ν = 2n - 2, δ = d sqrt(n / 2)
+///
+/// Greater:   1 - F(t_(1-α); ν, δ)
+/// Less:      F(-t_(1-α); ν, δ)
+/// Two-sided: 1 - F(t_(1-α/2); ν, δ) + F(-t_(1-α/2); ν, δ)
+/// ```
+///
+/// where `F(·; ν, δ)` is the cdf of the noncentral t-distribution and `t_p`
+/// is the `p` quantile of the central t-distribution with `ν` degrees of
+/// freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{t_test_power, Alternative, TTestDesign};
+/// use statrs::prec::almost_eq;
+///
+/// let power = t_test_power(1.0, 20.0, 0.05, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(power, 0.868953027723990, 1e-11));
+/// ```
+pub fn t_test_power(
+    effect_size: f64,
+    sample_size: f64,
+    alpha: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    check_t_sample_size(sample_size)?;
+    check_effect_size(effect_size)?;
+    t_power(effect_size, sample_size, alpha, design, alternative)
+}
+
+/// Computes the sample size (per group, for a two-sample test) at which a
+/// t-test at significance level `alpha` reaches `power` against the
+/// standardized effect size `effect_size`
+///
+/// # Errors
+///
+/// Returns an error if `alpha` is not in `(0, 1)`, `power` is not in
+/// `(alpha, 1)`, or `effect_size` is not finite or does not point in the
+/// direction of the alternative (it must be nonzero for a two-sided test)
+///
+/// # Remarks
+///
+/// The result is generally not an integer and should be rounded up. If even
+/// 2 observations reach the requested power, 2 is returned. See
+/// `t_test_power` for the definition of the effect size.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{t_test_sample_size, Alternative, TTestDesign};
+/// use statrs::prec::almost_eq;
+///
+/// let n = t_test_sample_size(1.0, 0.05, 0.9, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(n, 22.0210884263789, 1e-8));
+/// ```
+pub fn t_test_sample_size(
+    effect_size: f64,
+    alpha: f64,
+    power: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_direction(effect_size, alternative)?;
+    check_effect_size(effect_size)?;
+    increasing_solution(
+        |n| t_power(effect_size, n, alpha, design, alternative),
+        power,
+        2.0,
+    )
+}
+
+/// Computes the smallest standardized effect size that a t-test at
+/// significance level `alpha` with `sample_size` observations (in each
+/// group, for a two-sample test) detects with probability `power`
+///
+/// # Errors
+///
+/// Returns an error if `sample_size` is less than 2 or not finite, `alpha`
+/// is not in `(0, 1)` or `power` is not in `(alpha, 1)`
+///
+/// # Remarks
+///
+/// The effect size is returned in the direction of the alternative, so it is
+/// negative for `Alternative::Less` and positive otherwise. See
+/// `t_test_power` for its definition.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{t_test_effect_size, Alternative, TTestDesign};
+/// use statrs::prec::almost_eq;
+///
+/// let d = t_test_effect_size(20.0, 0.05, 0.8, TTestDesign::OneSample, Alternative::Greater).unwrap();
+/// assert!(almost_eq(d, 0.576917001338370, 1e-10));
+/// ```
+pub fn t_test_effect_size(
+    sample_size: f64,
+    alpha: f64,
+    power: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_t_sample_size(sample_size)?;
+    let sign = if alternative == Alternative::Less {
+        -1.0
+    } else {
+        1.0
+    };
+    let d = increasing_solution(
+        |d| t_power(sign * d, sample_size, alpha, design, alternative),
+        power,
+        0.0,
+    )?;
+    Ok(sign * d)
+}
+
+/// Computes the power of the two-sample z-test for equal proportions at
+/// significance level `alpha`, when the success probabilities are `p_1` and
+/// `p_2` and there are `sample_size` trials in each group
+///
+/// # Errors
+///
+/// Returns an error if `p_1` or `p_2` is not in the open interval `(0, 1)`,
+/// `sample_size` is not positive and finite or `alpha` is not in `(0, 1)`
+///
+/// # Remarks
+///
+/// The test statistic is the difference `p̂_1 - p̂_2` divided by its pooled
+/// standard error under the null hypothesis, as in R's `power.prop.test`.
+/// `Alternative::Greater` has power when `p_1 > p_2`, and the power of the
+/// two-sided test counts rejections in both tails.
+///
+/// # Formula
+///
+/// ```ignore
+/// Greater: Φ((sqrt(n) (p_1 - p_2) - z_(1-α) σ_0) / σ_1)
+/// Less:    Φ((sqrt(n) (p_2 - p_1) - z_(1-α) σ_0) / σ_1)
+/// ```
+///
+/// where `σ_0 = sqrt((p_1 + p_2) (2 - p_1 - p_2) / 2)`,
+/// `σ_1 = sqrt(p_1 (1 - p_1) + p_2 (1 - p_2))`, `z_p` is the `p` quantile
+/// of the standard normal distribution and `Φ` its cdf. The two-sided power
+/// is the sum of both with `α / 2` in place of `α`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{proportion_test_power, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let power = proportion_test_power(0.5, 0.75, 50.0, 0.05, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(power, 0.740167193453611, 1e-12));
+/// ```
+pub fn proportion_test_power(
+    p_1: f64,
+    p_2: f64,
+    sample_size: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_probability("p_1", p_1)?;
+    check_probability("p_2", p_2)?;
+    check_alpha(alpha)?;
+    check_positive("sample size", sample_size)?;
+    Ok(proportion_power(p_1, p_2, sample_size, alpha, alternative))
+}
+
+/// Computes the number of trials per group at which the two-sample z-test
+/// for equal proportions at significance level `alpha` reaches `power`, when
+/// the success probabilities are `p_1` and `p_2`
+///
+/// # Errors
+///
+/// Returns an error if `p_1` or `p_2` is not in `(0, 1)`, `alpha` is not in
+/// `(0, 1)`, `power` is not in `(alpha, 1)`, or `p_1 - p_2` does not point
+/// in the direction of the alternative
+///
+/// # Remarks
+///
+/// The result is generally not an integer and should be rounded up. If even
+/// 2 trials per group reach the requested power, 2 is returned.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{proportion_test_sample_size, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let n = proportion_test_sample_size(0.5, 0.75, 0.05, 0.9, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(n, 76.7069161158174, 1e-8));
+/// ```
+pub fn proportion_test_sample_size(
+    p_1: f64,
+    p_2: f64,
+    alpha: f64,
+    power: f64,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_probability("p_1", p_1)?;
+    check_probability("p_2", p_2)?;
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_direction(p_1 - p_2, alternative)?;
+    increasing_solution(
+        |n| Ok(proportion_power(p_1, p_2, n, alpha, alternative)),
+        power,
+        2.0,
+    )
+}
+
+/// Computes the success probability `p_2` of the second group that the
+/// two-sample z-test for equal proportions at significance level `alpha`,
+/// with `sample_size` trials per group, detects with probability `power`
+/// when the first group has success probability `p_1`
+///
+/// # Errors
+///
+/// Returns an error if `p_1` is not in `(0, 1)`, `sample_size` is not
+/// positive and finite, `alpha` is not in `(0, 1)` or `power` is not in
+/// `(alpha, 1)`.
+/// Returns `StatsError::ComputationFailedToConverge` if no `p_2` reaches
+/// the requested power.
+///
+/// # Remarks
+///
+/// The minimum detectable `p_2` is below `p_1` for `Alternative::Greater`
+/// and above it otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{proportion_test_effect_size, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let p_2 = proportion_test_effect_size(0.5, 50.0, 0.05, 0.9, Alternative::TwoSided).unwrap();
+/// assert!(almost_eq(p_2, 0.802630567988423, 1e-10));
+/// ```
+pub fn proportion_test_effect_size(
+    p_1: f64,
+    sample_size: f64,
+    alpha: f64,
+    power: f64,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_probability("p_1", p_1)?;
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_positive("sample size", sample_size)?;
+    let bound = if alternative == Alternative::Greater {
+        0.0
+    } else {
+        1.0
+    };
+    solve::brent(
+        |p_2| proportion_power(p_1, p_2, sample_size, alpha, alternative) - power,
+        p_1,
+        bound,
+        0.0,
+        solve::DEFAULT_MAX_ITERATIONS,
+    )
+}
+
+/// Computes the power of a chi-squared test with `freedom` degrees of
+/// freedom at significance level `alpha`, when the effect size is
+/// `effect_size` and the total sample size is `sample_size`
+///
+/// # Errors
+///
+/// Returns an error if `effect_size` is negative or not finite,
+/// `sample_size` or `freedom` is not positive and finite, or `alpha` is not
+/// in `(0, 1)`
+///
+/// # Remarks
+///
+/// The effect size is Cohen's `w`, which for a goodness of fit test is
+/// `sqrt(Σ (p_1i - p_0i)^2 / p_0i)` over the cells, with `p_0` the
+/// hypothesized and `p_1` the true cell probabilities. Under the
+/// alternative the statistic follows, approximately, the noncentral
+/// chi-squared distribution with noncentrality `n w^2`.
+///
+/// # Formula
+///
+/// ```ignore
+/// 1 - F(χ²_(1-α); k, n w^2)
+/// ```
+///
+/// where `F(·; k, λ)` is the cdf of the noncentral chi-squared distribution
+/// and `χ²_p` is the `p` quantile of the central chi-squared distribution
+/// with `k` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_power;
+/// use statrs::prec::almost_eq;
+///
+/// let power = chi_squared_power(0.3, 100.0, 3.0, 0.05).unwrap();
+/// assert!(almost_eq(power, 0.711253599795042, 1e-10));
+/// ```
+pub fn chi_squared_power(
+    effect_size: f64,
+    sample_size: f64,
+    freedom: f64,
+    alpha: f64,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    if effect_size.is_nan() || effect_size < 0.0 {
+        return Err(StatsError::ArgNotNegative("effect size"));
+    }
+    check_effect_size(effect_size)?;
+    check_positive("sample size", sample_size)?;
+    check_positive("freedom", freedom)?;
+    let critical = chi_squared_critical_value(freedom, alpha)?;
+    chi_squared_power_at(effect_size, sample_size, freedom, critical)
+}
+
+/// Computes the total sample size at which a chi-squared test with
+/// `freedom` degrees of freedom at significance level `alpha` reaches
+/// `power` against the effect size `effect_size`
+///
+/// # Errors
+///
+/// Returns an error if `effect_size` or `freedom` is not positive and
+/// finite, `alpha` is not in `(0, 1)` or `power` is not in `(alpha, 1)`
+///
+/// # Remarks
+///
+/// The result is generally not an integer and should be rounded up. See
+/// `chi_squared_power` for the definition of the effect size.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_sample_size;
+/// use statrs::prec::almost_eq;
+///
+/// let n = chi_squared_sample_size(0.3, 3.0, 0.05, 0.8).unwrap();
+/// assert!(almost_eq(n, 121.139592112592, 1e-7));
+/// ```
+pub fn chi_squared_sample_size(
+    effect_size: f64,
+    freedom: f64,
+    alpha: f64,
+    power: f64,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_positive("effect size", effect_size)?;
+    check_positive("freedom", freedom)?;
+    let critical = chi_squared_critical_value(freedom, alpha)?;
+    increasing_solution(
+        |n| chi_squared_power_at(effect_size, n, freedom, critical),
+        power,
+        0.0,
+    )
+}
+
+/// Computes the smallest effect size that a chi-squared test with `freedom`
+/// degrees of freedom at significance level `alpha` and total sample size
+/// `sample_size` detects with probability `power`
+///
+/// # Errors
+///
+/// Returns an error if `sample_size` or `freedom` is not positive and
+/// finite, `alpha` is not in `(0, 1)` or `power` is not in `(alpha, 1)`
+///
+/// # Remarks
+///
+/// See `chi_squared_power` for the definition of the effect size
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_effect_size;
+/// use statrs::prec::almost_eq;
+///
+/// let w = chi_squared_effect_size(100.0, 3.0, 0.05, 0.8).unwrap();
+/// assert!(almost_eq(w, 0.330190298012120, 1e-10));
+/// ```
+pub fn chi_squared_effect_size(
+    sample_size: f64,
+    freedom: f64,
+    alpha: f64,
+    power: f64,
+) -> Result<f64> {
+    check_alpha(alpha)?;
+    check_power(power, alpha)?;
+    check_positive("sample size", sample_size)?;
+    check_positive("freedom", freedom)?;
+    let critical = chi_squared_critical_value(freedom, alpha)?;
+    increasing_solution(
+        |w| chi_squared_power_at(w, sample_size, freedom, critical),
+        power,
+        0.0,
+    )
+}
+
+fn check_alpha(alpha: f64) -> Result<()> {
+    if alpha > 0.0 && alpha < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("alpha", 0.0, 1.0))
+    }
+}
+
+fn check_power(power: f64, alpha: f64) -> Result<()> {
+    if power > alpha && power < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("power", alpha, 1.0))
+    }
+}
+
+fn check_positive(name: &'static str, x: f64) -> Result<()> {
+    if x > 0.0 && x.is_finite() {
+        Ok(())
+    } else {
+        Err(StatsError::ArgMustBePositive(name))
+    }
+}
+
+fn check_t_sample_size(sample_size: f64) -> Result<()> {
+    if sample_size >= 2.0 && sample_size.is_finite() {
+        Ok(())
+    } else {
+        Err(StatsError::ArgGte("sample size", 2.0))
+    }
+}
+
+fn check_effect_size(effect_size: f64) -> Result<()> {
+    if effect_size.is_finite() {
+        Ok(())
+    } else {
+        Err(StatsError::BadParams)
+    }
+}
+
+fn check_probability(name: &'static str, p: f64) -> Result<()> {
+    if p > 0.0 && p < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl(name, 0.0, 1.0))
+    }
+}
+
+/// Checks that the power of a test against `effect` tends to 1 as the
+/// sample size grows
+fn check_direction(effect: f64, alternative: Alternative) -> Result<()> {
+    match alternative {
+        Alternative::TwoSided if effect == 0.0 || effect.is_nan() => {
+            Err(StatsError::ArgGt("absolute effect size", 0.0))
+        }
+        Alternative::Less if effect >= 0.0 || effect.is_nan() => {
+            Err(StatsError::ArgLt("effect size", 0.0))
+        }
+        Alternative::Greater if effect <= 0.0 || effect.is_nan() => {
+            Err(StatsError::ArgGt("effect size", 0.0))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn t_power(
+    effect_size: f64,
+    sample_size: f64,
+    alpha: f64,
+    design: TTestDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    let (freedom, ncp) = match design {
+        TTestDesign::OneSample => (sample_size - 1.0, effect_size * sample_size.sqrt()),
+        TTestDesign::TwoSample => (
+            2.0 * sample_size - 2.0,
+            effect_size * (sample_size / 2.0).sqrt(),
+        ),
+    };
+    let dist = StudentsT::new(0.0, 1.0, freedom)?;
+    let noncentral = NoncentralStudentsT::new(freedom, ncp)?;
+    let power = match alternative {
+        Alternative::TwoSided => {
+            let critical = dist.inverse_cdf(1.0 - alpha / 2.0);
+            1.0 - noncentral.cdf(critical) + noncentral.cdf(-critical)
+        }
+        Alternative::Less => noncentral.cdf(-dist.inverse_cdf(1.0 - alpha)),
+        Alternative::Greater => 1.0 - noncentral.cdf(dist.inverse_cdf(1.0 - alpha)),
+    };
+    Ok(power)
+}
+
+fn proportion_power(
+    p_1: f64,
+    p_2: f64,
+    sample_size: f64,
+    alpha: f64,
+    alternative: Alternative,
+) -> f64 {
+    let null_sd = ((p_1 + p_2) * (2.0 - p_1 - p_2) / 2.0).sqrt();
+    let sd = (p_1 * (1.0 - p_1) + p_2 * (1.0 - p_2)).sqrt();
+    let shift = sample_size.sqrt() * (p_1 - p_2);
+    let tail = |shift: f64, alpha: f64| {
+        let z = f64::consts::SQRT_2 * erf::erfc_inv(2.0 * alpha);
+        standard_normal_cdf((shift - z * null_sd) / sd)
+    };
+    match alternative {
+        Alternative::TwoSided => tail(shift, alpha / 2.0) + tail(-shift, alpha / 2.0),
+        Alternative::Less => tail(-shift, alpha),
+        Alternative::Greater => tail(shift, alpha),
+    }
+}
+
+fn standard_normal_cdf(x: f64) -> f64 {
+    0.5 * erf::erfc(-x / f64::consts::SQRT_2)
+}
+
+/// The `1 - alpha` quantile of the central chi-squared distribution
+fn chi_squared_critical_value(freedom: f64, alpha: f64) -> Result<f64> {
+    let sf = |x: f64| {
+        if x > 0.0 {
+            gamma::gamma_ur(freedom / 2.0, x / 2.0)
+        } else {
+            1.0
+        }
+    };
+    let mut upper = freedom.max(1.0);
+    while sf(upper) > alpha {
+        upper *= 2.0;
+    }
+    solve::brent(
+        |x| sf(x) - alpha,
+        0.0,
+        upper,
+        0.0,
+        solve::DEFAULT_MAX_ITERATIONS,
+    )
+}
+
+fn chi_squared_power_at(
+    effect_size: f64,
+    sample_size: f64,
+    freedom: f64,
+    critical: f64,
+) -> Result<f64> {
+    let noncentrality = sample_size * effect_size * effect_size;
+    Ok(1.0 - NoncentralChiSquared::new(freedom, noncentrality)?.cdf(critical))
+}
+
+/// Finds the smallest `x >= lower` with `f(x) = target` for an increasing
+/// `f`, returning `lower` if `f(lower)` already reaches the target
+fn increasing_solution<F: Fn(f64) -> Result<f64>>(f: F, target: f64, lower: f64) -> Result<f64> {
+    if f(lower)? >= target {
+        return Ok(lower);
+    }
+    let (mut lower, mut upper) = (lower, lower.max(0.5) * 2.0);
+    while f(upper)? < target {
+        if upper > 1e15 {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        lower = upper;
+        upper *= 2.0;
+    }
+    // the power is defined throughout the bracket once it is at its ends
+    solve::brent(
+        |x| f(x).unwrap_or(f64::NAN) - target,
+        lower,
+        upper,
+        0.0,
+        solve::DEFAULT_MAX_ITERATIONS,
+    )
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reference values computed to 30 digits by numerical integration of the
+    // noncentral densities and root finding in mpmath
+
+    #[test]
+    fn test_t_test_power() {
+        let power = t_test_power(1.0, 20.0, 0.05, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.8689530277239897, 1e-11);
+        let power = t_test_power(-0.8, 10.0, 0.05, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.3950692121136407, 1e-11);
+        let power = t_test_power(-0.5, 15.0, 0.01, TTestDesign::OneSample, Alternative::Less).unwrap();
+        assert_almost_eq!(power, 0.2830101899156419, 1e-11);
+        let power = t_test_power(0.5, 15.0, 0.01, TTestDesign::OneSample, Alternative::Less).unwrap();
+        assert_almost_eq!(power, 2.098595667236677e-5, 1e-11);
+        // without an effect the power is the significance level
+        let power = t_test_power(0.0, 7.5, 0.05, TTestDesign::OneSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.05, 1e-12);
+    }
+
+    #[test]
+    fn test_t_test_sample_size() {
+        let n = t_test_sample_size(1.0, 0.05, 0.9, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(n, 22.021088426378935, 1e-8);
+        let n = t_test_sample_size(0.3, 0.05, 0.8, TTestDesign::OneSample, Alternative::Greater).unwrap();
+        assert_almost_eq!(n, 70.06790520007297, 1e-8);
+        let n = t_test_sample_size(10.0, 0.05, 0.8, TTestDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_eq!(n, 2.0);
+    }
+
+    #[test]
+    fn test_t_test_effect_size() {
+        let d = t_test_effect_size(20.0, 0.05, 0.8, TTestDesign::OneSample, Alternative::Greater).unwrap();
+        assert_almost_eq!(d, 0.5769170013383703, 1e-10);
+        let d = t_test_effect_size(30.0, 0.05, 0.9, TTestDesign::TwoSample, Alternative::Less).unwrap();
+        assert_almost_eq!(d, -0.7645857492707754, 1e-10);
+    }
+
+    #[test]
+    fn test_proportion_test() {
+        let power = proportion_test_power(0.5, 0.75, 50.0, 0.05, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.740167193453611, 1e-12);
+        let power = proportion_test_power(0.3, 0.2, 100.0, 0.05, Alternative::Greater).unwrap();
+        assert_almost_eq!(power, 0.4952366089332915, 1e-12);
+
+        let n = proportion_test_sample_size(0.5, 0.75, 0.05, 0.9, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(n, 76.7069161158174, 1e-8);
+        let n = proportion_test_sample_size(0.1, 0.15, 0.05, 0.8, Alternative::Less).unwrap();
+        assert_almost_eq!(n, 539.9264311297709, 1e-7);
+
+        let p_2 = proportion_test_effect_size(0.5, 50.0, 0.05, 0.9, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(p_2, 0.8026305679884229, 1e-10);
+        let p_2 = proportion_test_effect_size(0.4, 200.0, 0.01, 0.8, Alternative::Greater).unwrap();
+        assert_almost_eq!(p_2, 0.2519971992180052, 1e-10);
+        // no p_2 reaches this power with so few trials
+        assert!(proportion_test_effect_size(0.9, 5.0, 0.05, 0.99, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared() {
+        let power = chi_squared_power(0.3, 100.0, 3.0, 0.05).unwrap();
+        assert_almost_eq!(power, 0.711253599795042, 1e-10);
+        let power = chi_squared_power(0.5, 200.0, 10.0, 0.01).unwrap();
+        assert_almost_eq!(power, 0.9987552580206017, 1e-10);
+        let power = chi_squared_power(0.1, 50.0, 1.0, 0.05).unwrap();
+        assert_almost_eq!(power, 0.1089546175506155, 1e-10);
+        let power = chi_squared_power(0.0, 50.0, 2.0, 0.05).unwrap();
+        assert_almost_eq!(power, 0.05, 1e-12);
+
+        let n = chi_squared_sample_size(0.3, 3.0, 0.05, 0.8).unwrap();
+        assert_almost_eq!(n, 121.1395921125919, 1e-7);
+        let w = chi_squared_effect_size(100.0, 3.0, 0.05, 0.8).unwrap();
+        assert_almost_eq!(w, 0.3301902980121202, 1e-10);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(t_test_power(0.5, 1.5, 0.05, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(0.5, 10.0, 1.0, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_sample_size(0.5, 0.05, 0.01, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_sample_size(0.0, 0.05, 0.8, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_sample_size(0.5, 0.05, 0.8, TTestDesign::OneSample, Alternative::Less).is_err());
+        assert!(t_test_effect_size(1.0, 0.05, 0.8, TTestDesign::TwoSample, Alternative::Greater).is_err());
+        assert!(proportion_test_power(0.0, 0.5, 10.0, 0.05, Alternative::TwoSided).is_err());
+        assert!(proportion_test_sample_size(0.2, 0.3, 0.05, 0.8, Alternative::Greater).is_err());
+        assert!(chi_squared_power(-0.1, 10.0, 1.0, 0.05).is_err());
+        assert!(chi_squared_sample_size(0.3, 0.0, 0.05, 0.8).is_err());
+        assert!(chi_squared_effect_size(100.0, 3.0, 0.05, 1.0).is_err());
+        // non-finite effect sizes, sample sizes and degrees of freedom
+        assert!(t_test_power(f64::NAN, 10.0, 0.05, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(f64::INFINITY, 10.0, 0.05, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(0.5, f64::INFINITY, 0.05, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(0.5, f64::NAN, 0.05, TTestDesign::TwoSample, Alternative::TwoSided).is_err());
+        assert!(t_test_sample_size(f64::INFINITY, 0.05, 0.8, TTestDesign::OneSample, Alternative::Greater).is_err());
+        assert!(t_test_effect_size(f64::INFINITY, 0.05, 0.8, TTestDesign::TwoSample, Alternative::Greater).is_err());
+        assert!(proportion_test_power(0.5, 0.75, f64::INFINITY, 0.05, Alternative::TwoSided).is_err());
+        assert!(chi_squared_power(f64::INFINITY, 10.0, 2.0, 0.05).is_err());
+        assert!(chi_squared_power(f64::NAN, 10.0, 2.0, 0.05).is_err());
+        assert!(chi_squared_power(0.3, f64::INFINITY, 2.0, 0.05).is_err());
+        assert!(chi_squared_power(0.3, 10.0, f64::INFINITY, 0.05).is_err());
+        assert!(chi_squared_sample_size(f64::INFINITY, 3.0, 0.05, 0.8).is_err());
+        assert!(chi_squared_sample_size(0.3, f64::INFINITY, 0.05, 0.8).is_err());
+        assert!(chi_squared_effect_size(100.0, f64::INFINITY, 0.05, 0.8).is_err());
+        // finite arguments whose noncentrality overflows
+        assert!(t_test_power(1e300, 1e300, 0.05, TTestDesign::OneSample, Alternative::TwoSided).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+use super::t_test::check_confidence;
+use crate::distribution::{Beta, ContinuousCDF, Normal};
+use crate::function::beta;
+use crate::{Result, StatsError};
+use std::f64;
+
+/// A method of constructing a confidence interval for a binomial proportion
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProportionInterval {
+    /// The Wilson score interval, obtained by inverting the score test; a
+    /// good default with coverage close to nominal even for small samples
+    Wilson,
+    /// The Agresti–Coull interval, the Wald interval centered on the
+    /// proportion after adding `z^2 / 2` successes and failures
+    AgrestiCoull,
+    /// The Clopper–Pearson interval, obtained by inverting two one-sided
+    /// binomial tests; conservative, with coverage never below nominal
+    ClopperPearson,
+    /// The Jeffreys interval, the equal-tailed credible interval under the
+    /// `Beta(1/2, 1/2)` prior
+    Jeffreys,
+}
+
+/// Computes a confidence interval `(lower, upper)` for the success
+/// probability of a binomial distribution, having observed `successes` in
+/// `trials`
+///
+/// # Errors
+///
+/// Returns an error if `trials` is zero, `successes > trials`, or
+/// `confidence` is not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// All intervals lie within `[0, 1]`. The lower bound is 0 when
+/// `successes` is 0 and the upper bound is 1 when `successes` equals
+/// `trials`, except for the Agresti–Coull interval, which is truncated to
+/// `[0, 1]` instead.
+///
+/// # Formula
+///
+/// ```ignore
+/// Wilson:          (x + z^2 / 2) / (n + z^2) ± z / (n + z^2) * sqrt(x (n - x) / n + z^2 / 4)
+/// Agresti–Coull:   p̃ ± z sqrt(p̃ (1 - p̃) / ñ), with ñ = n + z^2 and p̃ = (x + z^2 / 2) / ñ
+/// Clopper–Pearson: (I^-1_(α/2)(x, n - x + 1), I^-1_(1-α/2)(x + 1, n - x))
+/// Jeffreys:        (I^-1_(α/2)(x + 1/2, n - x + 1/2), I^-1_(1-α/2)(x + 1/2, n - x + 1/2))
+/// ```
+///
+/// where `x` is `successes`, `n` is `trials`, `α = 1 - confidence`, `z` is
+/// the `1 - α / 2` quantile of the standard normal distribution and `I^-1`
+/// is the inverse of the regularized incomplete beta function
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{proportion_interval, ProportionInterval};
+/// use statrs::prec::almost_eq;
+///
+/// let (lower, upper) = proportion_interval(7, 20, 0.95, ProportionInterval::Wilson).unwrap();
+/// assert!(almost_eq(lower, 0.181191824101082, 1e-12));
+/// assert!(almost_eq(upper, 0.567145723314764, 1e-12));
+/// ```
+pub fn proportion_interval(
+    successes: u64,
+    trials: u64,
+    confidence: f64,
+    method: ProportionInterval,
+) -> Result<(f64, f64)> {
+    check_confidence(confidence)?;
+    check_counts(successes, trials)?;
+    let alpha = 1.0 - confidence;
+    let (x, n) = (successes as f64, trials as f64);
+    let (lower, upper) = match method {
+        ProportionInterval::Wilson => wilson(x, n, z_quantile(alpha)),
+        ProportionInterval::AgrestiCoull => {
+            let z = z_quantile(alpha);
+            let n_tilde = n + z * z;
+            let p_tilde = (x + z * z / 2.0) / n_tilde;
+            let margin = z * (p_tilde * (1.0 - p_tilde) / n_tilde).sqrt();
+            return Ok(((p_tilde - margin).max(0.0), (p_tilde + margin).min(1.0)));
+        }
+        // the shape parameters must be positive, so the bounds for x = 0 and
+        // x = n are not computed here
+        ProportionInterval::ClopperPearson => (
+            if successes == 0 {
+                0.0
+            } else {
+                beta::inv_beta_reg(x, n - x + 1.0, alpha / 2.0)
+            },
+            if successes == trials {
+                1.0
+            } else {
+                beta::inv_beta_reg(x + 1.0, n - x, 1.0 - alpha / 2.0)
+            },
+        ),
+        ProportionInterval::Jeffreys => {
+            let posterior = Beta::new(x + 0.5, n - x + 0.5).unwrap();
+            (
+                posterior.inverse_cdf(alpha / 2.0),
+                posterior.inverse_cdf(1.0 - alpha / 2.0),
+            )
+        }
+    };
+    Ok((
+        if successes == 0 { 0.0 } else { lower },
+        if successes == trials { 1.0 } else { upper },
+    ))
+}
+
+/// Computes Newcombe's hybrid score confidence interval `(lower, upper)`
+/// for the difference `p_1 - p_2` of two binomial success probabilities,
+/// having observed `successes_1` in `trials_1` and `successes_2` in
+/// `trials_2` independent trials
+///
+/// # Errors
+///
+/// Returns an error if either number of trials is zero, a number of
+/// successes exceeds its number of trials, or `confidence` is not in the
+/// open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// The interval combines the Wilson score intervals of the two proportions
+/// (method 10 of Newcombe, 1998). Unlike the Wald interval it stays within
+/// `[-1, 1]` and behaves well when a proportion is near 0 or 1.
+///
+/// # Formula
+///
+/// ```ignore
+/// (p̂_1 - p̂_2 - sqrt((p̂_1 - l_1)^2 + (u_2 - p̂_2)^2),
+///  p̂_1 - p̂_2 + sqrt((u_1 - p̂_1)^2 + (p̂_2 - l_2)^2))
+/// ```
+///
+/// where `(l_i, u_i)` is the Wilson interval for the `i`th proportion
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::proportion_difference_interval;
+/// use statrs::prec::almost_eq;
+///
+/// let (lower, upper) = proportion_difference_interval(56, 70, 48, 80, 0.95).unwrap();
+/// assert!(almost_eq(lower, 0.0524314724023649, 1e-12));
+/// assert!(almost_eq(upper, 0.333872654036906, 1e-12));
+/// ```
+pub fn proportion_difference_interval(
+    successes_1: u64,
+    trials_1: u64,
+    successes_2: u64,
+    trials_2: u64,
+    confidence: f64,
+) -> Result<(f64, f64)> {
+    check_confidence(confidence)?;
+    check_counts(successes_1, trials_1)?;
+    check_counts(successes_2, trials_2)?;
+    let z = z_quantile(1.0 - confidence);
+    let (x1, n1) = (successes_1 as f64, trials_1 as f64);
+    let (x2, n2) = (successes_2 as f64, trials_2 as f64);
+    let (l1, u1) = wilson(x1, n1, z);
+    let (l2, u2) = wilson(x2, n2, z);
+    let (p1, p2) = (x1 / n1, x2 / n2);
+    let delta = ((p1 - l1) * (p1 - l1) + (u2 - p2) * (u2 - p2)).sqrt();
+    let epsilon = ((u1 - p1) * (u1 - p1) + (p2 - l2) * (p2 - l2)).sqrt();
+    Ok((p1 - p2 - delta, p1 - p2 + epsilon))
+}
+
+fn check_counts(successes: u64, trials: u64) -> Result<()> {
+    if trials == 0 {
+        Err(StatsError::ArgGte("trials", 1.0))
+    } else if successes > trials {
+        Err(StatsError::ArgLteArg("successes", "trials"))
+    } else {
+        Ok(())
+    }
+}
+
+/// The `1 - alpha / 2` quantile of the standard normal distribution
+fn z_quantile(alpha: f64) -> f64 {
+    Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_cdf(1.0 - alpha / 2.0)
+}
+
+fn wilson(x: f64, n: f64, z: f64) -> (f64, f64) {
+    let z2 = z * z;
+    let denominator = n + z2;
+    let center = (x + z2 / 2.0) / denominator;
+    let margin = z / denominator * (x * (n - x) / n + z2 / 4.0).sqrt();
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(successes: u64, trials: u64, confidence: f64, method: ProportionInterval, expected: (f64, f64)) {
+        let (lower, upper) = proportion_interval(successes, trials, confidence, method).unwrap();
+        assert_almost_eq!(lower, expected.0, 1e-12);
+        assert_almost_eq!(upper, expected.1, 1e-12);
+    }
+
+    #[test]
+    fn test_wilson() {
+        check(7, 20, 0.95, ProportionInterval::Wilson, (0.1811918241010821, 0.5671457233147637));
+        check(0, 10, 0.95, ProportionInterval::Wilson, (0.0, 0.27753279986288915));
+        check(10, 10, 0.9, ProportionInterval::Wilson, (0.7870580299165932, 1.0));
+    }
+
+    #[test]
+    fn test_agresti_coull() {
+        check(7, 20, 0.95, ProportionInterval::AgrestiCoull, (0.17992636143822804, 0.5684111859776177));
+        check(0, 10, 0.95, ProportionInterval::AgrestiCoull, (0.0, 0.3208873057505457));
+        check(10, 10, 0.9, ProportionInterval::AgrestiCoull, (0.7511976547699635, 1.0));
+    }
+
+    #[test]
+    fn test_clopper_pearson() {
+        check(7, 20, 0.95, ProportionInterval::ClopperPearson, (0.15390920478454162, 0.592188534532827));
+        check(0, 10, 0.95, ProportionInterval::ClopperPearson, (0.0, 0.30849710781875983));
+        check(10, 10, 0.9, ProportionInterval::ClopperPearson, (0.7411344491069485, 1.0));
+    }
+
+    #[test]
+    fn test_jeffreys() {
+        check(7, 20, 0.95, ProportionInterval::Jeffreys, (0.1722762136319127, 0.567766093841495));
+        check(0, 10, 0.95, ProportionInterval::Jeffreys, (0.0, 0.2171962675092089));
+        check(10, 10, 0.9, ProportionInterval::Jeffreys, (0.8292268917551531, 1.0));
+    }
+
+    #[test]
+    fn test_proportion_difference_interval() {
+        // examples from Newcombe (1998)
+        let (lower, upper) = proportion_difference_interval(56, 70, 48, 80, 0.95).unwrap();
+        assert_almost_eq!(lower, 0.0524314724023649, 1e-12);
+        assert_almost_eq!(upper, 0.3338726540369059, 1e-12);
+
+        let (lower, upper) = proportion_difference_interval(9, 10, 3, 10, 0.95).unwrap();
+        assert_almost_eq!(lower, 0.17052272393450302, 1e-12);
+        assert_almost_eq!(upper, 0.809017973535488, 1e-12);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(proportion_interval(1, 0, 0.95, ProportionInterval::Wilson).is_err());
+        assert!(proportion_interval(3, 2, 0.95, ProportionInterval::Jeffreys).is_err());
+        assert!(proportion_interval(1, 2, 1.0, ProportionInterval::ClopperPearson).is_err());
+        assert!(proportion_difference_interval(1, 2, 3, 2, 0.95).is_err());
+        assert!(proportion_difference_interval(1, 2, 1, 2, 0.0).is_err());
+    }
+}
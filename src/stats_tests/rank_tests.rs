@@ -0,0 +1,751 @@
+use super::chi_squared::chi_squared_sf;
+use super::Alternative;
+use crate::function::{erf, factorial};
+use crate::statistics::{OrderStatistics, RankTieBreaker};
+use crate::{Result, StatsError};
+use std::collections::HashMap;
+use std::f64;
+
+/// Largest sample size for which `mann_whitney_u` and `wilcoxon_signed_rank`
+/// compute exact p-values
+const EXACT_MAX_N: usize = 49;
+
+/// Largest number of equally likely rank assignments for which
+/// `kruskal_wallis` and `friedman` compute exact p-values
+const EXACT_MAX_PERMUTATIONS: f64 = 1e6;
+
+/// The outcome of a rank-based test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RankTest {
+    /// The test statistic
+    pub statistic: f64,
+    /// The p-value for the chosen alternative hypothesis
+    pub p_value: f64,
+    /// Whether the p-value was computed from the exact permutation
+    /// distribution rather than a large-sample approximation
+    pub exact: bool,
+}
+
+impl RankTest {
+    fn nan() -> RankTest {
+        RankTest {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+            exact: false,
+        }
+    }
+}
+
+/// Performs the Mann–Whitney U test (Wilcoxon rank-sum test) of the null
+/// hypothesis that `x` and `y` are drawn from the same distribution
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty
+///
+/// # Remarks
+///
+/// The statistic is `U = R_x - N_x (N_x + 1) / 2`, where `R_x` is the sum of
+/// the ranks of `x` in the pooled sample, with tied values given their
+/// average rank. `Alternative::Greater` tests whether `x` tends to be larger
+/// than `y`.
+///
+/// If both samples have fewer than 50 observations the p-value is computed
+/// from the exact permutation distribution of the midranks, which remains
+/// valid in the presence of ties. Otherwise the normal approximation with a
+/// continuity correction and tie-corrected variance is used. Two-sided exact
+/// p-values are twice the smaller tail, capped at 1.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN`.
+///
+/// # Formula
+///
+/// ```ignore
+/// σ^2 = N_x N_y / 12 * ((N + 1) - Σ(t^3 - t) / (N (N - 1)))
+/// z = (U - N_x N_y / 2 ± 0.5) / σ
+/// ```
+///
+/// where `N = N_x + N_y` and `t` ranges over the sizes of groups of tied
+/// values
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{mann_whitney_u, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+/// let y = [1.15, 0.88, 0.90, 0.74, 1.21];
+/// let result = mann_whitney_u(&x, &y, Alternative::Greater).unwrap();
+/// assert_eq!(result.statistic, 35.0);
+/// assert!(almost_eq(result.p_value, 0.127206127206127, 1e-14));
+/// ```
+pub fn mann_whitney_u(x: &[f64], y: &[f64], alternative: Alternative) -> Result<RankTest> {
+    if x.is_empty() || y.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    if x.iter().chain(y).any(|v| v.is_nan()) {
+        return Ok(RankTest::nan());
+    }
+
+    let (n, m) = (x.len(), y.len());
+    let mut pooled = x.to_vec();
+    pooled.extend_from_slice(y);
+    let ranks = pooled.ranks(RankTieBreaker::Average);
+    let rank_sum: f64 = ranks[..n].iter().sum();
+    let nf = n as f64;
+    let statistic = rank_sum - nf * (nf + 1.0) / 2.0;
+
+    if n <= EXACT_MAX_N && m <= EXACT_MAX_N {
+        // counts[j][s] is the number of j-subsets of the pooled sample whose
+        // doubled ranks sum to s
+        let doubled = doubled_ranks(&ranks);
+        let max_sum: usize = doubled.iter().sum();
+        let mut counts = vec![vec![0.0; max_sum + 1]; n + 1];
+        counts[0][0] = 1.0;
+        let mut reach = 0;
+        for &r in &doubled {
+            reach += r;
+            for j in (0..n).rev() {
+                for s in (0..=reach - r).rev() {
+                    let c = counts[j][s];
+                    if c != 0.0 {
+                        counts[j + 1][s + r] += c;
+                    }
+                }
+            }
+        }
+        let observed: usize = doubled[..n].iter().sum();
+        return Ok(RankTest {
+            statistic,
+            p_value: exact_p_value(&counts[n], observed, alternative),
+            exact: true,
+        });
+    }
+
+    let mf = m as f64;
+    let total = nf + mf;
+    let variance =
+        nf * mf / 12.0 * ((total + 1.0) - tie_correction(&pooled) / (total * (total - 1.0)));
+    Ok(RankTest {
+        statistic,
+        p_value: normal_p_value(statistic - nf * mf / 2.0, variance.sqrt(), alternative),
+        exact: false,
+    })
+}
+
+/// Performs the Wilcoxon signed-rank test of the null hypothesis that the
+/// distribution from which `x` is drawn is symmetric about `mu`
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty
+///
+/// # Remarks
+///
+/// The statistic `V` is the sum of the ranks of `|x_i - mu|` over the
+/// observations with `x_i > mu`. Observations equal to `mu` are discarded
+/// and tied absolute differences are given their average rank.
+/// `Alternative::Greater` tests whether the distribution is located to the
+/// right of `mu`.
+///
+/// If fewer than 50 observations remain the p-value is computed from the
+/// exact permutation distribution of the signed midranks, otherwise the
+/// normal approximation with a continuity correction and tie-corrected
+/// variance is used.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or every observation equals `mu`.
+///
+/// # Formula
+///
+/// ```ignore
+/// σ^2 = N (N + 1) (2N + 1) / 24 - Σ(t^3 - t) / 48
+/// z = (V - N (N + 1) / 4 ± 0.5) / σ
+/// ```
+///
+/// where `t` ranges over the sizes of groups of tied absolute differences
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{wilcoxon_signed_rank, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.952, -0.147, 1.022, 0.43, 0.62, 0.59, 0.49, -0.08, 0.01];
+/// let result = wilcoxon_signed_rank(&x, 0.0, Alternative::Greater).unwrap();
+/// assert_eq!(result.statistic, 40.0);
+/// assert!(almost_eq(result.p_value, 0.01953125, 1e-15));
+/// ```
+pub fn wilcoxon_signed_rank(x: &[f64], mu: f64, alternative: Alternative) -> Result<RankTest> {
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    if mu.is_nan() || x.iter().any(|v| v.is_nan()) {
+        return Ok(RankTest::nan());
+    }
+
+    let diffs: Vec<f64> = x.iter().map(|v| v - mu).filter(|&d| d != 0.0).collect();
+    if diffs.is_empty() {
+        return Ok(RankTest::nan());
+    }
+    let mut abs_diffs: Vec<f64> = diffs.iter().map(|d| d.abs()).collect();
+    let ranks = abs_diffs.ranks(RankTieBreaker::Average);
+    let statistic: f64 = ranks
+        .iter()
+        .zip(&diffs)
+        .filter(|(_, &d)| d > 0.0)
+        .map(|(r, _)| r)
+        .sum();
+
+    let n = diffs.len();
+    if n <= EXACT_MAX_N {
+        // counts[s] is the number of sign assignments whose positive doubled
+        // ranks sum to s
+        let doubled = doubled_ranks(&ranks);
+        let max_sum: usize = doubled.iter().sum();
+        let mut counts = vec![0.0; max_sum + 1];
+        counts[0] = 1.0;
+        let mut reach = 0;
+        for &r in &doubled {
+            reach += r;
+            for s in (r..=reach).rev() {
+                counts[s] += counts[s - r];
+            }
+        }
+        let observed = (2.0 * statistic).round() as usize;
+        return Ok(RankTest {
+            statistic,
+            p_value: exact_p_value(&counts, observed, alternative),
+            exact: true,
+        });
+    }
+
+    let nf = n as f64;
+    let variance = nf * (nf + 1.0) * (2.0 * nf + 1.0) / 24.0 - tie_correction(&abs_diffs) / 48.0;
+    Ok(RankTest {
+        statistic,
+        p_value: normal_p_value(
+            statistic - nf * (nf + 1.0) / 4.0,
+            variance.sqrt(),
+            alternative,
+        ),
+        exact: false,
+    })
+}
+
+/// Performs the Wilcoxon signed-rank test on the paired differences
+/// `x_i - y_i` of the null hypothesis that their distribution is symmetric
+/// about `mu`
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` differ in length or are empty
+///
+/// # Remarks
+///
+/// Equivalent to `wilcoxon_signed_rank` applied to the differences
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{paired_wilcoxon_signed_rank, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+/// let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+/// let result = paired_wilcoxon_signed_rank(&x, &y, 0.0, Alternative::TwoSided).unwrap();
+/// assert_eq!(result.statistic, 40.0);
+/// assert!(almost_eq(result.p_value, 0.0390625, 1e-15));
+/// ```
+pub fn paired_wilcoxon_signed_rank(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+) -> Result<RankTest> {
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    wilcoxon_signed_rank(&diffs, mu, alternative)
+}
+
+/// Performs the Kruskal–Wallis test of the null hypothesis that all
+/// `groups` are drawn from the same distribution
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups or a group is empty
+///
+/// # Remarks
+///
+/// The statistic `H` is computed from the average ranks of the pooled
+/// sample and divided by the usual tie correction. If the number of
+/// distinct assignments of the observations to groups of the given sizes
+/// is at most one million, the p-value is the exact proportion of those
+/// assignments with a statistic at least as large as the observed one.
+/// Otherwise `H` is referred to the chi-squared distribution with `k - 1`
+/// degrees of freedom.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or all entries are equal.
+///
+/// # Formula
+///
+/// ```ignore
+/// H = (12 / (N (N + 1)) Σ R_i^2 / N_i - 3 (N + 1)) / (1 - Σ(t^3 - t) / (N^3 - N))
+/// ```
+///
+/// where `R_i` is the rank sum of the `i`th group and `t` ranges over the
+/// sizes of groups of tied values
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::kruskal_wallis;
+/// use statrs::prec::almost_eq;
+///
+/// let groups = [
+///     vec![2.9, 3.0, 2.5, 2.6, 3.2],
+///     vec![3.8, 2.7, 4.0, 2.4],
+///     vec![2.8, 3.4, 3.7, 2.2, 2.0],
+/// ];
+/// let result = kruskal_wallis(&groups).unwrap();
+/// assert!(almost_eq(result.statistic, 0.771428571428571, 1e-14));
+/// assert!(result.exact);
+/// ```
+pub fn kruskal_wallis<R: AsRef<[f64]>>(groups: &[R]) -> Result<RankTest> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().is_empty()) {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    let pooled: Vec<f64> = groups.iter().flat_map(|g| g.as_ref()).cloned().collect();
+    if pooled.iter().any(|v| v.is_nan()) {
+        return Ok(RankTest::nan());
+    }
+
+    let sizes: Vec<usize> = groups.iter().map(|g| g.as_ref().len()).collect();
+    let ranks = pooled.clone().ranks(RankTieBreaker::Average);
+    let doubled = doubled_ranks(&ranks);
+    let mut sums = Vec::with_capacity(sizes.len());
+    let mut start = 0;
+    for &size in &sizes {
+        sums.push(doubled[start..start + size].iter().sum::<usize>());
+        start += size;
+    }
+
+    // H is an increasing function of Σ R_i^2 / N_i, evaluated here on the
+    // doubled ranks
+    let spread = |sums: &[usize]| -> f64 {
+        sums.iter()
+            .zip(&sizes)
+            .map(|(&s, &size)| (s * s) as f64 / size as f64)
+            .sum()
+    };
+    let total = pooled.len() as f64;
+    let correction = 1.0 - tie_correction(&pooled) / (total * total * total - total);
+    if correction <= 0.0 {
+        return Ok(RankTest::nan());
+    }
+    let observed = spread(&sums);
+    let statistic = (3.0 / (total * (total + 1.0)) * observed - 3.0 * (total + 1.0)) / correction;
+
+    let ln_assignments = factorial::ln_factorial(pooled.len() as u64)
+        - sizes
+            .iter()
+            .map(|&s| factorial::ln_factorial(s as u64))
+            .sum::<f64>();
+    if ln_assignments > EXACT_MAX_PERMUTATIONS.ln() {
+        return Ok(RankTest {
+            statistic,
+            p_value: chi_squared_sf((sizes.len() - 1) as f64, statistic),
+            exact: false,
+        });
+    }
+
+    // assign the observations to groups one at a time, tracking the number
+    // of ways to reach each combination of group counts and rank sums
+    let k = sizes.len();
+    let mut states: HashMap<Vec<usize>, f64> = HashMap::new();
+    states.insert(vec![0; 2 * k], 1.0);
+    for &r in &doubled {
+        let mut next = HashMap::with_capacity(states.len() * k);
+        for (state, &ways) in &states {
+            for g in 0..k {
+                if state[g] < sizes[g] {
+                    let mut s = state.clone();
+                    s[g] += 1;
+                    s[k + g] += r;
+                    *next.entry(s).or_insert(0.0) += ways;
+                }
+            }
+        }
+        states = next;
+    }
+    let threshold = observed * (1.0 - 1e-12);
+    let (extreme, all) = states
+        .iter()
+        .fold((0.0, 0.0), |(extreme, all), (state, &ways)| {
+            if spread(&state[k..]) >= threshold {
+                (extreme + ways, all + ways)
+            } else {
+                (extreme, all + ways)
+            }
+        });
+    Ok(RankTest {
+        statistic,
+        p_value: extreme / all,
+        exact: true,
+    })
+}
+
+/// Performs the Friedman test of the null hypothesis that the treatments
+/// have identical effects, given one row of observations per block and one
+/// column per treatment
+///
+/// # Errors
+///
+/// Returns an error if `blocks` is empty, the rows differ in length or
+/// there are fewer than two treatments
+///
+/// # Remarks
+///
+/// Observations are ranked within each block, with tied values given their
+/// average rank, and the statistic is corrected for ties. If the number of
+/// within-block permutations, `(k!)^b` for `k` treatments and `b` blocks, is
+/// at most one million the p-value is the exact proportion of them with a
+/// statistic at least as large as the observed one. Otherwise the statistic
+/// is referred to the chi-squared distribution with `k - 1` degrees of
+/// freedom.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or every block is constant.
+///
+/// # Formula
+///
+/// ```ignore
+/// Q = 12 Σ(R_j - b (k + 1) / 2)^2 / (b k (k + 1) - Σ(t^3 - t) / (k - 1))
+/// ```
+///
+/// where `R_j` is the rank sum of the `j`th treatment and `t` ranges over
+/// the sizes of groups of tied values within each block
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::friedman;
+/// use statrs::prec::almost_eq;
+///
+/// let blocks = [
+///     [1.2, 2.3, 3.1],
+///     [0.8, 1.9, 1.7],
+///     [2.2, 3.4, 3.5],
+///     [1.0, 1.4, 2.6],
+///     [0.7, 2.0, 1.1],
+/// ];
+/// let result = friedman(&blocks).unwrap();
+/// assert!(almost_eq(result.statistic, 7.6, 1e-14));
+/// assert!(almost_eq(result.p_value, 0.0239197530864198, 1e-14));
+/// ```
+pub fn friedman<R: AsRef<[f64]>>(blocks: &[R]) -> Result<RankTest> {
+    if blocks.is_empty() {
+        return Err(StatsError::ArgGte("blocks", 1.0));
+    }
+    let k = blocks[0].as_ref().len();
+    if blocks.iter().any(|row| row.as_ref().len() != k) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if k < 2 {
+        return Err(StatsError::ArgGte("treatments", 2.0));
+    }
+    if blocks
+        .iter()
+        .any(|row| row.as_ref().iter().any(|v| v.is_nan()))
+    {
+        return Ok(RankTest::nan());
+    }
+
+    let b = blocks.len();
+    let ranks: Vec<Vec<usize>> = blocks
+        .iter()
+        .map(|row| doubled_ranks(&row.as_ref().to_vec().ranks(RankTieBreaker::Average)))
+        .collect();
+    let ties: f64 = blocks.iter().map(|row| tie_correction(row.as_ref())).sum();
+    let mut sums = vec![0; k];
+    for row in &ranks {
+        for (s, r) in sums.iter_mut().zip(row) {
+            *s += r;
+        }
+    }
+
+    // Q is an increasing function of Σ R_j^2, since Σ R_j is fixed; on the
+    // doubled ranks it is an integer
+    let spread = |sums: &[usize]| -> usize { sums.iter().map(|s| s * s).sum() };
+    let (bf, kf) = (b as f64, k as f64);
+    let denominator = bf * kf * (kf + 1.0) - ties / (kf - 1.0);
+    if denominator <= 0.0 {
+        return Ok(RankTest::nan());
+    }
+    let center = bf * (kf + 1.0);
+    let statistic = 3.0
+        * sums
+            .iter()
+            .map(|&s| (s as f64 - center) * (s as f64 - center))
+            .sum::<f64>()
+        / denominator;
+
+    if bf * factorial::ln_factorial(k as u64) > EXACT_MAX_PERMUTATIONS.ln() {
+        return Ok(RankTest {
+            statistic,
+            p_value: chi_squared_sf(kf - 1.0, statistic),
+            exact: false,
+        });
+    }
+
+    // permute the ranks within one block at a time, tracking the number of
+    // ways to reach each vector of rank sums
+    let observed = spread(&sums);
+    let mut states: HashMap<Vec<usize>, f64> = HashMap::new();
+    states.insert(vec![0; k], 1.0);
+    for row in &ranks {
+        let orderings = permutations(row);
+        let mut next = HashMap::with_capacity(states.len() * orderings.len());
+        for (state, &ways) in &states {
+            for ordering in &orderings {
+                let s: Vec<usize> = state.iter().zip(ordering).map(|(a, b)| a + b).collect();
+                *next.entry(s).or_insert(0.0) += ways;
+            }
+        }
+        states = next;
+    }
+    let (extreme, all) = states
+        .iter()
+        .fold((0.0, 0.0), |(extreme, all), (state, &ways)| {
+            if spread(state) >= observed {
+                (extreme + ways, all + ways)
+            } else {
+                (extreme, all + ways)
+            }
+        });
+    Ok(RankTest {
+        statistic,
+        p_value: extreme / all,
+        exact: true,
+    })
+}
+
+/// Converts average ranks, which are multiples of one half, to integers
+fn doubled_ranks(ranks: &[f64]) -> Vec<usize> {
+    ranks.iter().map(|r| (2.0 * r).round() as usize).collect()
+}
+
+/// Returns `Σ(t^3 - t)` over the sizes `t` of groups of tied values in `x`
+fn tie_correction(x: &[f64]) -> f64 {
+    let mut sorted = x.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut sum = 0.0;
+    let mut start = 0;
+    for i in 1..=sorted.len() {
+        if i == sorted.len() || sorted[i] != sorted[start] {
+            let t = (i - start) as f64;
+            sum += t * t * t - t;
+            start = i;
+        }
+    }
+    sum
+}
+
+/// Returns every ordering of `v`, counting orderings of equal entries
+/// separately
+fn permutations(v: &[usize]) -> Vec<Vec<usize>> {
+    if v.len() <= 1 {
+        return vec![v.to_vec()];
+    }
+    let mut result = Vec::new();
+    for i in 0..v.len() {
+        let mut rest = v.to_vec();
+        let first = rest.remove(i);
+        for mut tail in permutations(&rest) {
+            tail.insert(0, first);
+            result.push(tail);
+        }
+    }
+    result
+}
+
+/// P-value of the integer statistic `observed` under the distribution given
+/// by the (unnormalized) `counts`
+fn exact_p_value(counts: &[f64], observed: usize, alternative: Alternative) -> f64 {
+    let total: f64 = counts.iter().sum();
+    let lower = counts[..=observed].iter().sum::<f64>() / total;
+    let upper = counts[observed..].iter().sum::<f64>() / total;
+    match alternative {
+        Alternative::TwoSided => (2.0 * lower.min(upper)).min(1.0),
+        Alternative::Less => lower,
+        Alternative::Greater => upper,
+    }
+}
+
+/// P-value of the normal approximation to a statistic whose deviation from
+/// its null mean is `diff`, with a continuity correction of one half
+fn normal_p_value(diff: f64, std_dev: f64, alternative: Alternative) -> f64 {
+    let correction = match alternative {
+        Alternative::TwoSided if diff == 0.0 => 0.0,
+        Alternative::TwoSided => 0.5 * diff.signum(),
+        Alternative::Less => -0.5,
+        Alternative::Greater => 0.5,
+    };
+    let z = (diff - correction) / std_dev;
+    match alternative {
+        Alternative::TwoSided => erf::erfc(z.abs() / f64::consts::SQRT_2),
+        Alternative::Less => 0.5 * erf::erfc(-z / f64::consts::SQRT_2),
+        Alternative::Greater => 0.5 * erf::erfc(z / f64::consts::SQRT_2),
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X: [f64; 10] = [0.80, 0.83, 1.89, 1.04, 1.45, 1.38, 1.91, 1.64, 0.73, 1.46];
+    const Y: [f64; 5] = [1.15, 0.88, 0.90, 0.74, 1.21];
+
+    fn golden_sequence(n: usize, step: f64, shift: f64) -> Vec<f64> {
+        (1..=n).map(|i| (i as f64 * step) % 1.0 + shift).collect()
+    }
+
+    #[test]
+    fn test_mann_whitney_u_exact() {
+        let result = mann_whitney_u(&X, &Y, Alternative::Greater).unwrap();
+        assert_eq!(result.statistic, 35.0);
+        assert_almost_eq!(result.p_value, 0.1272061272061272, 1e-15);
+        assert!(result.exact);
+
+        let result = mann_whitney_u(&X, &Y, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(result.p_value, 0.2544122544122544, 1e-15);
+
+        let result = mann_whitney_u(&Y, &X, Alternative::Less).unwrap();
+        assert_eq!(result.statistic, 15.0);
+        assert_almost_eq!(result.p_value, 0.1272061272061272, 1e-15);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_ties() {
+        // permutation distribution of the midranks, by enumeration
+        let x = [1.0, 2.0, 2.0, 3.0, 5.0];
+        let y = [2.0, 3.0, 4.0, 4.0, 6.0, 7.0];
+        let result = mann_whitney_u(&x, &y, Alternative::TwoSided).unwrap();
+        assert_eq!(result.statistic, 6.5);
+        assert_almost_eq!(result.p_value, 0.12554112554112554, 1e-14);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_asymptotic() {
+        let x = golden_sequence(60, 0.6180339887498949, 0.1);
+        let y = golden_sequence(55, 0.7548776662466927, 0.0);
+        let result = mann_whitney_u(&x, &y, Alternative::TwoSided).unwrap();
+        assert!(!result.exact);
+        assert_almost_eq!(result.statistic, 1961.0, 1e-12);
+        assert_almost_eq!(result.p_value, 0.08212869259446326, 1e-10);
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank() {
+        let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+        let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.14, 1.29];
+        let result = paired_wilcoxon_signed_rank(&x, &y, 0.0, Alternative::Greater).unwrap();
+        assert_eq!(result.statistic, 40.0);
+        assert_almost_eq!(result.p_value, 0.01953125, 1e-15);
+
+        let result = paired_wilcoxon_signed_rank(&x, &y, 0.0, Alternative::Less).unwrap();
+        assert_almost_eq!(result.p_value, 0.986328125, 1e-15);
+
+        // zeros are dropped and ties share their average rank
+        let d = [0.0, 1.0, -1.0, 2.0, 2.0, 3.0, -0.5, 4.0];
+        let result = wilcoxon_signed_rank(&d, 0.0, Alternative::TwoSided).unwrap();
+        assert_eq!(result.statistic, 24.5);
+        assert_almost_eq!(result.p_value, 0.09375, 1e-14);
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank_asymptotic() {
+        let x = golden_sequence(80, 0.6180339887498949, -0.4);
+        let result = wilcoxon_signed_rank(&x, 0.0, Alternative::Greater).unwrap();
+        assert!(!result.exact);
+        assert_almost_eq!(result.statistic, 2239.0, 1e-12);
+        assert_almost_eq!(result.p_value, 0.0015060340348598221, 1e-10);
+    }
+
+    #[test]
+    fn test_kruskal_wallis() {
+        let groups = [
+            vec![2.9, 3.0, 2.5, 2.6, 3.2],
+            vec![3.8, 2.7, 4.0, 2.4],
+            vec![2.8, 3.4, 3.7, 2.2, 2.0],
+        ];
+        let result = kruskal_wallis(&groups).unwrap();
+        assert!(result.exact);
+        assert_almost_eq!(result.statistic, 0.7714285714285722, 1e-13);
+        assert_almost_eq!(result.p_value, 0.7107733536304965, 1e-14);
+
+        let groups = [
+            golden_sequence(10, 0.6180339887498949, 0.0),
+            golden_sequence(12, 0.7548776662466927, 0.2),
+            golden_sequence(9, 0.4142135623730951, 0.4),
+        ];
+        let result = kruskal_wallis(&groups).unwrap();
+        assert!(!result.exact);
+        assert_almost_eq!(result.statistic, 7.681250000000006, 1e-12);
+        assert_almost_eq!(result.p_value, 0.0214801720413433, 1e-12);
+    }
+
+    #[test]
+    fn test_friedman() {
+        // rounding times from Hollander and Wolfe, 22 blocks of 3
+        let blocks = [
+            [5.40, 5.50, 5.55], [5.85, 5.70, 5.75], [5.20, 5.60, 5.50], [5.55, 5.50, 5.40],
+            [5.90, 5.85, 5.70], [5.45, 5.55, 5.60], [5.40, 5.40, 5.35], [5.45, 5.50, 5.35],
+            [5.25, 5.15, 5.00], [5.85, 5.80, 5.70], [5.25, 5.20, 5.10], [5.65, 5.55, 5.45],
+            [5.60, 5.35, 5.45], [5.05, 5.00, 4.95], [5.50, 5.50, 5.40], [5.45, 5.55, 5.50],
+            [5.55, 5.55, 5.35], [5.45, 5.50, 5.55], [5.50, 5.45, 5.25], [5.65, 5.60, 5.40],
+            [5.70, 5.65, 5.55], [6.30, 6.30, 6.25],
+        ];
+        let result = friedman(&blocks).unwrap();
+        assert!(!result.exact);
+        assert_almost_eq!(result.statistic, 11.142857142857142, 1e-13);
+        assert_almost_eq!(result.p_value, 0.003805040775511269, 1e-12);
+
+        let blocks = [[1.0, 2.0, 2.0, 3.0], [2.0, 1.0, 3.0, 3.0], [4.0, 3.0, 1.0, 2.0], [1.0, 1.0, 2.0, 3.0]];
+        let result = friedman(&blocks).unwrap();
+        assert!(result.exact);
+        assert_almost_eq!(result.statistic, 3.0, 1e-14);
+        assert_almost_eq!(result.p_value, 0.42997685185185186, 1e-14);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert!(mann_whitney_u(&[1.0, f64::NAN], &[2.0], Alternative::TwoSided).unwrap().p_value.is_nan());
+        assert!(wilcoxon_signed_rank(&[1.0, 1.0], 1.0, Alternative::TwoSided).unwrap().p_value.is_nan());
+        assert!(kruskal_wallis(&[[1.0, 1.0], [1.0, 1.0]]).unwrap().statistic.is_nan());
+        assert!(friedman(&[[2.0, 2.0], [1.0, 1.0]]).unwrap().statistic.is_nan());
+        assert_eq!(mann_whitney_u(&[1.0, 1.0], &[1.0], Alternative::TwoSided).unwrap().p_value, 1.0);
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(mann_whitney_u(&[], &[1.0], Alternative::TwoSided).is_err());
+        assert!(wilcoxon_signed_rank(&[], 0.0, Alternative::TwoSided).is_err());
+        assert!(paired_wilcoxon_signed_rank(&[1.0], &[1.0, 2.0], 0.0, Alternative::TwoSided).is_err());
+        assert!(kruskal_wallis(&[[1.0, 2.0]]).is_err());
+        assert!(kruskal_wallis(&[vec![1.0, 2.0], vec![]]).is_err());
+        assert!(friedman(&[vec![1.0, 2.0], vec![1.0]]).is_err());
+        assert!(friedman(&[[1.0], [2.0]]).is_err());
+        let empty: [[f64; 2]; 0] = [];
+        assert!(friedman(&empty).is_err());
+    }
+}
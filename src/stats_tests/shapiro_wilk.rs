@@ -0,0 +1,225 @@
+use super::GoodnessOfFit;
+use crate::distribution::{ContinuousCDF, Normal};
+use crate::function::evaluate::polynomial;
+use crate::{Result, StatsError};
+use std::f64;
+
+const SMALL: f64 = 1e-19;
+
+// polynomial coefficients of Royston (1995)
+const G: [f64; 2] = [-2.273, 0.459];
+const C1: [f64; 6] = [0.0, 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+const C2: [f64; 6] = [0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+
+/// Performs the Shapiro–Wilk test of the null hypothesis that `x` is drawn
+/// from a normal distribution
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than 3 or more than 5000 entries
+///
+/// # Remarks
+///
+/// The statistic `W` is the squared correlation between the ordered sample
+/// and approximations to the expected normal order statistics, and the
+/// p-value comes from Royston's (1995) normalizing transformation of `W`
+/// (algorithm AS R94). For `N = 3` the p-value is exact. Small values of `W`
+/// indicate departure from normality.
+///
+/// Returns `f64::NAN` for the statistic and p-value if an entry is
+/// `f64::NAN` or all entries are equal.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::shapiro_wilk;
+/// use statrs::prec::almost_eq;
+///
+/// let x = [148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0];
+/// let result = shapiro_wilk(&x).unwrap();
+/// assert!(almost_eq(result.statistic, 0.788814694835387, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.006703814056503, 1e-10));
+/// ```
+pub fn shapiro_wilk(x: &[f64]) -> Result<GoodnessOfFit> {
+    let n = x.len();
+    if n < 3 {
+        return Err(StatsError::ArgGte("sample size", 3.0));
+    }
+    if n > 5000 {
+        return Err(StatsError::ArgLte("sample size", 5000.0));
+    }
+    if x.iter().any(|v| v.is_nan()) {
+        return Ok(GoodnessOfFit::nan());
+    }
+    let mut x = x.to_vec();
+    x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let range = x[n - 1] - x[0];
+    if range < SMALL {
+        return Ok(GoodnessOfFit::nan());
+    }
+
+    let a = coefficients(n);
+    let nf = n as f64;
+
+    // W is computed as the squared correlation between the data and the
+    // antisymmetric coefficients; 1 - W is formed directly to avoid
+    // cancellation when W is very close to 1
+    let coef = |i: usize| {
+        if i < n / 2 {
+            -a[i]
+        } else if n - 1 - i < n / 2 {
+            a[n - 1 - i]
+        } else {
+            0.0
+        }
+    };
+    let sa = (0..n).map(coef).sum::<f64>() / nf;
+    let sx = x.iter().map(|v| v / range).sum::<f64>() / nf;
+    let (mut ssa, mut ssx, mut sax) = (0.0, 0.0, 0.0);
+    for (i, &v) in x.iter().enumerate() {
+        let asa = coef(i) - sa;
+        let xsx = v / range - sx;
+        ssa += asa * asa;
+        ssx += xsx * xsx;
+        sax += asa * xsx;
+    }
+    let ssassx = (ssa * ssx).sqrt();
+    let w1 = (ssassx - sax) * (ssassx + sax) / (ssa * ssx);
+    let w = 1.0 - w1;
+
+    Ok(GoodnessOfFit {
+        statistic: w,
+        p_value: p_value(n, w, w1),
+    })
+}
+
+/// Returns the coefficients `a_1 >= a_2 >= ... >= a_(N/2) > 0` applied to
+/// the differences of opposite order statistics
+fn coefficients(n: usize) -> Vec<f64> {
+    let half = n / 2;
+    if n == 3 {
+        return vec![f64::consts::FRAC_1_SQRT_2];
+    }
+    let nf = n as f64;
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let mut m: Vec<f64> = (1..=half)
+        .map(|i| normal.inverse_cdf((i as f64 - 0.375) / (nf + 0.25)))
+        .collect();
+    let summ2 = 2.0 * m.iter().map(|v| v * v).sum::<f64>();
+    let ssumm2 = summ2.sqrt();
+    let rsn = 1.0 / nf.sqrt();
+    let a1 = polynomial(rsn, &C1) - m[0] / ssumm2;
+
+    let (first, fac) = if n > 5 {
+        let a2 = -m[1] / ssumm2 + polynomial(rsn, &C2);
+        let fac = ((summ2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1])
+            / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2))
+            .sqrt();
+        m[1] = a2;
+        (2, fac)
+    } else {
+        let fac = ((summ2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt();
+        (1, fac)
+    };
+    m[0] = a1;
+    for v in &mut m[first..] {
+        *v /= -fac;
+    }
+    m
+}
+
+fn p_value(n: usize, w: f64, w1: f64) -> f64 {
+    if n == 3 {
+        // exact: 6 / π * (asin(sqrt(W)) - asin(sqrt(3 / 4)))
+        let p = 6.0 / f64::consts::PI * (w.sqrt().asin() - f64::consts::FRAC_PI_3);
+        return p.max(0.0);
+    }
+    let nf = n as f64;
+    let mut y = w1.ln();
+    let (m, s) = if n <= 11 {
+        let gamma = polynomial(nf, &G);
+        if y >= gamma {
+            return 1e-99;
+        }
+        y = -(gamma - y).ln();
+        (polynomial(nf, &C3), polynomial(nf, &C4).exp())
+    } else {
+        let ln_n = nf.ln();
+        (polynomial(ln_n, &C5), polynomial(ln_n, &C6).exp())
+    };
+    let normal = Normal::new(m, s).unwrap();
+    1.0 - normal.cdf(y)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shapiro_wilk() {
+        // the weights of eleven men from Shapiro and Wilk (1965)
+        let x = [148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0];
+        let result = shapiro_wilk(&x).unwrap();
+        assert_almost_eq!(result.statistic, 0.7888146948353874, 1e-12);
+        assert_almost_eq!(result.p_value, 0.006703814056502989, 1e-10);
+
+        let x = [
+            2.1, 3.4, 1.9, 5.6, 4.4, 3.0, 2.8, 6.1, 3.3, 4.0,
+            2.5, 3.9, 7.2, 2.2, 3.6, 4.8, 3.1, 5.0, 2.9, 4.1,
+        ];
+        let result = shapiro_wilk(&x).unwrap();
+        assert_almost_eq!(result.statistic, 0.9452600015447662, 1e-12);
+        assert_almost_eq!(result.p_value, 0.30079941404201305, 1e-10);
+    }
+
+    #[test]
+    fn test_small_samples() {
+        let result = shapiro_wilk(&[1.0, 2.0, 4.0]).unwrap();
+        assert_almost_eq!(result.statistic, 0.9642857142857142, 1e-14);
+        assert_almost_eq!(result.p_value, 0.6368868450289632, 1e-12);
+
+        let result = shapiro_wilk(&[2.1, 3.4, 1.9, 5.6, 4.4]).unwrap();
+        assert_almost_eq!(result.statistic, 0.9320849396015236, 1e-12);
+        assert_almost_eq!(result.p_value, 0.6106559050550954, 1e-10);
+
+        // equally spaced points are as normal as three points can be
+        let result = shapiro_wilk(&[1.0, 2.0, 3.0]).unwrap();
+        assert_almost_eq!(result.statistic, 1.0, 1e-15);
+        assert_almost_eq!(result.p_value, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_large_samples() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let cubed: Vec<f64> = (1..=300)
+            .map(|i| normal.inverse_cdf((i as f64 * 0.6180339887498949) % 1.0 * 0.998 + 0.001).powi(3))
+            .collect();
+        let result = shapiro_wilk(&cubed).unwrap();
+        assert_almost_eq!(result.statistic, 0.6828832859559295, 1e-10);
+        assert!(result.p_value < 1e-20);
+
+        let quantiles: Vec<f64> = (1..=1000)
+            .map(|i| normal.inverse_cdf((i as f64 - 0.5) / 1000.0))
+            .collect();
+        let result = shapiro_wilk(&quantiles).unwrap();
+        assert_almost_eq!(result.statistic, 0.9999487842113511, 1e-10);
+        assert!(result.p_value > 0.99);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert!(shapiro_wilk(&[1.0, 1.0, 1.0, 1.0]).unwrap().p_value.is_nan());
+        assert!(shapiro_wilk(&[1.0, f64::NAN, 1.0]).unwrap().statistic.is_nan());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(shapiro_wilk(&[1.0, 2.0]).is_err());
+        assert!(shapiro_wilk(&vec![1.0; 5001]).is_err());
+    }
+}
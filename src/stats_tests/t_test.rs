@@ -0,0 +1,396 @@
+use super::Alternative;
+use crate::distribution::{ContinuousCDF, StudentsT};
+use crate::statistics::Statistics;
+use crate::{Result, StatsError};
+use std::f64;
+
+/// The outcome of a Student's t-test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TTest {
+    /// The t statistic
+    pub statistic: f64,
+    /// The degrees of freedom of the reference t-distribution
+    pub freedom: f64,
+    /// The p-value for the chosen alternative hypothesis
+    pub p_value: f64,
+    /// The estimated mean, or difference in means, before subtracting the
+    /// hypothesized value
+    pub estimate: f64,
+    /// The standard error of the estimate
+    pub std_error: f64,
+    /// The confidence interval for the estimate, one-sided (unbounded on one
+    /// end) unless the alternative is `Alternative::TwoSided`
+    pub confidence_interval: (f64, f64),
+}
+
+/// Performs a one-sample t-test of the null hypothesis that the mean of the
+/// population from which `x` is drawn equals `mu`
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than two entries or if `confidence` is
+/// not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` for the statistic and p-value if `x` has zero variance
+/// or contains `f64::NAN`
+///
+/// # Formula
+///
+/// ```ignore
+/// t = (x̄ - μ) / (s / sqrt(N))
+/// ```
+///
+/// with `N - 1` degrees of freedom, where `s` is the sample standard deviation
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{one_sample_t_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let result = one_sample_t_test(&x, 2.0, Alternative::Less, 0.95).unwrap();
+/// assert_eq!(result.freedom, 9.0);
+/// assert!(almost_eq(result.p_value, 0.0272440837205066, 1e-12));
+/// assert_eq!(result.confidence_interval.0, f64::NEG_INFINITY);
+/// ```
+pub fn one_sample_t_test(
+    x: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TTest> {
+    check_confidence(confidence)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("sample size", 2.0));
+    }
+    let n = x.len() as f64;
+    let std_error = (x.variance() / n).sqrt();
+    Ok(t_test(
+        x.mean(),
+        std_error,
+        n - 1.0,
+        mu,
+        alternative,
+        confidence,
+    ))
+}
+
+/// Performs a paired t-test of the null hypothesis that the mean of the
+/// differences `x_i - y_i` equals `mu`
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` differ in length, have fewer than two
+/// entries, or if `confidence` is not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// This is the one-sample t-test applied to the differences of the pairs
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{paired_t_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let y = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+/// let result = paired_t_test(&x, &y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(almost_eq(result.statistic, -4.06212768338204, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.00283289019738853, 1e-12));
+/// ```
+pub fn paired_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TTest> {
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let differences: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    one_sample_t_test(&differences, mu, alternative, confidence)
+}
+
+/// Performs Student's two-sample t-test of the null hypothesis that the
+/// difference between the means of the populations from which `x` and `y`
+/// are drawn equals `mu`, assuming both populations have the same variance
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty, there are fewer than three
+/// entries in total, or if `confidence` is not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// Prefer `welch_t_test` unless the variances are known to be equal; it
+/// loses little power when they are and stays accurate when they are not
+///
+/// # Formula
+///
+/// ```ignore
+/// s_p^2 = ((N_x - 1) s_x^2 + (N_y - 1) s_y^2) / (N_x + N_y - 2)
+/// t = (x̄ - ȳ - μ) / (s_p * sqrt(1 / N_x + 1 / N_y))
+/// ```
+///
+/// with `N_x + N_y - 2` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{two_sample_t_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let y = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+/// let result = two_sample_t_test(&x, &y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert_eq!(result.freedom, 18.0);
+/// assert!(almost_eq(result.p_value, 0.0791867142159247, 1e-12));
+/// ```
+pub fn two_sample_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TTest> {
+    check_confidence(confidence)?;
+    if x.is_empty() || y.is_empty() {
+        return Err(StatsError::ArgGte("sample size", 1.0));
+    }
+    if x.len() + y.len() < 3 {
+        return Err(StatsError::ArgGte("total sample size", 3.0));
+    }
+    let nx = x.len() as f64;
+    let ny = y.len() as f64;
+    let freedom = nx + ny - 2.0;
+    let ssx = if x.len() > 1 {
+        (nx - 1.0) * x.variance()
+    } else {
+        0.0
+    };
+    let ssy = if y.len() > 1 {
+        (ny - 1.0) * y.variance()
+    } else {
+        0.0
+    };
+    let pooled_variance = (ssx + ssy) / freedom;
+    let std_error = (pooled_variance * (1.0 / nx + 1.0 / ny)).sqrt();
+    Ok(t_test(
+        x.mean() - y.mean(),
+        std_error,
+        freedom,
+        mu,
+        alternative,
+        confidence,
+    ))
+}
+
+/// Performs Welch's two-sample t-test of the null hypothesis that the
+/// difference between the means of the populations from which `x` and `y`
+/// are drawn equals `mu`, without assuming equal variances
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two entries or if
+/// `confidence` is not in the open interval `(0, 1)`
+///
+/// # Remarks
+///
+/// The degrees of freedom come from the Welch–Satterthwaite equation and are
+/// generally not an integer
+///
+/// # Formula
+///
+/// ```ignore
+/// t = (x̄ - ȳ - μ) / sqrt(s_x^2 / N_x + s_y^2 / N_y)
+/// ν = (s_x^2 / N_x + s_y^2 / N_y)^2 /
+///     ((s_x^2 / N_x)^2 / (N_x - 1) + (s_y^2 / N_y)^2 / (N_y - 1))
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{welch_t_test, Alternative};
+/// use statrs::prec::almost_eq;
+///
+/// let x = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+/// let y = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+/// let result = welch_t_test(&x, &y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(almost_eq(result.freedom, 17.7764735161785, 1e-12));
+/// assert!(almost_eq(result.p_value, 0.0793941401873585, 1e-12));
+/// ```
+pub fn welch_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> Result<TTest> {
+    check_confidence(confidence)?;
+    if x.len() < 2 || y.len() < 2 {
+        return Err(StatsError::ArgGte("sample size", 2.0));
+    }
+    let nx = x.len() as f64;
+    let ny = y.len() as f64;
+    let vx = x.variance() / nx;
+    let vy = y.variance() / ny;
+    let freedom = (vx + vy) * (vx + vy) / (vx * vx / (nx - 1.0) + vy * vy / (ny - 1.0));
+    Ok(t_test(
+        x.mean() - y.mean(),
+        (vx + vy).sqrt(),
+        freedom,
+        mu,
+        alternative,
+        confidence,
+    ))
+}
+
+pub(crate) fn check_confidence(confidence: f64) -> Result<()> {
+    if confidence > 0.0 && confidence < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("confidence", 0.0, 1.0))
+    }
+}
+
+fn t_test(
+    estimate: f64,
+    std_error: f64,
+    freedom: f64,
+    mu: f64,
+    alternative: Alternative,
+    confidence: f64,
+) -> TTest {
+    let statistic = (estimate - mu) / std_error;
+    let dist = match StudentsT::new(0.0, 1.0, freedom) {
+        Ok(dist) if !statistic.is_nan() => dist,
+        _ => {
+            return TTest {
+                statistic,
+                freedom,
+                p_value: f64::NAN,
+                estimate,
+                std_error,
+                confidence_interval: (f64::NAN, f64::NAN),
+            }
+        }
+    };
+
+    let alpha = 1.0 - confidence;
+    let (p_value, confidence_interval) = match alternative {
+        Alternative::TwoSided => {
+            let margin = dist.inverse_cdf(1.0 - alpha / 2.0) * std_error;
+            (
+                (2.0 * dist.cdf(-statistic.abs())).min(1.0),
+                (estimate - margin, estimate + margin),
+            )
+        }
+        Alternative::Less => {
+            let margin = dist.inverse_cdf(confidence) * std_error;
+            (dist.cdf(statistic), (f64::NEG_INFINITY, estimate + margin))
+        }
+        Alternative::Greater => {
+            let margin = dist.inverse_cdf(confidence) * std_error;
+            (dist.cdf(-statistic), (estimate - margin, f64::INFINITY))
+        }
+    };
+    TTest {
+        statistic,
+        freedom,
+        p_value,
+        estimate,
+        std_error,
+        confidence_interval,
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Student's sleep data, as shipped with R
+    const SLEEP_1: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    const SLEEP_2: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+    #[test]
+    fn test_one_sample() {
+        let result = one_sample_t_test(&SLEEP_1, 0.5, Alternative::Greater, 0.9).unwrap();
+        assert_almost_eq!(result.statistic, 0.44190338023794046, 1e-13);
+        assert_eq!(result.freedom, 9.0);
+        assert_almost_eq!(result.p_value, 0.33449331180733743, 1e-12);
+        assert_almost_eq!(result.estimate, 0.75, 1e-15);
+        assert_almost_eq!(result.confidence_interval.0, -0.03242710977453911, 1e-10);
+        assert_eq!(result.confidence_interval.1, f64::INFINITY);
+
+        let result = one_sample_t_test(&SLEEP_1, 2.0, Alternative::Less, 0.95).unwrap();
+        assert_almost_eq!(result.statistic, -2.2095169011897022, 1e-13);
+        assert_almost_eq!(result.p_value, 0.02724408372050663, 1e-12);
+        assert_eq!(result.confidence_interval.0, f64::NEG_INFINITY);
+        assert_almost_eq!(result.confidence_interval.1, 1.7870552787292817, 1e-10);
+    }
+
+    #[test]
+    fn test_paired() {
+        let result = paired_t_test(&SLEEP_1, &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(result.statistic, -4.062127683382037, 1e-13);
+        assert_eq!(result.freedom, 9.0);
+        assert_almost_eq!(result.p_value, 0.0028328901973885268, 1e-12);
+        assert_almost_eq!(result.estimate, -1.58, 1e-14);
+        assert_almost_eq!(result.confidence_interval.0, -2.459885763276871, 1e-10);
+        assert_almost_eq!(result.confidence_interval.1, -0.7001142367231293, 1e-10);
+    }
+
+    #[test]
+    fn test_two_sample() {
+        let result = two_sample_t_test(&SLEEP_1, &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(result.statistic, -1.8608134674868528, 1e-13);
+        assert_eq!(result.freedom, 18.0);
+        assert_almost_eq!(result.p_value, 0.07918671421592471, 1e-12);
+        assert_almost_eq!(result.confidence_interval.0, -3.363874032287551, 1e-10);
+        assert_almost_eq!(result.confidence_interval.1, 0.20387403228755074, 1e-10);
+    }
+
+    #[test]
+    fn test_welch() {
+        let result = welch_t_test(&SLEEP_1, &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(result.statistic, -1.860813467486853, 1e-13);
+        assert_almost_eq!(result.freedom, 17.776473516178488, 1e-12);
+        assert_almost_eq!(result.p_value, 0.07939414018735846, 1e-12);
+        assert_almost_eq!(result.confidence_interval.0, -3.365483230711776, 1e-10);
+        assert_almost_eq!(result.confidence_interval.1, 0.20548323071177577, 1e-10);
+
+        // one-sided p-values split the two-sided one
+        let less = welch_t_test(&SLEEP_1, &SLEEP_2, 0.0, Alternative::Less, 0.95).unwrap();
+        let greater = welch_t_test(&SLEEP_1, &SLEEP_2, 0.0, Alternative::Greater, 0.95).unwrap();
+        assert_almost_eq!(less.p_value, result.p_value / 2.0, 1e-15);
+        assert_almost_eq!(less.p_value + greater.p_value, 1.0, 1e-15);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let result = one_sample_t_test(&[1.0, 1.0, 1.0], 1.0, Alternative::TwoSided, 0.95).unwrap();
+        assert!(result.statistic.is_nan());
+        assert!(result.p_value.is_nan());
+
+        let result = welch_t_test(&[1.0, f64::NAN], &[1.0, 2.0], 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert!(result.p_value.is_nan());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(one_sample_t_test(&[1.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(one_sample_t_test(&SLEEP_1, 0.0, Alternative::TwoSided, 1.0).is_err());
+        assert!(one_sample_t_test(&SLEEP_1, 0.0, Alternative::TwoSided, f64::NAN).is_err());
+        assert!(paired_t_test(&SLEEP_1, &SLEEP_2[..9], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[1.0], &[2.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[], &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[1.0], &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).is_ok());
+        assert!(welch_t_test(&[1.0], &SLEEP_2, 0.0, Alternative::TwoSided, 0.95).is_err());
+    }
+}